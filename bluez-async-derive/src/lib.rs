@@ -0,0 +1,327 @@
+//! Derive macros for use with [`bluez-async`](https://crates.io/crates/bluez-async).
+//!
+//! See [`GattProfile`](derive.GattProfile.html) for details.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, Expr, ExprLit, Fields,
+    Lit, Type,
+};
+
+/// Derive a constructor to look up a set of GATT characteristics on a device in one go, along with
+/// typed accessors for each of them.
+///
+/// The struct must have named fields, each of which is either a `CharacteristicId` or an
+/// `Option<CharacteristicId>`. Each field must be annotated with the UUID of the characteristic,
+/// and either the field or the struct must be annotated with the UUID of the service. UUIDs may be
+/// given either as integer literals, which are taken to be short BLE UUIDs, or as any expression of
+/// type `Uuid`, such as `uuid_from_u16(0x180f)`.
+///
+/// ```ignore
+/// use bluez_async::{uuid_from_u16, CharacteristicId};
+/// use bluez_async_derive::GattProfile;
+///
+/// #[derive(GattProfile)]
+/// #[gatt(service = uuid_from_u16(0x180f))]
+/// struct Battery {
+///     #[gatt(characteristic = uuid_from_u16(0x2a19), value = u8)]
+///     level: CharacteristicId,
+///     #[gatt(characteristic = 0x2a1a)]
+///     power_state: Option<CharacteristicId>,
+/// }
+/// ```
+///
+/// This generates:
+///
+/// - `async fn resolve(session: &BluetoothSession, device: &DeviceId) -> Result<Self, BluetoothError>`,
+///   which fetches the services and characteristics of the device once and fills in every field. If
+///   any of the characteristics for non-`Option` fields are missing then it fails with
+///   `BluetoothError::CharacteristicsNotFound` listing all of them.
+/// - For each field `foo`, methods `read_foo`, `write_foo` and `subscribe_foo`. The value type is
+///   given by the `value` attribute, which defaults to `Vec<u8>`, and must implement `GattValue`.
+///   For `Option` fields these fail with `BluetoothError::UuidNotFound` if the characteristic was
+///   not found.
+#[proc_macro_derive(GattProfile, attributes(gatt))]
+pub fn derive_gatt_profile(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_gatt_profile(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The contents of a `#[gatt(...)]` attribute.
+#[derive(Default)]
+struct GattAttribute {
+    service: Option<Expr>,
+    characteristic: Option<Expr>,
+    value: Option<Type>,
+}
+
+impl GattAttribute {
+    fn parse(attributes: &[Attribute]) -> Result<Self, Error> {
+        let mut gatt = Self::default();
+        for attribute in attributes {
+            if !attribute.path().is_ident("gatt") {
+                continue;
+            }
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("service") {
+                    gatt.service = Some(uuid_expression(meta.value()?.parse()?));
+                } else if meta.path.is_ident("characteristic") {
+                    gatt.characteristic = Some(uuid_expression(meta.value()?.parse()?));
+                } else if meta.path.is_ident("value") {
+                    gatt.value = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unsupported gatt attribute"));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(gatt)
+    }
+}
+
+/// Convert an integer literal to a UUID using `uuid_from_u16` or `uuid_from_u32`, or pass any other
+/// expression through unchanged.
+fn uuid_expression(expr: Expr) -> Expr {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Int(int), ..
+    }) = &expr
+    {
+        if int.base10_parse::<u16>().is_ok() {
+            return parse_quote!(::bluez_async::uuid_from_u16(#int));
+        } else if int.base10_parse::<u32>().is_ok() {
+            return parse_quote!(::bluez_async::uuid_from_u32(#int));
+        }
+    }
+    expr
+}
+
+/// Returns whether the given type is an `Option`.
+fn is_option(ty: &Type) -> bool {
+    if let Type::Path(path) = ty {
+        path.qself.is_none()
+            && path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Option")
+    } else {
+        false
+    }
+}
+
+fn expand_gatt_profile(input: DeriveInput) -> Result<TokenStream, Error> {
+    let name = &input.ident;
+    let struct_attribute = GattAttribute::parse(&input.attrs)?;
+    if let Some(characteristic) = &struct_attribute.characteristic {
+        return Err(Error::new(
+            characteristic.span(),
+            "characteristic must be specified on fields, not the struct",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new(
+                    Span::call_site(),
+                    "GattProfile can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "GattProfile can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut uuids = vec![];
+    let mut field_initialisers = vec![];
+    let mut accessors = vec![];
+    for (index, field) in fields.iter().enumerate() {
+        let field_name = field.ident.as_ref().unwrap();
+        let attribute = GattAttribute::parse(&field.attrs)?;
+        let service = attribute
+            .service
+            .or_else(|| struct_attribute.service.clone())
+            .ok_or_else(|| {
+                Error::new(
+                    field.span(),
+                    "no service UUID specified for field or struct, add #[gatt(service = ...)]",
+                )
+            })?;
+        let characteristic = attribute.characteristic.ok_or_else(|| {
+            Error::new(
+                field.span(),
+                "no characteristic UUID specified for field, add #[gatt(characteristic = ...)]",
+            )
+        })?;
+        let optional = is_option(&field.ty);
+        let value_type = attribute.value.unwrap_or_else(|| parse_quote!(Vec<u8>));
+
+        let required = !optional;
+        uuids.push(quote!((#service, #characteristic, #required)));
+        let resolved = quote!(characteristics[#index].take());
+        field_initialisers.push(if optional {
+            quote!(#field_name: #resolved)
+        } else {
+            quote!(#field_name: #resolved.unwrap())
+        });
+
+        let id = if optional {
+            quote!(::bluez_async::__private::get(self.#field_name.as_ref(), #characteristic)?)
+        } else {
+            quote!(&self.#field_name)
+        };
+        let read = format_ident!("read_{}", field_name);
+        let write = format_ident!("write_{}", field_name);
+        let subscribe = format_ident!("subscribe_{}", field_name);
+        let read_doc = format!("Read the value of the `{}` characteristic.", field_name);
+        let write_doc = format!("Write a value to the `{}` characteristic.", field_name);
+        let subscribe_doc = format!(
+            "Start notifications on the `{}` characteristic, and return a stream of its values.",
+            field_name
+        );
+        accessors.push(quote_spanned! {field.span()=>
+            #[doc = #read_doc]
+            pub async fn #read(
+                &self,
+                session: &::bluez_async::BluetoothSession,
+            ) -> ::std::result::Result<#value_type, ::bluez_async::BluetoothError> {
+                ::bluez_async::__private::read(session, #id).await
+            }
+
+            #[doc = #write_doc]
+            pub async fn #write(
+                &self,
+                session: &::bluez_async::BluetoothSession,
+                value: &#value_type,
+            ) -> ::std::result::Result<(), ::bluez_async::BluetoothError> {
+                ::bluez_async::__private::write(session, #id, value).await
+            }
+
+            #[doc = #subscribe_doc]
+            pub async fn #subscribe(
+                &self,
+                session: &::bluez_async::BluetoothSession,
+            ) -> ::std::result::Result<
+                impl ::bluez_async::__private::Stream<
+                    Item = ::std::result::Result<#value_type, ::bluez_async::BluetoothError>,
+                >,
+                ::bluez_async::BluetoothError,
+            > {
+                ::bluez_async::__private::subscribe(session, #id).await
+            }
+        });
+    }
+
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        // Not every profile will use every generated accessor.
+        #[allow(dead_code)]
+        impl #impl_generics #name #type_generics #where_clause {
+            /// Look up all the characteristics of this profile on the given device.
+            ///
+            /// The device must already be connected, and service discovery complete. If any
+            /// required characteristics are missing then this fails with
+            /// `BluetoothError::CharacteristicsNotFound` listing all of them.
+            pub async fn resolve(
+                session: &::bluez_async::BluetoothSession,
+                device: &::bluez_async::DeviceId,
+            ) -> ::std::result::Result<Self, ::bluez_async::BluetoothError> {
+                let mut characteristics =
+                    ::bluez_async::__private::resolve(session, device, &[#(#uuids),*]).await?;
+                ::std::result::Result::Ok(Self {
+                    #(#field_initialisers,)*
+                })
+            }
+
+            #(#accessors)*
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_error(input: DeriveInput) -> String {
+        expand_gatt_profile(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn expand_success() {
+        let output = expand_gatt_profile(parse_quote! {
+            #[gatt(service = 0x180f)]
+            struct Battery {
+                #[gatt(characteristic = 0x2a19, value = u8)]
+                level: CharacteristicId,
+                #[gatt(service = uuid_from_u16(0x1234), characteristic = 0x12345678)]
+                other: Option<CharacteristicId>,
+            }
+        })
+        .unwrap()
+        .to_string();
+        assert!(output.contains("pub async fn resolve"));
+        assert!(output.contains("pub async fn read_level"));
+        assert!(output.contains("pub async fn write_other"));
+        assert!(output.contains("pub async fn subscribe_other"));
+        assert!(output.contains(":: bluez_async :: uuid_from_u16 (0x180f)"));
+        assert!(output.contains(":: bluez_async :: uuid_from_u32 (0x12345678)"));
+        assert!(output.contains("uuid_from_u16 (0x1234)"));
+    }
+
+    #[test]
+    fn missing_service() {
+        assert!(expand_error(parse_quote! {
+            struct Battery {
+                #[gatt(characteristic = 0x2a19)]
+                level: CharacteristicId,
+            }
+        })
+        .contains("no service UUID"));
+    }
+
+    #[test]
+    fn missing_characteristic() {
+        assert!(expand_error(parse_quote! {
+            #[gatt(service = 0x180f)]
+            struct Battery {
+                level: CharacteristicId,
+            }
+        })
+        .contains("no characteristic UUID"));
+    }
+
+    #[test]
+    fn unsupported_attribute() {
+        assert!(expand_error(parse_quote! {
+            #[gatt(service = 0x180f, foo = 42)]
+            struct Battery {}
+        })
+        .contains("unsupported gatt attribute"));
+    }
+
+    #[test]
+    fn tuple_struct() {
+        assert!(expand_error(parse_quote! {
+            #[gatt(service = 0x180f)]
+            struct Battery(CharacteristicId);
+        })
+        .contains("named fields"));
+    }
+
+    #[test]
+    fn option_detection() {
+        assert!(is_option(&parse_quote!(Option<CharacteristicId>)));
+        assert!(is_option(&parse_quote!(
+            std::option::Option<CharacteristicId>
+        )));
+        assert!(!is_option(&parse_quote!(CharacteristicId)));
+    }
+}
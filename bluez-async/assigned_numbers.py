@@ -0,0 +1,133 @@
+#!/usr/bin/env python3
+"""Generates src/assigned_numbers/generated.rs and src/appearance/generated.rs from the Bluetooth
+SIG assigned numbers.
+
+Usage:
+    git clone https://bitbucket.org/bluetooth-SIG/public.git bluetooth-sig
+    ./assigned_numbers.py bluetooth-sig [--revision REVISION]
+
+The source revision recorded in the generated file is taken from the checkout's git HEAD, unless
+it is given with --revision.
+
+Requires PyYAML.
+"""
+
+import argparse
+import json
+import os
+import subprocess
+
+import yaml
+
+SRC = os.path.join(os.path.dirname(__file__), "src")
+
+
+def load(root, path, key):
+    with open(os.path.join(root, "assigned_numbers", path), encoding="utf-8") as f:
+        return yaml.safe_load(f)[key]
+
+
+def uuids(root, path):
+    return [(entry["uuid"], entry["name"]) for entry in load(root, path, "uuids")]
+
+
+def companies(root):
+    return [
+        (entry["value"], entry["name"])
+        for entry in load(root, "company_identifiers/company_identifiers.yaml", "company_identifiers")
+    ]
+
+
+def appearances(root):
+    values = []
+    for category in load(root, "core/appearance_values.yaml", "appearance_values"):
+        value = category["category"] << 6
+        values.append((value, category["name"]))
+        for subcategory in category.get("subcategory", []):
+            values.append(
+                (value | subcategory["value"], f"{category['name']}: {subcategory['name']}")
+            )
+    return values
+
+
+def subcategories(root):
+    return [
+        ((category["category"] << 6) | subcategory["value"], subcategory["name"])
+        for category in load(root, "core/appearance_values.yaml", "appearance_values")
+        for subcategory in category.get("subcategory", [])
+    ]
+
+
+def table(name, doc, entries):
+    # Later entries take precedence, in case of duplicates.
+    entries = sorted(dict(entries).items())
+    lines = [f"/// {doc}", f"pub(super) const {name}: &[(u16, &str)] = &["]
+    for value, entry_name in entries:
+        lines.append(f"    (0x{value:04x}, {json.dumps(entry_name.strip(), ensure_ascii=False)}),")
+    lines.append("];")
+    return "\n".join(lines)
+
+
+def revision(root):
+    return subprocess.run(
+        ["git", "-C", root, "rev-parse", "HEAD"], capture_output=True, check=True, text=True
+    ).stdout.strip()
+
+
+def main():
+    parser = argparse.ArgumentParser(
+        description=__doc__, formatter_class=argparse.RawDescriptionHelpFormatter
+    )
+    parser.add_argument("root", help="checkout of the Bluetooth SIG public repository")
+    parser.add_argument("--revision", help="source revision to record in the generated file")
+    args = parser.parse_args()
+    root = args.root
+    source_revision = args.revision or revision(root)
+    write(
+        os.path.join(SRC, "assigned_numbers", "generated.rs"),
+        source_revision,
+        [
+            table("SERVICES", "GATT service 16-bit UUIDs.", uuids(root, "uuids/service_uuids.yaml")),
+            table(
+                "CHARACTERISTICS",
+                "GATT characteristic 16-bit UUIDs.",
+                uuids(root, "uuids/characteristic_uuids.yaml"),
+            ),
+            table(
+                "DESCRIPTORS", "GATT descriptor 16-bit UUIDs.", uuids(root, "uuids/descriptors.yaml")
+            ),
+            table(
+                "MEMBERS",
+                "16-bit UUIDs assigned to SIG members.",
+                uuids(root, "uuids/member_uuids.yaml"),
+            ),
+            table("COMPANIES", "Company identifiers.", companies(root)),
+            table("APPEARANCES", "GAP appearance values.", appearances(root)),
+        ],
+    )
+    write(
+        os.path.join(SRC, "appearance", "generated.rs"),
+        source_revision,
+        [
+            table(
+                "SUBCATEGORIES",
+                "GAP appearance subcategory names, keyed by the full appearance value.",
+                subcategories(root),
+            )
+        ],
+    )
+
+
+def write(path, source_revision, tables):
+    with open(path, "w", encoding="utf-8") as f:
+        f.write(
+            "// This file is generated by assigned_numbers.py from the Bluetooth SIG assigned numbers.\n"
+            f"// Source revision: {source_revision}\n"
+            "// Do not edit it by hand.\n\n"
+        )
+        f.write("\n\n".join(tables))
+        f.write("\n")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,37 @@
+//! Example to log beacon advertisements (iBeacon, AltBeacon and Eddystone) from nearby devices.
+
+use bluez_async::advertisement::Beacon;
+use bluez_async::{BluetoothEvent, BluetoothSession, DiscoveryFilter};
+use futures::stream::StreamExt;
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Report> {
+    pretty_env_logger::init();
+
+    let (_, session) = BluetoothSession::new().await?;
+    let mut events = session.event_stream().await?;
+    // Beacons may change their advertisement data, e.g. Eddystone beacons cycle between frame types.
+    session
+        .start_discovery_with_filter(&DiscoveryFilter {
+            duplicate_data: Some(true),
+            ..DiscoveryFilter::default()
+        })
+        .await?;
+
+    // Devices which were already known won't necessarily send events, so check them first.
+    for device in session.get_devices().await? {
+        for beacon in Beacon::from_device_info(&device) {
+            println!("{}: {:?}", device.id, beacon);
+        }
+    }
+
+    while let Some(event) = events.next().await {
+        if let BluetoothEvent::Device { id, event } = event {
+            for beacon in Beacon::from_device_event(&event) {
+                println!("{}: {:?}", id, beacon);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//! Example to broadcast an iBeacon from the first Bluetooth adapter, e.g. to act as a location
+//! anchor.
+
+use bluez_async::advertisement::{Beacon, IBeacon};
+use bluez_async::BluetoothSession;
+use std::time::Duration;
+use tokio::time;
+use uuid::Uuid;
+
+const BEACON_UUID: Uuid = Uuid::from_u128(0xe2c56db5_dffb_48d2_b060_d0f5a71096e0);
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Report> {
+    pretty_env_logger::init();
+
+    let (_, session) = BluetoothSession::new().await?;
+    let adapter = session
+        .get_adapters()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| eyre::eyre!("No Bluetooth adapters found"))?;
+
+    let beacon = Beacon::IBeacon(IBeacon {
+        uuid: BEACON_UUID,
+        major: 1,
+        minor: 2,
+        measured_power: -59,
+    });
+    let advertisement = beacon
+        .to_advertisement()
+        .ok_or_else(|| eyre::eyre!("Beacon can't be encoded"))?;
+    let handle = session
+        .start_advertising(&adapter.id, &advertisement)
+        .await?;
+    println!("Broadcasting {:?} from {}", beacon, adapter.id);
+
+    time::sleep(Duration::from_secs(60)).await;
+    handle.unregister().await?;
+    Ok(())
+}
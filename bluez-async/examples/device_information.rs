@@ -0,0 +1,20 @@
+//! Example to read the Device Information Service of all connected devices which support it.
+
+use bluez_async::BluetoothSession;
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Report> {
+    pretty_env_logger::init();
+
+    let (_, session) = BluetoothSession::new().await?;
+
+    let devices = session.get_devices().await?;
+    for device in devices.into_iter().filter(|device| device.connected) {
+        match session.read_device_information(&device.id).await {
+            Ok(information) => println!("{}: {:#?}", device.mac_address, information),
+            Err(e) => println!("{}: {}", device.mac_address, e),
+        }
+    }
+
+    Ok(())
+}
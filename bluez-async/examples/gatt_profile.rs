@@ -0,0 +1,45 @@
+//! Example to read the battery level of all connected devices which support the Battery Service,
+//! using a profile declared with `#[derive(GattProfile)]`.
+
+use bluez_async::{BluetoothSession, CharacteristicId};
+use bluez_async_derive::GattProfile;
+
+#[derive(Debug, GattProfile)]
+#[gatt(service = 0x180f)]
+struct BatteryProfile {
+    /// The battery level as a percentage.
+    #[gatt(characteristic = 0x2a19, value = u8)]
+    level: CharacteristicId,
+    /// The battery power state, which many devices don't support.
+    #[gatt(characteristic = 0x2a1a)]
+    power_state: Option<CharacteristicId>,
+}
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Report> {
+    pretty_env_logger::init();
+
+    let (_, session) = BluetoothSession::new().await?;
+
+    let devices = session.get_devices().await?;
+    for device in devices.into_iter().filter(|device| device.connected) {
+        match BatteryProfile::resolve(&session, &device.id).await {
+            Ok(battery) => {
+                println!(
+                    "{}: battery level {}%",
+                    device.mac_address,
+                    battery.read_level(&session).await?
+                );
+                if battery.power_state.is_some() {
+                    println!(
+                        "  power state {:?}",
+                        battery.read_power_state(&session).await?
+                    );
+                }
+            }
+            Err(e) => println!("{}: {}", device.mac_address, e),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+//! Example to export a local GATT service, with a battery level characteristic which can be read
+//! or subscribed to, and a writable characteristic which logs whatever is written to it. The
+//! services are advertised so that remote devices can find them.
+
+use bluez_async::{
+    uuid_from_u16, Advertisement, AdvertisementType, BluetoothSession, CharacteristicFlags,
+    DescriptorFlags, GattApplication, GattError, LocalCharacteristic, LocalDescriptor,
+    LocalService,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use tokio::time;
+use uuid::Uuid;
+
+const SERVICE_UUID: Uuid = Uuid::from_u128(0x4b0aa0b2_28f6_4a4b_9d3e_4a8b1b5e0001);
+const LOG_CHARACTERISTIC_UUID: Uuid = Uuid::from_u128(0x4b0aa0b2_28f6_4a4b_9d3e_4a8b1b5e0002);
+const USER_DESCRIPTION_UUID: Uuid = uuid_from_u16(0x2901);
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Report> {
+    pretty_env_logger::init();
+
+    let (_, session) = BluetoothSession::new().await?;
+    let adapter = session
+        .get_adapters()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| eyre::eyre!("No Bluetooth adapters found"))?;
+
+    let battery_level = LocalCharacteristic::new(
+        uuid_from_u16(0x2a19),
+        CharacteristicFlags::READ | CharacteristicFlags::NOTIFY,
+    )
+    .initial_value(vec![100]);
+    let battery_notifier = battery_level.notifier();
+    let application = GattApplication::new()
+        .service(LocalService::new(uuid_from_u16(0x180f)).characteristic(battery_level))
+        .service(
+            LocalService::new(SERVICE_UUID).characteristic(
+                LocalCharacteristic::new(LOG_CHARACTERISTIC_UUID, CharacteristicFlags::WRITE)
+                    .on_write(|value, request| async move {
+                        let message = String::from_utf8(value)
+                            .map_err(|e| GattError::Failed(e.to_string()))?;
+                        println!("{:?} wrote {:?}", request.device, message);
+                        Ok(())
+                    })
+                    .descriptor(
+                        LocalDescriptor::new(USER_DESCRIPTION_UUID, DescriptorFlags::READ)
+                            .on_read(|_| async { Ok(b"Log".to_vec()) }),
+                    ),
+            ),
+        );
+
+    let handle = session
+        .register_gatt_application(&adapter.id, &application)
+        .await?;
+    println!("Registered GATT application on {}", adapter.id);
+
+    let advertisement = Advertisement::new(AdvertisementType::Peripheral)
+        .service_uuid(uuid_from_u16(0x180f))
+        .service_uuid(SERVICE_UUID)
+        .discoverable(true);
+    let advertisement_handle = session
+        .start_advertising(&adapter.id, &advertisement)
+        .await?;
+
+    let mut subscription_events = battery_notifier.subscription_events();
+    tokio::spawn(async move {
+        while let Some(event) = subscription_events.next().await {
+            println!("Battery level subscription: {:?}", event);
+        }
+    });
+
+    // Drain the battery slowly while the application is registered, notifying subscribers.
+    for level in (0..100).rev() {
+        time::sleep(Duration::from_secs(3)).await;
+        battery_notifier.notify(vec![level])?;
+    }
+
+    advertisement_handle.unregister().await?;
+    handle.unregister().await?;
+    Ok(())
+}
@@ -0,0 +1,50 @@
+//! Example to show what is playing on a connected phone via AVRCP, and print changes to the track
+//! and playback status.
+
+use bluez_async::{BluetoothEvent, BluetoothSession, MediaPlayerEvent};
+use futures::stream::StreamExt;
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Report> {
+    pretty_env_logger::init();
+
+    let (_, session) = BluetoothSession::new().await?;
+
+    // Find the first connected device with an active media player.
+    let mut player = None;
+    for device in session.get_devices().await? {
+        if device.connected {
+            if let Ok(Some(id)) = session.get_media_player(&device.id).await {
+                player = Some((device, id));
+                break;
+            }
+        }
+    }
+    let (device, player) = player.ok_or_else(|| eyre::eyre!("No media player found"))?;
+
+    let info = session.get_media_player_info(&player).await?;
+    println!(
+        "{} ({:?}) is {:?}: {:?} by {:?}",
+        device.alias.unwrap_or_default(),
+        info.name,
+        info.status,
+        info.track.title,
+        info.track.artist
+    );
+
+    let mut events = session.device_event_stream(&device.id).await?;
+    while let Some(event) = events.next().await {
+        if let BluetoothEvent::MediaPlayer { id, event } = event {
+            match event {
+                MediaPlayerEvent::Track { track } => println!(
+                    "{} now playing {:?} by {:?} from {:?} ({:?})",
+                    id, track.title, track.artist, track.album, track.duration
+                ),
+                MediaPlayerEvent::Status { status } => println!("{} is {:?}", id, status),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -8,51 +8,10 @@
 //!
 //! [RuuviTag]: https://ruuvi.com/ruuvitag-specs/
 
-use std::collections::HashMap;
-
+use bluez_async::advertisement::{RuuviTag, RUUVI_COMPANY_ID};
 use bluez_async::{BluetoothEvent, BluetoothSession, DeviceEvent, DiscoveryFilter};
 use futures::stream::StreamExt;
 
-/// The [Bluetooth company identifier](https://www.bluetooth.com/specifications/assigned-numbers/company-identifiers/)
-/// of Ruuvi Innovations Ltd.
-const RUUVI_ID: u16 = 0x0499;
-
-/// Protocol version of RuuviTags' data format
-/// [RAWv2](https://github.com/ruuvi/ruuvi-sensor-protocols/blob/master/dataformat_05.md)
-const PROTOCOL_VERSION: u8 = 0x05;
-
-/// Search for manufacturer data from a Ruuvi device with protocol version 5.
-fn get_ruuvi_data(mdata: &HashMap<u16, Vec<u8>>) -> Option<&Vec<u8>> {
-    match mdata.get(&RUUVI_ID) {
-        Some(data) if !data.is_empty() && data[0] == PROTOCOL_VERSION => Some(data),
-        _ => None,
-    }
-}
-
-/// Temperature in `°C`.
-fn temperature(data: &[u8]) -> f64 {
-    assert!(data.len() >= 3);
-    let value = [data[1], data[2]];
-    let value = u16::from_be_bytes(value);
-    (value as f64) * 0.005
-}
-
-/// Humidity in `%`.
-fn humidity(data: &[u8]) -> f64 {
-    assert!(data.len() >= 5);
-    let value = [data[3], data[4]];
-    let value = u16::from_be_bytes(value);
-    (value as f64) * 0.0025
-}
-
-/// Pressure in `Pa`.
-fn pressure(data: &[u8]) -> f64 {
-    assert!(data.len() >= 7);
-    let value = [data[5], data[6]];
-    let value = u16::from_be_bytes(value);
-    (value as f64) + 50_000_f64
-}
-
 #[tokio::main]
 async fn main() -> Result<(), eyre::Report> {
     pretty_env_logger::init();
@@ -68,24 +27,30 @@ async fn main() -> Result<(), eyre::Report> {
         .await?;
 
     while let Some(event) = events.next().await {
-        match event {
-            BluetoothEvent::Device {
-                id,
-                event: DeviceEvent::ManufacturerData { manufacturer_data },
-            } => {
-                if let Some(data) = get_ruuvi_data(&manufacturer_data) {
-                    let t = temperature(data);
-                    let h = humidity(data);
-                    let p = pressure(data);
-                    println!(
-                        "RuuviTag {} measured: t = {:6.2} °C, h = {:6.2} %, p = {:6} Pa",
-                        id, t, h, p
-                    );
-                }
+        if let BluetoothEvent::Device {
+            id,
+            event: DeviceEvent::ManufacturerData { manufacturer_data },
+        } = event
+        {
+            if let Some(tag) = manufacturer_data
+                .get(&RUUVI_COMPANY_ID)
+                .and_then(|data| RuuviTag::from_manufacturer_data(RUUVI_COMPANY_ID, data))
+            {
+                let t = show(tag.temperature.map(|t| format!("{:.2}", t)));
+                let h = show(tag.humidity.map(|h| format!("{:.2}", h)));
+                let p = show(tag.pressure.map(|p| p.to_string()));
+                println!(
+                    "RuuviTag {} measured: t = {:>6} °C, h = {:>6} %, p = {:>6} Pa",
+                    id, t, h, p
+                );
             }
-            _ => {}
         }
     }
 
     Ok(())
 }
+
+/// Formats a measurement which the tag may not have reported, showing `-` if it is missing.
+fn show(measurement: Option<String>) -> String {
+    measurement.unwrap_or_else(|| "-".to_string())
+}
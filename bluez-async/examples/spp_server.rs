@@ -0,0 +1,51 @@
+//! Example to register a Serial Port Profile (SPP) server, and echo back whatever connected devices
+//! send.
+
+use bluez_async::{
+    BluetoothSession, ProfileEvent, ProfileOptions, ProfileRole, SERIAL_PORT_PROFILE_UUID,
+};
+use futures::StreamExt;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+#[tokio::main]
+async fn main() -> Result<(), eyre::Report> {
+    pretty_env_logger::init();
+
+    let (_, session) = BluetoothSession::new().await?;
+    let options = ProfileOptions {
+        name: Some("Serial Port".to_string()),
+        role: Some(ProfileRole::Server),
+        channel: Some(1),
+        ..Default::default()
+    };
+    let mut events = session
+        .register_profile(SERIAL_PORT_PROFILE_UUID, &options)
+        .await?;
+    println!("Registered SPP server");
+
+    while let Some(event) = events.next().await {
+        match event {
+            ProfileEvent::NewConnection(mut connection) => {
+                println!("{} connected", connection.device);
+                tokio::spawn(async move {
+                    let mut buffer = [0; 256];
+                    while let Ok(length @ 1..) = connection.stream.read(&mut buffer).await {
+                        println!("{} sent {:?}", connection.device, &buffer[..length]);
+                        if connection
+                            .stream
+                            .write_all(&buffer[..length])
+                            .await
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                    println!("{} disconnected", connection.device);
+                });
+            }
+            event => println!("{:?}", event),
+        }
+    }
+
+    Ok(())
+}
@@ -1,10 +1,10 @@
-use bluez_generated::OrgBluezAdapter1Properties;
+use bluez_generated::{OrgBluezAdapter1Properties, OrgBluezLEAdvertisingManager1Properties};
 use dbus::Path;
 use serde::{Deserialize, Serialize};
 use std::fmt::{self, Display, Formatter};
 
 use crate::Modalias;
-use crate::{AddressType, BluetoothError, MacAddress};
+use crate::{AddressType, AdvertisingCapabilities, BluetoothError, MacAddress};
 
 /// Opaque identifier for a Bluetooth adapter on the system.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -59,12 +59,15 @@ pub struct AdapterInfo {
     pub powered: bool,
     /// Whether the adapter is currently discovering devices.
     pub discovering: bool,
+    /// The LE advertising capabilities of the adapter, if BlueZ supports LE advertising on it.
+    pub advertising: Option<AdvertisingCapabilities>,
 }
 
 impl AdapterInfo {
     pub(crate) fn from_properties(
         id: AdapterId,
         adapter_properties: OrgBluezAdapter1Properties,
+        advertising_properties: Option<OrgBluezLEAdvertisingManager1Properties>,
     ) -> Result<AdapterInfo, BluetoothError> {
         let mac_address = adapter_properties
             .address()
@@ -78,6 +81,16 @@ impl AdapterInfo {
             .modalias()
             .ok_or(BluetoothError::RequiredPropertyMissing("Modalias"))?
             .parse()?;
+        // Don't fail to report the adapter just because its advertising capabilities are invalid.
+        let advertising = match advertising_properties.map(AdvertisingCapabilities::from_properties)
+        {
+            Some(Ok(advertising)) => Some(advertising),
+            Some(Err(e)) => {
+                log::warn!("Invalid advertising capabilities for adapter {}: {}", id, e);
+                None
+            }
+            None => None,
+        };
 
         Ok(AdapterInfo {
             id,
@@ -98,6 +111,7 @@ impl AdapterInfo {
             discovering: adapter_properties
                 .discovering()
                 .ok_or(BluetoothError::RequiredPropertyMissing("Discovering"))?,
+            advertising,
         })
     }
 }
@@ -108,10 +122,10 @@ mod tests {
     use std::collections::HashMap;
 
     use super::*;
+    use crate::ModaliasSource;
 
-    #[test]
-    fn adapter_info_minimal() {
-        let id = AdapterId::new("/org/bluez/hci0");
+    /// The properties which BlueZ always reports for an adapter.
+    fn adapter_properties() -> PropMap {
         let mut adapter_properties: PropMap = HashMap::new();
         adapter_properties.insert(
             "Address".to_string(),
@@ -129,10 +143,18 @@ mod tests {
         );
         adapter_properties.insert("Powered".to_string(), Variant(Box::new(false)));
         adapter_properties.insert("Discovering".to_string(), Variant(Box::new(false)));
+        adapter_properties
+    }
+
+    #[test]
+    fn adapter_info_minimal() {
+        let id = AdapterId::new("/org/bluez/hci0");
+        let adapter_properties = adapter_properties();
 
         let adapter = AdapterInfo::from_properties(
             id.clone(),
             OrgBluezAdapter1Properties(&adapter_properties),
+            None,
         )
         .unwrap();
         assert_eq!(
@@ -144,16 +166,36 @@ mod tests {
                 name: "name".to_string(),
                 alias: "alias".to_string(),
                 modalias: Modalias {
+                    source: ModaliasSource::Usb,
                     vendor_id: 0x1234,
                     product_id: 0x5678,
                     device_id: 0x90ab
                 },
                 powered: false,
-                discovering: false
+                discovering: false,
+                advertising: None,
             }
         )
     }
 
+    #[test]
+    fn adapter_info_invalid_advertising() {
+        let id = AdapterId::new("/org/bluez/hci0");
+        let adapter_properties = adapter_properties();
+        // ActiveInstances and SupportedInstances are missing.
+        let advertising_properties: PropMap = HashMap::new();
+
+        let adapter = AdapterInfo::from_properties(
+            id,
+            OrgBluezAdapter1Properties(&adapter_properties),
+            Some(OrgBluezLEAdvertisingManager1Properties(
+                &advertising_properties,
+            )),
+        )
+        .unwrap();
+        assert_eq!(adapter.advertising, None);
+    }
+
     #[test]
     fn to_string() {
         let adapter_id = AdapterId::new("/org/bluez/hci0");
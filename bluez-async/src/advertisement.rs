@@ -0,0 +1,282 @@
+//! Decoders for common advertisement formats, such as beacons.
+//!
+//! These work from the raw manufacturer-specific data and service data maps found in
+//! [`DeviceInfo`](../struct.DeviceInfo.html) and in
+//! [`DeviceEvent::ManufacturerData`](../enum.DeviceEvent.html#variant.ManufacturerData) and
+//! [`DeviceEvent::ServiceData`](../enum.DeviceEvent.html#variant.ServiceData) events.
+//!
+//! Decoders for sensor advertisements are available with the `sensors` cargo feature.
+//!
+//! Beacons can also be encoded, and broadcast from a local adapter with
+//! [`Beacon::to_advertisement`].
+
+mod altbeacon;
+#[cfg(feature = "sensors")]
+mod bthome;
+mod eddystone;
+mod ibeacon;
+#[cfg(feature = "sensors")]
+mod mibeacon;
+#[cfg(feature = "sensors")]
+mod ruuvi;
+
+pub use self::altbeacon::AltBeacon;
+#[cfg(feature = "sensors")]
+pub use self::bthome::{BTHome, BTHomeMeasurement, BTHomeValue, BTHOME_SERVICE_UUID};
+pub use self::eddystone::{Eddystone, EddystoneTlm, EDDYSTONE_SERVICE_UUID};
+pub use self::ibeacon::{IBeacon, APPLE_COMPANY_ID};
+#[cfg(feature = "sensors")]
+pub use self::mibeacon::{MiBeacon, MiBeaconMeasurement, MIBEACON_SERVICE_UUID};
+#[cfg(feature = "sensors")]
+pub use self::ruuvi::{Acceleration, RuuviTag, RUUVI_COMPANY_ID};
+
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{Advertisement, AdvertisementType, DeviceEvent, DeviceInfo};
+
+/// A beacon advertisement in one of the supported formats.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Beacon {
+    /// An Apple iBeacon.
+    IBeacon(IBeacon),
+    /// An AltBeacon.
+    AltBeacon(AltBeacon),
+    /// A Google Eddystone frame.
+    Eddystone(Eddystone),
+}
+
+impl Beacon {
+    /// Decode all the beacons found in the given manufacturer-specific advertisement data.
+    pub fn from_manufacturer_data(manufacturer_data: &HashMap<u16, Vec<u8>>) -> Vec<Self> {
+        let mut beacons = vec![];
+        for (&company_id, data) in manufacturer_data {
+            if let Some(ibeacon) = IBeacon::from_manufacturer_data(company_id, data) {
+                beacons.push(Self::IBeacon(ibeacon));
+            } else if let Some(altbeacon) = AltBeacon::from_manufacturer_data(company_id, data) {
+                beacons.push(Self::AltBeacon(altbeacon));
+            }
+        }
+        beacons
+    }
+
+    /// Decode all the beacons found in the given GATT service advertisement data.
+    pub fn from_service_data(service_data: &HashMap<Uuid, Vec<u8>>) -> Vec<Self> {
+        service_data
+            .get(&EDDYSTONE_SERVICE_UUID)
+            .and_then(|data| Eddystone::from_service_data(data))
+            .map(Self::Eddystone)
+            .into_iter()
+            .collect()
+    }
+
+    /// Decode all the beacons found in the advertisement data of the given device.
+    pub fn from_device_info(device: &DeviceInfo) -> Vec<Self> {
+        let mut beacons = Self::from_manufacturer_data(&device.manufacturer_data);
+        beacons.extend(Self::from_service_data(&device.service_data));
+        beacons
+    }
+
+    /// Decode all the beacons found in the given event, if it is a
+    /// `DeviceEvent::ManufacturerData` or `DeviceEvent::ServiceData` event.
+    pub fn from_device_event(event: &DeviceEvent) -> Vec<Self> {
+        match event {
+            DeviceEvent::ManufacturerData { manufacturer_data } => {
+                Self::from_manufacturer_data(manufacturer_data)
+            }
+            DeviceEvent::ServiceData { service_data } => Self::from_service_data(service_data),
+            _ => vec![],
+        }
+    }
+
+    /// Build a broadcast advertisement for the beacon, to send with
+    /// [`BluetoothSession::start_advertising`](../struct.BluetoothSession.html#method.start_advertising).
+    ///
+    /// Returns `None` if the beacon can't be encoded, i.e. an Eddystone-URL frame with an
+    /// unsupported URL.
+    pub fn to_advertisement(&self) -> Option<Advertisement> {
+        let advertisement = Advertisement::new(AdvertisementType::Broadcast);
+        Some(match self {
+            Self::IBeacon(ibeacon) => {
+                advertisement.manufacturer_data(APPLE_COMPANY_ID, ibeacon.to_manufacturer_data())
+            }
+            Self::AltBeacon(altbeacon) => advertisement
+                .manufacturer_data(altbeacon.manufacturer_id, altbeacon.to_manufacturer_data()),
+            // Eddystone requires the service UUID to be listed as well as the service data.
+            Self::Eddystone(eddystone) => advertisement
+                .service_uuid(EDDYSTONE_SERVICE_UUID)
+                .service_data(EDDYSTONE_SERVICE_UUID, eddystone.to_service_data()?),
+        })
+    }
+}
+
+/// Sensor measurements from an advertisement in one of the supported formats.
+#[cfg(feature = "sensors")]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Sensor {
+    /// A RuuviTag, in the RAWv1 or RAWv2 format.
+    RuuviTag(RuuviTag),
+    /// A BTHome v2 device.
+    BTHome(BTHome),
+    /// A Xiaomi device using unencrypted MiBeacon advertisements.
+    MiBeacon(MiBeacon),
+}
+
+#[cfg(feature = "sensors")]
+impl Sensor {
+    /// Decode all the sensor measurements found in the given manufacturer-specific advertisement
+    /// data.
+    pub fn from_manufacturer_data(manufacturer_data: &HashMap<u16, Vec<u8>>) -> Vec<Self> {
+        manufacturer_data
+            .iter()
+            .filter_map(|(&company_id, data)| RuuviTag::from_manufacturer_data(company_id, data))
+            .map(Self::RuuviTag)
+            .collect()
+    }
+
+    /// Decode all the sensor measurements found in the given GATT service advertisement data.
+    pub fn from_service_data(service_data: &HashMap<Uuid, Vec<u8>>) -> Vec<Self> {
+        let mut sensors = vec![];
+        if let Some(bthome) = service_data
+            .get(&BTHOME_SERVICE_UUID)
+            .and_then(|data| BTHome::from_service_data(data))
+        {
+            sensors.push(Self::BTHome(bthome));
+        }
+        if let Some(mibeacon) = service_data
+            .get(&MIBEACON_SERVICE_UUID)
+            .and_then(|data| MiBeacon::from_service_data(data))
+        {
+            sensors.push(Self::MiBeacon(mibeacon));
+        }
+        sensors
+    }
+
+    /// Decode all the sensor measurements found in the advertisement data of the given device.
+    pub fn from_device_info(device: &DeviceInfo) -> Vec<Self> {
+        let mut sensors = Self::from_manufacturer_data(&device.manufacturer_data);
+        sensors.extend(Self::from_service_data(&device.service_data));
+        sensors
+    }
+
+    /// Decode all the sensor measurements found in the given event, if it is a
+    /// `DeviceEvent::ManufacturerData` or `DeviceEvent::ServiceData` event.
+    pub fn from_device_event(event: &DeviceEvent) -> Vec<Self> {
+        match event {
+            DeviceEvent::ManufacturerData { manufacturer_data } => {
+                Self::from_manufacturer_data(manufacturer_data)
+            }
+            DeviceEvent::ServiceData { service_data } => Self::from_service_data(service_data),
+            _ => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uuid_from_u16;
+
+    #[test]
+    fn from_device_event() {
+        let mut manufacturer_data = HashMap::new();
+        manufacturer_data.insert(
+            APPLE_COMPANY_ID,
+            vec![
+                0x02, 0x15, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c,
+                0x0d, 0x0e, 0x0f, 0x10, 0x00, 0x01, 0x00, 0x02, 0xc5,
+            ],
+        );
+        manufacturer_data.insert(0x1234, vec![1, 2, 3]);
+        let beacons =
+            Beacon::from_device_event(&DeviceEvent::ManufacturerData { manufacturer_data });
+        assert_eq!(
+            beacons,
+            vec![Beacon::IBeacon(IBeacon {
+                uuid: Uuid::from_u128(0x0102030405060708090a0b0c0d0e0f10),
+                major: 1,
+                minor: 2,
+                measured_power: -59,
+            })]
+        );
+
+        let mut service_data = HashMap::new();
+        service_data.insert(
+            EDDYSTONE_SERVICE_UUID,
+            vec![0x10, 0xeb, 0x03, b'g', b'o', b'o', b'.', b'g', b'l'],
+        );
+        service_data.insert(uuid_from_u16(0x1234), vec![1, 2, 3]);
+        let beacons = Beacon::from_device_event(&DeviceEvent::ServiceData { service_data });
+        assert_eq!(
+            beacons,
+            vec![Beacon::Eddystone(Eddystone::Url {
+                tx_power: -21,
+                url: "https://goo.gl".to_string(),
+            })]
+        );
+
+        assert_eq!(Beacon::from_device_event(&DeviceEvent::Discovered), vec![]);
+    }
+
+    #[test]
+    fn to_advertisement() {
+        let ibeacon = IBeacon {
+            uuid: Uuid::from_u128(0x0102030405060708090a0b0c0d0e0f10),
+            major: 1,
+            minor: 2,
+            measured_power: -59,
+        };
+        assert_eq!(
+            Beacon::IBeacon(ibeacon).to_advertisement(),
+            Some(
+                Advertisement::new(AdvertisementType::Broadcast)
+                    .manufacturer_data(APPLE_COMPANY_ID, ibeacon.to_manufacturer_data())
+            )
+        );
+
+        let eddystone = Eddystone::Url {
+            tx_power: -21,
+            url: "https://goo.gl".to_string(),
+        };
+        assert_eq!(
+            Beacon::Eddystone(eddystone).to_advertisement(),
+            Some(
+                Advertisement::new(AdvertisementType::Broadcast)
+                    .service_uuid(EDDYSTONE_SERVICE_UUID)
+                    .service_data(
+                        EDDYSTONE_SERVICE_UUID,
+                        vec![0x10, 0xeb, 0x03, b'g', b'o', b'o', b'.', b'g', b'l']
+                    )
+            )
+        );
+        assert_eq!(
+            Beacon::Eddystone(Eddystone::Url {
+                tx_power: 0,
+                url: "gopher://example".to_string(),
+            })
+            .to_advertisement(),
+            None
+        );
+    }
+
+    #[cfg(feature = "sensors")]
+    #[test]
+    fn sensor_from_device_event() {
+        let mut service_data = HashMap::new();
+        service_data.insert(BTHOME_SERVICE_UUID, vec![0x40, 0x01, 0x64]);
+        service_data.insert(
+            MIBEACON_SERVICE_UUID,
+            vec![0x40, 0x20, 0x98, 0x00, 0x01, 0x0a, 0x10, 0x01, 0x5d],
+        );
+        let sensors = Sensor::from_device_event(&DeviceEvent::ServiceData { service_data });
+        assert_eq!(sensors.len(), 2);
+        assert!(matches!(sensors[0], Sensor::BTHome(_)));
+        assert!(matches!(sensors[1], Sensor::MiBeacon(_)));
+
+        let mut manufacturer_data = HashMap::new();
+        manufacturer_data.insert(RUUVI_COMPANY_ID, vec![0x05; 24]);
+        let sensors =
+            Sensor::from_device_event(&DeviceEvent::ManufacturerData { manufacturer_data });
+        assert!(matches!(sensors[..], [Sensor::RuuviTag(_)]));
+    }
+}
@@ -0,0 +1,86 @@
+use std::convert::TryInto;
+
+/// The beacon code which starts every AltBeacon advertisement.
+const ALTBEACON_CODE: [u8; 2] = [0xbe, 0xac];
+
+/// An [AltBeacon](https://github.com/AltBeacon/spec) advertisement.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct AltBeacon {
+    /// The company identifier of the beacon's manufacturer.
+    pub manufacturer_id: u16,
+    /// The 20-byte beacon identifier. By convention the first 16 bytes are an organisational unit
+    /// UUID, but this is not required.
+    pub beacon_id: [u8; 20],
+    /// The average received signal strength at 1 metre from the beacon, in dBm.
+    pub reference_rssi: i8,
+    /// A byte reserved for use by the manufacturer.
+    pub manufacturer_reserved: u8,
+}
+
+impl AltBeacon {
+    /// Decode an AltBeacon from the manufacturer-specific data for the given company ID, if it is
+    /// one.
+    pub fn from_manufacturer_data(company_id: u16, data: &[u8]) -> Option<Self> {
+        if data.len() != 24 || data[0..2] != ALTBEACON_CODE {
+            return None;
+        }
+        Some(Self {
+            manufacturer_id: company_id,
+            beacon_id: data[2..22].try_into().unwrap(),
+            reference_rssi: data[22] as i8,
+            manufacturer_reserved: data[23],
+        })
+    }
+
+    /// Encode the AltBeacon as manufacturer-specific data, to advertise for its
+    /// `manufacturer_id`.
+    pub fn to_manufacturer_data(&self) -> Vec<u8> {
+        let mut data = ALTBEACON_CODE.to_vec();
+        data.extend_from_slice(&self.beacon_id);
+        data.push(self.reference_rssi as u8);
+        data.push(self.manufacturer_reserved);
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let mut data = vec![0xbe, 0xac];
+        data.extend(1..=20);
+        data.extend([0xc5, 0x42]);
+        assert_eq!(
+            AltBeacon::from_manufacturer_data(0x0118, &data),
+            Some(AltBeacon {
+                manufacturer_id: 0x0118,
+                beacon_id: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20],
+                reference_rssi: -59,
+                manufacturer_reserved: 0x42,
+            })
+        );
+        assert_eq!(AltBeacon::from_manufacturer_data(0x0118, &data[1..]), None);
+        data[0] = 0;
+        assert_eq!(AltBeacon::from_manufacturer_data(0x0118, &data), None);
+    }
+
+    #[test]
+    fn encode() {
+        let altbeacon = AltBeacon {
+            manufacturer_id: 0x0118,
+            beacon_id: [
+                1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+            ],
+            reference_rssi: -59,
+            manufacturer_reserved: 0x42,
+        };
+        let data = altbeacon.to_manufacturer_data();
+        assert_eq!(data.len(), 24);
+        assert_eq!(
+            AltBeacon::from_manufacturer_data(0x0118, &data),
+            Some(altbeacon)
+        );
+    }
+}
@@ -0,0 +1,392 @@
+use uuid::Uuid;
+
+use crate::uuid_from_u16;
+
+/// The UUID of the service data used by BTHome advertisements.
+pub const BTHOME_SERVICE_UUID: Uuid = uuid_from_u16(0xfcd2);
+
+const DEVICE_INFO_ENCRYPTED: u8 = 0x01;
+const DEVICE_INFO_TRIGGER_BASED: u8 = 0x04;
+const BTHOME_VERSION_2: u8 = 2;
+
+/// Object IDs with a variable length, where the first byte of the value is its length.
+const OBJECT_ID_TEXT: u8 = 0x53;
+const OBJECT_ID_RAW: u8 = 0x54;
+
+/// A decoded [BTHome](https://bthome.io/) v2 advertisement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BTHome {
+    /// Whether the device sends advertisements on a trigger such as a button press, rather than at
+    /// a regular interval.
+    pub trigger_based: bool,
+    /// The measurements in the advertisement, in the order in which they were sent.
+    pub measurements: Vec<BTHomeMeasurement>,
+}
+
+/// A single measurement from a BTHome advertisement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BTHomeMeasurement {
+    /// The BTHome object ID.
+    pub object_id: u8,
+    /// The name of the property measured, e.g. `"temperature"`.
+    pub name: &'static str,
+    /// The value, already scaled by the factor for the object ID.
+    pub value: BTHomeValue,
+    /// The unit of the value, if any, e.g. `"°C"`.
+    pub unit: Option<&'static str>,
+}
+
+/// The value of a BTHome measurement.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BTHomeValue {
+    /// A numeric value, for sensors.
+    Number(f64),
+    /// A binary sensor value.
+    Bool(bool),
+    /// An event such as a button press, with its event type.
+    Event(u8),
+    /// A dimmer event, with its event type and number of steps.
+    Dimmer { event: u8, steps: u8 },
+    /// A text value.
+    Text(String),
+    /// A raw value.
+    Raw(Vec<u8>),
+}
+
+/// How the value of an object is encoded.
+#[derive(Clone, Copy, Debug)]
+enum Encoding {
+    /// An unsigned little-endian integer of the given size, multiplied by the given factor.
+    Unsigned(usize, f64),
+    /// A signed little-endian integer of the given size, multiplied by the given factor.
+    Signed(usize, f64),
+    Bool,
+    Event,
+    Dimmer,
+}
+
+/// Details of a BTHome object ID.
+struct ObjectType {
+    id: u8,
+    name: &'static str,
+    encoding: Encoding,
+    unit: Option<&'static str>,
+}
+
+const fn object(
+    id: u8,
+    name: &'static str,
+    encoding: Encoding,
+    unit: Option<&'static str>,
+) -> ObjectType {
+    ObjectType {
+        id,
+        name,
+        encoding,
+        unit,
+    }
+}
+
+/// The object IDs defined by the BTHome v2 format, in ascending order.
+const OBJECT_TYPES: &[ObjectType] = &[
+    object(0x00, "packet id", Encoding::Unsigned(1, 1.0), None),
+    object(0x01, "battery", Encoding::Unsigned(1, 1.0), Some("%")),
+    object(0x02, "temperature", Encoding::Signed(2, 0.01), Some("°C")),
+    object(0x03, "humidity", Encoding::Unsigned(2, 0.01), Some("%")),
+    object(0x04, "pressure", Encoding::Unsigned(3, 0.01), Some("hPa")),
+    object(
+        0x05,
+        "illuminance",
+        Encoding::Unsigned(3, 0.01),
+        Some("lux"),
+    ),
+    object(0x06, "mass", Encoding::Unsigned(2, 0.01), Some("kg")),
+    object(0x07, "mass", Encoding::Unsigned(2, 0.01), Some("lb")),
+    object(0x08, "dewpoint", Encoding::Signed(2, 0.01), Some("°C")),
+    object(0x09, "count", Encoding::Unsigned(1, 1.0), None),
+    object(0x0a, "energy", Encoding::Unsigned(3, 0.001), Some("kWh")),
+    object(0x0b, "power", Encoding::Unsigned(3, 0.01), Some("W")),
+    object(0x0c, "voltage", Encoding::Unsigned(2, 0.001), Some("V")),
+    object(0x0d, "pm2.5", Encoding::Unsigned(2, 1.0), Some("µg/m³")),
+    object(0x0e, "pm10", Encoding::Unsigned(2, 1.0), Some("µg/m³")),
+    object(0x0f, "generic boolean", Encoding::Bool, None),
+    object(0x10, "power", Encoding::Bool, None),
+    object(0x11, "opening", Encoding::Bool, None),
+    object(0x12, "co2", Encoding::Unsigned(2, 1.0), Some("ppm")),
+    object(0x13, "tvoc", Encoding::Unsigned(2, 1.0), Some("µg/m³")),
+    object(0x14, "moisture", Encoding::Unsigned(2, 0.01), Some("%")),
+    object(0x15, "battery low", Encoding::Bool, None),
+    object(0x16, "battery charging", Encoding::Bool, None),
+    object(0x17, "carbon monoxide", Encoding::Bool, None),
+    object(0x18, "cold", Encoding::Bool, None),
+    object(0x19, "connectivity", Encoding::Bool, None),
+    object(0x1a, "door", Encoding::Bool, None),
+    object(0x1b, "garage door", Encoding::Bool, None),
+    object(0x1c, "gas", Encoding::Bool, None),
+    object(0x1d, "heat", Encoding::Bool, None),
+    object(0x1e, "light", Encoding::Bool, None),
+    object(0x1f, "lock", Encoding::Bool, None),
+    object(0x20, "moisture", Encoding::Bool, None),
+    object(0x21, "motion", Encoding::Bool, None),
+    object(0x22, "moving", Encoding::Bool, None),
+    object(0x23, "occupancy", Encoding::Bool, None),
+    object(0x24, "plug", Encoding::Bool, None),
+    object(0x25, "presence", Encoding::Bool, None),
+    object(0x26, "problem", Encoding::Bool, None),
+    object(0x27, "running", Encoding::Bool, None),
+    object(0x28, "safety", Encoding::Bool, None),
+    object(0x29, "smoke", Encoding::Bool, None),
+    object(0x2a, "sound", Encoding::Bool, None),
+    object(0x2b, "tamper", Encoding::Bool, None),
+    object(0x2c, "vibration", Encoding::Bool, None),
+    object(0x2d, "window", Encoding::Bool, None),
+    object(0x2e, "humidity", Encoding::Unsigned(1, 1.0), Some("%")),
+    object(0x2f, "moisture", Encoding::Unsigned(1, 1.0), Some("%")),
+    object(0x3a, "button", Encoding::Event, None),
+    object(0x3c, "dimmer", Encoding::Dimmer, None),
+    object(0x3d, "count", Encoding::Unsigned(2, 1.0), None),
+    object(0x3e, "count", Encoding::Unsigned(4, 1.0), None),
+    object(0x3f, "rotation", Encoding::Signed(2, 0.1), Some("°")),
+    object(0x40, "distance", Encoding::Unsigned(2, 1.0), Some("mm")),
+    object(0x41, "distance", Encoding::Unsigned(2, 0.1), Some("m")),
+    object(0x42, "duration", Encoding::Unsigned(3, 0.001), Some("s")),
+    object(0x43, "current", Encoding::Unsigned(2, 0.001), Some("A")),
+    object(0x44, "speed", Encoding::Unsigned(2, 0.01), Some("m/s")),
+    object(0x45, "temperature", Encoding::Signed(2, 0.1), Some("°C")),
+    object(0x46, "UV index", Encoding::Unsigned(1, 0.1), None),
+    object(0x47, "volume", Encoding::Unsigned(2, 0.1), Some("L")),
+    object(0x48, "volume", Encoding::Unsigned(2, 1.0), Some("mL")),
+    object(
+        0x49,
+        "volume flow rate",
+        Encoding::Unsigned(2, 0.001),
+        Some("m³/h"),
+    ),
+    object(0x4a, "voltage", Encoding::Unsigned(2, 0.1), Some("V")),
+    object(0x4b, "gas", Encoding::Unsigned(3, 0.001), Some("m³")),
+    object(0x4c, "gas", Encoding::Unsigned(4, 0.001), Some("m³")),
+    object(0x4d, "energy", Encoding::Unsigned(4, 0.001), Some("kWh")),
+    object(0x4e, "volume", Encoding::Unsigned(4, 0.001), Some("L")),
+    object(0x4f, "water", Encoding::Unsigned(4, 0.001), Some("L")),
+    object(0x50, "timestamp", Encoding::Unsigned(4, 1.0), Some("s")),
+    object(
+        0x51,
+        "acceleration",
+        Encoding::Unsigned(2, 0.001),
+        Some("m/s²"),
+    ),
+    object(0x52, "gyroscope", Encoding::Unsigned(2, 0.001), Some("°/s")),
+    object(
+        0x55,
+        "volume storage",
+        Encoding::Unsigned(4, 0.001),
+        Some("L"),
+    ),
+    object(
+        0x56,
+        "conductivity",
+        Encoding::Unsigned(2, 1.0),
+        Some("µS/cm"),
+    ),
+    object(0x57, "temperature", Encoding::Signed(1, 1.0), Some("°C")),
+    object(0x58, "temperature", Encoding::Signed(1, 0.35), Some("°C")),
+    object(0x59, "count", Encoding::Signed(1, 1.0), None),
+    object(0x5a, "count", Encoding::Signed(2, 1.0), None),
+    object(0x5b, "count", Encoding::Signed(4, 1.0), None),
+    object(0x5c, "power", Encoding::Signed(4, 0.01), Some("W")),
+    object(0x5d, "current", Encoding::Signed(2, 0.001), Some("A")),
+    object(0xf0, "device type id", Encoding::Unsigned(2, 1.0), None),
+    object(0xf1, "firmware version", Encoding::Unsigned(4, 1.0), None),
+    object(0xf2, "firmware version", Encoding::Unsigned(3, 1.0), None),
+];
+
+impl BTHome {
+    /// Decode a BTHome v2 advertisement from the service data for the BTHome service UUID.
+    ///
+    /// Returns `None` if the advertisement is encrypted or not BTHome v2. If an unknown object ID
+    /// or truncated value is found then the measurements before it are still returned, as the
+    /// length of the rest can't be determined.
+    pub fn from_service_data(data: &[u8]) -> Option<Self> {
+        let device_info = *data.first()?;
+        if device_info >> 5 != BTHOME_VERSION_2 || device_info & DEVICE_INFO_ENCRYPTED != 0 {
+            return None;
+        }
+        let mut measurements = vec![];
+        let mut rest = &data[1..];
+        while let Some((&object_id, value)) = rest.split_first() {
+            match decode_object(object_id, value) {
+                Some((measurement, length)) => {
+                    measurements.push(measurement);
+                    rest = &value[length..];
+                }
+                None => {
+                    log::warn!(
+                        "Unknown or truncated BTHome object {:#04x} in {:?}",
+                        object_id,
+                        data
+                    );
+                    break;
+                }
+            }
+        }
+        Some(Self {
+            trigger_based: device_info & DEVICE_INFO_TRIGGER_BASED != 0,
+            measurements,
+        })
+    }
+}
+
+/// Decode the object with the given ID from the start of `data`, returning the measurement and the
+/// number of bytes it used.
+fn decode_object(object_id: u8, data: &[u8]) -> Option<(BTHomeMeasurement, usize)> {
+    if object_id == OBJECT_ID_TEXT || object_id == OBJECT_ID_RAW {
+        let (&length, rest) = data.split_first()?;
+        let bytes = rest.get(..usize::from(length))?;
+        let (name, value) = if object_id == OBJECT_ID_TEXT {
+            (
+                "text",
+                BTHomeValue::Text(String::from_utf8_lossy(bytes).into_owned()),
+            )
+        } else {
+            ("raw", BTHomeValue::Raw(bytes.to_owned()))
+        };
+        let measurement = BTHomeMeasurement {
+            object_id,
+            name,
+            value,
+            unit: None,
+        };
+        return Some((measurement, 1 + usize::from(length)));
+    }
+
+    let object_type = OBJECT_TYPES
+        .binary_search_by_key(&object_id, |object_type| object_type.id)
+        .ok()
+        .map(|index| &OBJECT_TYPES[index])?;
+    let (value, length) = match object_type.encoding {
+        Encoding::Unsigned(length, factor) => {
+            let bytes = data.get(..length)?;
+            (
+                BTHomeValue::Number(unsigned_le(bytes) as f64 * factor),
+                length,
+            )
+        }
+        Encoding::Signed(length, factor) => {
+            let bytes = data.get(..length)?;
+            (
+                BTHomeValue::Number(signed_le(bytes) as f64 * factor),
+                length,
+            )
+        }
+        Encoding::Bool => (BTHomeValue::Bool(*data.first()? != 0), 1),
+        Encoding::Event => (BTHomeValue::Event(*data.first()?), 1),
+        Encoding::Dimmer => {
+            let bytes = data.get(..2)?;
+            (
+                BTHomeValue::Dimmer {
+                    event: bytes[0],
+                    steps: bytes[1],
+                },
+                2,
+            )
+        }
+    };
+    let measurement = BTHomeMeasurement {
+        object_id,
+        name: object_type.name,
+        value,
+        unit: object_type.unit,
+    };
+    Some((measurement, length))
+}
+
+fn unsigned_le(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .rev()
+        .fold(0, |value, &byte| value << 8 | u64::from(byte))
+}
+
+fn signed_le(bytes: &[u8]) -> i64 {
+    // Shift the value up to the top of the i64 and back down again to sign-extend it.
+    let shift = 64 - 8 * bytes.len();
+    ((unsigned_le(bytes) << shift) as i64) >> shift
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn object_types_sorted() {
+        assert!(OBJECT_TYPES.windows(2).all(|pair| pair[0].id < pair[1].id));
+    }
+
+    #[test]
+    fn decode() {
+        // Temperature 25.06 °C, humidity 50.55 %, then a button press and some text.
+        let data = [
+            0x40, 0x02, 0xca, 0x09, 0x03, 0xbf, 0x13, 0x3a, 0x01, 0x53, 0x02, b'h', b'i',
+        ];
+        let bthome = BTHome::from_service_data(&data).unwrap();
+        assert!(!bthome.trigger_based);
+        assert_eq!(bthome.measurements.len(), 4);
+        assert_eq!(bthome.measurements[0].name, "temperature");
+        assert_eq!(bthome.measurements[0].unit, Some("°C"));
+        assert_eq!(
+            bthome.measurements[0].value,
+            BTHomeValue::Number(2506.0 * 0.01)
+        );
+        assert_eq!(bthome.measurements[1].name, "humidity");
+        assert_eq!(
+            bthome.measurements[1].value,
+            BTHomeValue::Number(5055.0 * 0.01)
+        );
+        assert_eq!(
+            bthome.measurements[2],
+            BTHomeMeasurement {
+                object_id: 0x3a,
+                name: "button",
+                value: BTHomeValue::Event(1),
+                unit: None,
+            }
+        );
+        assert_eq!(
+            bthome.measurements[3].value,
+            BTHomeValue::Text("hi".to_string())
+        );
+    }
+
+    #[test]
+    fn negative() {
+        let bthome = BTHome::from_service_data(&[0x44, 0x02, 0x18, 0xfc]).unwrap();
+        assert!(bthome.trigger_based);
+        assert_eq!(
+            bthome.measurements[0].value,
+            BTHomeValue::Number(-1000.0 * 0.01)
+        );
+    }
+
+    #[test]
+    fn unknown_object() {
+        let bthome = BTHome::from_service_data(&[0x40, 0x01, 0x64, 0xee, 0x01, 0x02]).unwrap();
+        assert_eq!(
+            bthome.measurements,
+            vec![BTHomeMeasurement {
+                object_id: 0x01,
+                name: "battery",
+                value: BTHomeValue::Number(100.0),
+                unit: Some("%"),
+            }]
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        // Encrypted.
+        assert_eq!(BTHome::from_service_data(&[0x41, 0x01, 0x64]), None);
+        // BTHome v1.
+        assert_eq!(BTHome::from_service_data(&[0x20, 0x01, 0x64]), None);
+        assert_eq!(BTHome::from_service_data(&[]), None);
+    }
+}
@@ -0,0 +1,443 @@
+use std::convert::{TryFrom, TryInto};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::uuid_from_u16;
+
+/// The UUID of the service data used by Eddystone frames.
+pub const EDDYSTONE_SERVICE_UUID: Uuid = uuid_from_u16(0xfeaa);
+
+const FRAME_TYPE_UID: u8 = 0x00;
+const FRAME_TYPE_URL: u8 = 0x10;
+const FRAME_TYPE_TLM: u8 = 0x20;
+const FRAME_TYPE_EID: u8 = 0x30;
+
+/// The maximum length of the encoded URL in an Eddystone-URL frame, after the scheme prefix byte.
+const MAX_ENCODED_URL_LENGTH: usize = 17;
+
+/// The URL scheme prefixes which may be encoded by the first byte of an Eddystone-URL frame.
+const URL_SCHEMES: [&str; 4] = ["http://www.", "https://www.", "http://", "https://"];
+
+/// The expansions for bytes 0x00 to 0x0d in the encoded URL of an Eddystone-URL frame.
+const URL_EXPANSIONS: [&str; 14] = [
+    ".com/", ".org/", ".edu/", ".net/", ".info/", ".biz/", ".gov/", ".com", ".org", ".edu", ".net",
+    ".info", ".biz", ".gov",
+];
+
+/// A Google [Eddystone](https://github.com/google/eddystone) frame.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Eddystone {
+    /// An Eddystone-UID frame, broadcasting a fixed beacon ID.
+    Uid {
+        /// The calibrated transmission power at 0 metres, in dBm.
+        tx_power: i8,
+        /// The 10-byte namespace ID.
+        namespace: [u8; 10],
+        /// The 6-byte instance ID within the namespace.
+        instance: [u8; 6],
+    },
+    /// An Eddystone-URL frame, broadcasting a URL.
+    Url {
+        /// The calibrated transmission power at 0 metres, in dBm.
+        tx_power: i8,
+        /// The URL, with its scheme and any encoded expansions expanded.
+        url: String,
+    },
+    /// An Eddystone-TLM frame, broadcasting telemetry about the beacon itself.
+    Tlm(EddystoneTlm),
+    /// An Eddystone-EID frame, broadcasting a rotating encrypted ephemeral ID.
+    Eid {
+        /// The calibrated transmission power at 0 metres, in dBm.
+        tx_power: i8,
+        /// The 8-byte ephemeral ID.
+        eid: [u8; 8],
+    },
+}
+
+/// The contents of an Eddystone-TLM frame.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EddystoneTlm {
+    /// Unencrypted telemetry.
+    Unencrypted {
+        /// The battery voltage in millivolts, or `None` if the beacon isn't battery powered.
+        battery_voltage: Option<u16>,
+        /// The beacon temperature in °C, or `None` if it isn't supported.
+        temperature: Option<f32>,
+        /// The number of advertisement frames sent since the beacon was powered on or rebooted.
+        advertisement_count: u32,
+        /// The time since the beacon was powered on or rebooted, with a resolution of 0.1 seconds.
+        uptime: Duration,
+    },
+    /// Encrypted telemetry, which can only be decoded by the owner of the beacon's identity key.
+    Encrypted {
+        /// The 12 bytes of encrypted telemetry.
+        etlm: [u8; 12],
+        /// The random salt used for encryption.
+        salt: u16,
+        /// The message integrity check.
+        mic: u16,
+    },
+}
+
+impl Eddystone {
+    /// Decode an Eddystone frame from the service data for the Eddystone service UUID, if it is a
+    /// valid one.
+    pub fn from_service_data(data: &[u8]) -> Option<Self> {
+        match *data.first()? {
+            FRAME_TYPE_UID if data.len() >= 18 => Some(Self::Uid {
+                tx_power: data[1] as i8,
+                namespace: data[2..12].try_into().unwrap(),
+                instance: data[12..18].try_into().unwrap(),
+            }),
+            FRAME_TYPE_URL if data.len() >= 3 => Some(Self::Url {
+                tx_power: data[1] as i8,
+                url: decode_url(data[2], &data[3..])?,
+            }),
+            FRAME_TYPE_TLM => Some(Self::Tlm(EddystoneTlm::decode(data)?)),
+            FRAME_TYPE_EID if data.len() == 10 => Some(Self::Eid {
+                tx_power: data[1] as i8,
+                eid: data[2..10].try_into().unwrap(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Encode the frame as service data, to advertise for [`EDDYSTONE_SERVICE_UUID`].
+    ///
+    /// Returns `None` if it is an Eddystone-URL frame whose URL can't be encoded, because it
+    /// doesn't start with an `http://` or `https://` scheme, contains characters other than
+    /// printable ASCII, or is too long once encoded.
+    pub fn to_service_data(&self) -> Option<Vec<u8>> {
+        Some(match self {
+            Self::Uid {
+                tx_power,
+                namespace,
+                instance,
+            } => {
+                let mut data = vec![FRAME_TYPE_UID, *tx_power as u8];
+                data.extend_from_slice(namespace);
+                data.extend_from_slice(instance);
+                // Reserved for future use.
+                data.extend_from_slice(&[0x00, 0x00]);
+                data
+            }
+            Self::Url { tx_power, url } => {
+                let mut data = vec![FRAME_TYPE_URL, *tx_power as u8];
+                data.extend(encode_url(url)?);
+                data
+            }
+            Self::Tlm(tlm) => tlm.encode(),
+            Self::Eid { tx_power, eid } => {
+                let mut data = vec![FRAME_TYPE_EID, *tx_power as u8];
+                data.extend_from_slice(eid);
+                data
+            }
+        })
+    }
+}
+
+impl EddystoneTlm {
+    /// Decode a TLM frame, including the frame type byte.
+    fn decode(data: &[u8]) -> Option<Self> {
+        match (data.get(1)?, data.len()) {
+            (0x00, 14) => {
+                let battery_voltage = u16::from_be_bytes([data[2], data[3]]);
+                let temperature = i16::from_be_bytes([data[4], data[5]]);
+                Some(Self::Unencrypted {
+                    battery_voltage: if battery_voltage == 0 {
+                        None
+                    } else {
+                        Some(battery_voltage)
+                    },
+                    // 0x8000 means that temperature is not supported, otherwise it is in signed
+                    // 8.8 fixed point.
+                    temperature: if temperature == i16::MIN {
+                        None
+                    } else {
+                        Some(f32::from(temperature) / 256.0)
+                    },
+                    advertisement_count: u32::from_be_bytes(data[6..10].try_into().unwrap()),
+                    uptime: Duration::from_millis(
+                        u64::from(u32::from_be_bytes(data[10..14].try_into().unwrap())) * 100,
+                    ),
+                })
+            }
+            (0x01, 18) => Some(Self::Encrypted {
+                etlm: data[2..14].try_into().unwrap(),
+                salt: u16::from_be_bytes([data[14], data[15]]),
+                mic: u16::from_be_bytes([data[16], data[17]]),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Encode a TLM frame, including the frame type byte.
+    fn encode(&self) -> Vec<u8> {
+        match self {
+            Self::Unencrypted {
+                battery_voltage,
+                temperature,
+                advertisement_count,
+                uptime,
+            } => {
+                let temperature = temperature.map_or(i16::MIN, |temperature| {
+                    (temperature * 256.0)
+                        .round()
+                        .clamp(f32::from(i16::MIN + 1), f32::from(i16::MAX))
+                        as i16
+                });
+                let uptime = u32::try_from(uptime.as_millis() / 100).unwrap_or(u32::MAX);
+                let mut data = vec![FRAME_TYPE_TLM, 0x00];
+                data.extend_from_slice(&battery_voltage.unwrap_or(0).to_be_bytes());
+                data.extend_from_slice(&temperature.to_be_bytes());
+                data.extend_from_slice(&advertisement_count.to_be_bytes());
+                data.extend_from_slice(&uptime.to_be_bytes());
+                data
+            }
+            Self::Encrypted { etlm, salt, mic } => {
+                let mut data = vec![FRAME_TYPE_TLM, 0x01];
+                data.extend_from_slice(etlm);
+                data.extend_from_slice(&salt.to_be_bytes());
+                data.extend_from_slice(&mic.to_be_bytes());
+                data
+            }
+        }
+    }
+}
+
+/// Decode the URL from an Eddystone-URL frame, given its scheme prefix byte and encoded URL.
+fn decode_url(scheme: u8, encoded: &[u8]) -> Option<String> {
+    let mut url = URL_SCHEMES.get(usize::from(scheme))?.to_string();
+    for &byte in encoded {
+        if let Some(expansion) = URL_EXPANSIONS.get(usize::from(byte)) {
+            url.push_str(expansion);
+        } else if (0x21..=0x7e).contains(&byte) {
+            url.push(char::from(byte));
+        } else {
+            return None;
+        }
+    }
+    Some(url)
+}
+
+/// Encode a URL for an Eddystone-URL frame, as its scheme prefix byte followed by the encoded URL.
+fn encode_url(url: &str) -> Option<Vec<u8>> {
+    // The schemes including "www." come first, so they are preferred.
+    let (scheme, mut remaining) = URL_SCHEMES
+        .iter()
+        .enumerate()
+        .find_map(|(index, scheme)| Some((index as u8, url.strip_prefix(scheme)?)))?;
+    let mut encoded = vec![scheme];
+    while let Some(&byte) = remaining.as_bytes().first() {
+        // Expansions ending in '/' come first, so they are preferred.
+        if let Some((index, expansion)) = URL_EXPANSIONS
+            .iter()
+            .enumerate()
+            .find(|(_, expansion)| remaining.starts_with(*expansion))
+        {
+            encoded.push(index as u8);
+            remaining = &remaining[expansion.len()..];
+        } else if (0x21..=0x7e).contains(&byte) {
+            encoded.push(byte);
+            remaining = &remaining[1..];
+        } else {
+            return None;
+        }
+    }
+    if encoded.len() > MAX_ENCODED_URL_LENGTH + 1 {
+        return None;
+    }
+    Some(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uid() {
+        let data = [
+            0x00, 0xe7, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x11, 0x12,
+            0x13, 0x14, 0x15, 0x16, 0x00, 0x00,
+        ];
+        assert_eq!(
+            Eddystone::from_service_data(&data),
+            Some(Eddystone::Uid {
+                tx_power: -25,
+                namespace: [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a],
+                instance: [0x11, 0x12, 0x13, 0x14, 0x15, 0x16],
+            })
+        );
+        assert_eq!(Eddystone::from_service_data(&data[0..17]), None);
+        assert_eq!(
+            Eddystone::from_service_data(&data)
+                .unwrap()
+                .to_service_data(),
+            Some(data.to_vec())
+        );
+    }
+
+    #[test]
+    fn url() {
+        assert_eq!(
+            Eddystone::from_service_data(&[
+                0x10, 0xeb, 0x01, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x07
+            ]),
+            Some(Eddystone::Url {
+                tx_power: -21,
+                url: "https://www.example.com".to_string(),
+            })
+        );
+        assert_eq!(
+            Eddystone::from_service_data(&[0x10, 0x00, 0x02, b'a', 0x00, b'b']),
+            Some(Eddystone::Url {
+                tx_power: 0,
+                url: "http://a.com/b".to_string(),
+            })
+        );
+        // Invalid scheme.
+        assert_eq!(
+            Eddystone::from_service_data(&[0x10, 0x00, 0x04, b'a']),
+            None
+        );
+        // Reserved byte in URL.
+        assert_eq!(
+            Eddystone::from_service_data(&[0x10, 0x00, 0x00, b'a', 0x20]),
+            None
+        );
+    }
+
+    #[test]
+    fn encode_url() {
+        assert_eq!(
+            Eddystone::Url {
+                tx_power: -21,
+                url: "https://www.example.com".to_string(),
+            }
+            .to_service_data(),
+            Some(vec![
+                0x10, 0xeb, 0x01, b'e', b'x', b'a', b'm', b'p', b'l', b'e', 0x07
+            ])
+        );
+        assert_eq!(
+            Eddystone::Url {
+                tx_power: 0,
+                url: "http://a.com/b".to_string(),
+            }
+            .to_service_data(),
+            Some(vec![0x10, 0x00, 0x02, b'a', 0x00, b'b'])
+        );
+        // Unsupported scheme.
+        assert_eq!(
+            Eddystone::Url {
+                tx_power: 0,
+                url: "ftp://a.com".to_string(),
+            }
+            .to_service_data(),
+            None
+        );
+        // Not printable ASCII.
+        assert_eq!(
+            Eddystone::Url {
+                tx_power: 0,
+                url: "https://a b.com".to_string(),
+            }
+            .to_service_data(),
+            None
+        );
+        // Too long.
+        assert_eq!(
+            Eddystone::Url {
+                tx_power: 0,
+                url: "https://abcdefghijklmnopqr".to_string(),
+            }
+            .to_service_data(),
+            None
+        );
+    }
+
+    #[test]
+    fn tlm() {
+        assert_eq!(
+            Eddystone::from_service_data(&[
+                0x20, 0x00, 0x0b, 0xb8, 0x15, 0x80, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x64
+            ]),
+            Some(Eddystone::Tlm(EddystoneTlm::Unencrypted {
+                battery_voltage: Some(3000),
+                temperature: Some(21.5),
+                advertisement_count: 256,
+                uptime: Duration::from_secs(10),
+            }))
+        );
+        assert_eq!(
+            Eddystone::from_service_data(&[
+                0x20, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ]),
+            Some(Eddystone::Tlm(EddystoneTlm::Unencrypted {
+                battery_voltage: None,
+                temperature: None,
+                advertisement_count: 0,
+                uptime: Duration::ZERO,
+            }))
+        );
+        assert_eq!(
+            Eddystone::from_service_data(&[
+                0x20, 0x01, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x12, 0x34, 0x56, 0x78
+            ]),
+            Some(Eddystone::Tlm(EddystoneTlm::Encrypted {
+                etlm: [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12],
+                salt: 0x1234,
+                mic: 0x5678,
+            }))
+        );
+        assert_eq!(Eddystone::from_service_data(&[0x20, 0x02]), None);
+    }
+
+    #[test]
+    fn encode_tlm() {
+        for data in [
+            vec![
+                0x20, 0x00, 0x0b, 0xb8, 0x15, 0x80, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x64,
+            ],
+            vec![
+                0x20, 0x00, 0x00, 0x00, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            ],
+            vec![
+                0x20, 0x01, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0x12, 0x34, 0x56, 0x78,
+            ],
+        ] {
+            assert_eq!(
+                Eddystone::from_service_data(&data)
+                    .unwrap()
+                    .to_service_data(),
+                Some(data)
+            );
+        }
+    }
+
+    #[test]
+    fn eid() {
+        assert_eq!(
+            Eddystone::from_service_data(&[0x30, 0xf0, 1, 2, 3, 4, 5, 6, 7, 8]),
+            Some(Eddystone::Eid {
+                tx_power: -16,
+                eid: [1, 2, 3, 4, 5, 6, 7, 8],
+            })
+        );
+        assert_eq!(Eddystone::from_service_data(&[0x30, 0xf0, 1, 2]), None);
+        assert_eq!(
+            Eddystone::Eid {
+                tx_power: -16,
+                eid: [1, 2, 3, 4, 5, 6, 7, 8],
+            }
+            .to_service_data(),
+            Some(vec![0x30, 0xf0, 1, 2, 3, 4, 5, 6, 7, 8])
+        );
+    }
+
+    #[test]
+    fn unknown_frame_type() {
+        assert_eq!(Eddystone::from_service_data(&[]), None);
+        assert_eq!(Eddystone::from_service_data(&[0x40, 0x00]), None);
+    }
+}
@@ -0,0 +1,96 @@
+use std::convert::TryInto;
+use uuid::Uuid;
+
+/// The Bluetooth company identifier of Apple, Inc., used for iBeacon manufacturer data.
+pub const APPLE_COMPANY_ID: u16 = 0x004c;
+
+/// The iBeacon type and length prefix which follows the company identifier.
+const IBEACON_PREFIX: [u8; 2] = [0x02, 0x15];
+
+/// An Apple iBeacon advertisement.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct IBeacon {
+    /// The proximity UUID identifying the beacon's organisation or deployment.
+    pub uuid: Uuid,
+    /// The major number, typically identifying a group of beacons.
+    pub major: u16,
+    /// The minor number, typically identifying an individual beacon within the group.
+    pub minor: u16,
+    /// The calibrated RSSI at 1 metre from the beacon, in dBm.
+    pub measured_power: i8,
+}
+
+impl IBeacon {
+    /// Decode an iBeacon from the manufacturer-specific data for the given company ID, if it is
+    /// one.
+    pub fn from_manufacturer_data(company_id: u16, data: &[u8]) -> Option<Self> {
+        if company_id != APPLE_COMPANY_ID || data.len() != 23 || data[0..2] != IBEACON_PREFIX {
+            return None;
+        }
+        Some(Self {
+            uuid: Uuid::from_bytes(data[2..18].try_into().unwrap()),
+            major: u16::from_be_bytes([data[18], data[19]]),
+            minor: u16::from_be_bytes([data[20], data[21]]),
+            measured_power: data[22] as i8,
+        })
+    }
+
+    /// Encode the iBeacon as manufacturer-specific data, to advertise for [`APPLE_COMPANY_ID`].
+    pub fn to_manufacturer_data(&self) -> Vec<u8> {
+        let mut data = IBEACON_PREFIX.to_vec();
+        data.extend_from_slice(self.uuid.as_bytes());
+        data.extend_from_slice(&self.major.to_be_bytes());
+        data.extend_from_slice(&self.minor.to_be_bytes());
+        data.push(self.measured_power as u8);
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let data = [
+            0x02, 0x15, 0xe2, 0xc5, 0x6d, 0xb5, 0xdf, 0xfb, 0x48, 0xd2, 0xb0, 0x60, 0xd0, 0xf5,
+            0xa7, 0x10, 0x96, 0xe0, 0x12, 0x34, 0x56, 0x78, 0xc5,
+        ];
+        assert_eq!(
+            IBeacon::from_manufacturer_data(APPLE_COMPANY_ID, &data),
+            Some(IBeacon {
+                uuid: Uuid::parse_str("e2c56db5-dffb-48d2-b060-d0f5a71096e0").unwrap(),
+                major: 0x1234,
+                minor: 0x5678,
+                measured_power: -59,
+            })
+        );
+        assert_eq!(IBeacon::from_manufacturer_data(0x1234, &data), None);
+        assert_eq!(
+            IBeacon::from_manufacturer_data(APPLE_COMPANY_ID, &data[0..22]),
+            None
+        );
+        // Other Apple advertisements, e.g. AirPods.
+        assert_eq!(
+            IBeacon::from_manufacturer_data(APPLE_COMPANY_ID, &[0x07, 0x19, 0x01]),
+            None
+        );
+    }
+
+    #[test]
+    fn encode() {
+        let ibeacon = IBeacon {
+            uuid: Uuid::parse_str("e2c56db5-dffb-48d2-b060-d0f5a71096e0").unwrap(),
+            major: 0x1234,
+            minor: 0x5678,
+            measured_power: -59,
+        };
+        assert_eq!(
+            ibeacon.to_manufacturer_data(),
+            vec![
+                0x02, 0x15, 0xe2, 0xc5, 0x6d, 0xb5, 0xdf, 0xfb, 0x48, 0xd2, 0xb0, 0x60, 0xd0, 0xf5,
+                0xa7, 0x10, 0x96, 0xe0, 0x12, 0x34, 0x56, 0x78, 0xc5,
+            ]
+        );
+    }
+}
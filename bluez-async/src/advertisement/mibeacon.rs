@@ -0,0 +1,216 @@
+use uuid::Uuid;
+
+use crate::{uuid_from_u16, MacAddress};
+
+/// The UUID of the service data used by Xiaomi MiBeacon advertisements.
+pub const MIBEACON_SERVICE_UUID: Uuid = uuid_from_u16(0xfe95);
+
+const FRAME_CONTROL_ENCRYPTED: u16 = 0x0008;
+const FRAME_CONTROL_MAC_ADDRESS: u16 = 0x0010;
+const FRAME_CONTROL_CAPABILITY: u16 = 0x0020;
+const FRAME_CONTROL_OBJECT: u16 = 0x0040;
+
+/// Capability flag indicating that two bytes of I/O capability follow the capability byte.
+const CAPABILITY_IO: u8 = 0x20;
+
+/// A decoded unencrypted Xiaomi MiBeacon advertisement.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MiBeacon {
+    /// The product ID of the device.
+    pub product_id: u16,
+    /// A counter incremented for each new advertisement.
+    pub frame_counter: u8,
+    /// The MAC address of the device, if included.
+    pub mac_address: Option<MacAddress>,
+    /// The measurements in the advertisement, if any.
+    pub measurements: Vec<MiBeaconMeasurement>,
+}
+
+/// A single measurement from a Xiaomi MiBeacon advertisement.
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum MiBeaconMeasurement {
+    /// Temperature in °C.
+    Temperature(f32),
+    /// Relative humidity in %.
+    Humidity(f32),
+    /// Illuminance in lux.
+    Illuminance(u32),
+    /// Soil moisture in %.
+    Moisture(u8),
+    /// Soil conductivity in µS/cm.
+    Conductivity(u16),
+    /// Battery level in %.
+    Battery(u8),
+    /// Formaldehyde concentration in mg/m³.
+    Formaldehyde(f32),
+    /// Time since motion was last detected, in seconds.
+    NoMotionTime(u32),
+    /// An object which we don't know how to decode.
+    Unknown {
+        /// The MiBeacon object ID.
+        object_id: u16,
+        /// The raw object data.
+        data: Vec<u8>,
+    },
+}
+
+impl MiBeacon {
+    /// Decode a MiBeacon advertisement from the service data for the MiBeacon service UUID.
+    ///
+    /// Returns `None` if the advertisement is encrypted or truncated.
+    pub fn from_service_data(data: &[u8]) -> Option<Self> {
+        let frame_control = u16::from_le_bytes([*data.first()?, *data.get(1)?]);
+        if frame_control & FRAME_CONTROL_ENCRYPTED != 0 {
+            return None;
+        }
+        let product_id = u16::from_le_bytes([*data.get(2)?, *data.get(3)?]);
+        let frame_counter = *data.get(4)?;
+        let mut rest = &data[5..];
+
+        let mac_address = if frame_control & FRAME_CONTROL_MAC_ADDRESS != 0 {
+            // The MAC address is sent in reverse byte order.
+            let mut mac_address = [0; 6];
+            mac_address.copy_from_slice(rest.get(..6)?);
+            mac_address.reverse();
+            rest = &rest[6..];
+            Some(mac_address.into())
+        } else {
+            None
+        };
+        if frame_control & FRAME_CONTROL_CAPABILITY != 0 {
+            let capability = *rest.first()?;
+            let length = if capability & CAPABILITY_IO != 0 {
+                3
+            } else {
+                1
+            };
+            rest = rest.get(length..)?;
+        }
+
+        let mut measurements = vec![];
+        if frame_control & FRAME_CONTROL_OBJECT != 0 {
+            while rest.len() >= 3 {
+                let object_id = u16::from_le_bytes([rest[0], rest[1]]);
+                let length = usize::from(rest[2]);
+                let value = rest.get(3..3 + length)?;
+                decode_object(object_id, value, &mut measurements);
+                rest = &rest[3 + length..];
+            }
+        }
+
+        Some(Self {
+            product_id,
+            frame_counter,
+            mac_address,
+            measurements,
+        })
+    }
+}
+
+/// Decode a single MiBeacon object, adding the resulting measurements to `measurements`.
+fn decode_object(object_id: u16, value: &[u8], measurements: &mut Vec<MiBeaconMeasurement>) {
+    match (object_id, value.len()) {
+        (0x1004, 2) => measurements.push(MiBeaconMeasurement::Temperature(
+            f32::from(i16::from_le_bytes([value[0], value[1]])) / 10.0,
+        )),
+        (0x1006, 2) => measurements.push(MiBeaconMeasurement::Humidity(
+            f32::from(u16::from_le_bytes([value[0], value[1]])) / 10.0,
+        )),
+        (0x1007, 3) => measurements.push(MiBeaconMeasurement::Illuminance(u32::from_le_bytes([
+            value[0], value[1], value[2], 0,
+        ]))),
+        (0x1008, 1) => measurements.push(MiBeaconMeasurement::Moisture(value[0])),
+        (0x1009, 2) => measurements.push(MiBeaconMeasurement::Conductivity(u16::from_le_bytes([
+            value[0], value[1],
+        ]))),
+        (0x100a, 1) => measurements.push(MiBeaconMeasurement::Battery(value[0])),
+        (0x100d, 4) => {
+            measurements.push(MiBeaconMeasurement::Temperature(
+                f32::from(i16::from_le_bytes([value[0], value[1]])) / 10.0,
+            ));
+            measurements.push(MiBeaconMeasurement::Humidity(
+                f32::from(u16::from_le_bytes([value[2], value[3]])) / 10.0,
+            ));
+        }
+        (0x1010, 2) => measurements.push(MiBeaconMeasurement::Formaldehyde(
+            f32::from(u16::from_le_bytes([value[0], value[1]])) / 100.0,
+        )),
+        (0x1017, 4) => measurements.push(MiBeaconMeasurement::NoMotionTime(u32::from_le_bytes([
+            value[0], value[1], value[2], value[3],
+        ]))),
+        _ => measurements.push(MiBeaconMeasurement::Unknown {
+            object_id,
+            data: value.to_owned(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temperature_humidity() {
+        // LYWSDCGQ with MAC address, temperature and humidity.
+        let data = [
+            0x50, 0x20, 0xaa, 0x01, 0x17, 0x66, 0x55, 0x44, 0x33, 0x22, 0x11, 0x0d, 0x10, 0x04,
+            0xfe, 0x00, 0xe5, 0x01,
+        ];
+        assert_eq!(
+            MiBeacon::from_service_data(&data),
+            Some(MiBeacon {
+                product_id: 0x01aa,
+                frame_counter: 0x17,
+                mac_address: Some("11:22:33:44:55:66".parse().unwrap()),
+                measurements: vec![
+                    MiBeaconMeasurement::Temperature(25.4),
+                    MiBeaconMeasurement::Humidity(48.5),
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn battery_without_mac() {
+        let data = [0x40, 0x20, 0x98, 0x00, 0x01, 0x0a, 0x10, 0x01, 0x5d];
+        assert_eq!(
+            MiBeacon::from_service_data(&data),
+            Some(MiBeacon {
+                product_id: 0x0098,
+                frame_counter: 1,
+                mac_address: None,
+                measurements: vec![MiBeaconMeasurement::Battery(93)],
+            })
+        );
+    }
+
+    #[test]
+    fn capability_and_unknown_object() {
+        let data = [
+            0x60, 0x20, 0x98, 0x00, 0x02, 0x28, 0x00, 0x00, 0x99, 0x99, 0x02, 0x01, 0x02,
+        ];
+        assert_eq!(
+            MiBeacon::from_service_data(&data).unwrap().measurements,
+            vec![MiBeaconMeasurement::Unknown {
+                object_id: 0x9999,
+                data: vec![1, 2],
+            }]
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        // Encrypted.
+        assert_eq!(
+            MiBeacon::from_service_data(&[0x58, 0x30, 0x98, 0x00, 0x01, 0x01, 0x02]),
+            None
+        );
+        // Truncated object.
+        assert_eq!(
+            MiBeacon::from_service_data(&[0x40, 0x20, 0x98, 0x00, 0x01, 0x0a, 0x10, 0x02, 0x5d]),
+            None
+        );
+        assert_eq!(MiBeacon::from_service_data(&[0x40]), None);
+    }
+}
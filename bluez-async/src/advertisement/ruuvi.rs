@@ -0,0 +1,221 @@
+use std::convert::TryInto;
+
+use crate::MacAddress;
+
+/// The Bluetooth company identifier of Ruuvi Innovations Ltd.
+pub const RUUVI_COMPANY_ID: u16 = 0x0499;
+
+/// The [RAWv1](https://docs.ruuvi.com/communication/bluetooth-advertisements/data-format-3-rawv1)
+/// data format.
+const DATA_FORMAT_RAWV1: u8 = 3;
+/// The [RAWv2](https://docs.ruuvi.com/communication/bluetooth-advertisements/data-format-5-rawv2)
+/// data format.
+const DATA_FORMAT_RAWV2: u8 = 5;
+
+/// Acceleration along three axes, in milli-G.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Acceleration {
+    pub x: i16,
+    pub y: i16,
+    pub z: i16,
+}
+
+/// Sensor measurements from a [RuuviTag](https://ruuvi.com/ruuvitag/) advertisement, in either the
+/// RAWv1 or RAWv2 data format.
+///
+/// Fields are `None` if the sensor isn't available, or aren't supported by the data format.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RuuviTag {
+    /// The data format of the advertisement, 3 for RAWv1 or 5 for RAWv2.
+    pub data_format: u8,
+    /// The temperature in °C.
+    pub temperature: Option<f64>,
+    /// The relative humidity in %.
+    pub humidity: Option<f64>,
+    /// The air pressure in Pa.
+    pub pressure: Option<u32>,
+    /// The acceleration of the tag.
+    pub acceleration: Option<Acceleration>,
+    /// The battery voltage in mV.
+    pub battery_voltage: Option<u16>,
+    /// The transmission power in dBm. Only for RAWv2.
+    pub tx_power: Option<i8>,
+    /// A counter incremented by motion detection interrupts from the accelerometer. Only for RAWv2.
+    pub movement_counter: Option<u8>,
+    /// A counter incremented for each new measurement. Only for RAWv2.
+    pub measurement_sequence: Option<u16>,
+    /// The MAC address of the tag. Only for RAWv2.
+    pub mac_address: Option<MacAddress>,
+}
+
+impl RuuviTag {
+    /// Decode RuuviTag measurements from the manufacturer-specific data for the given company ID,
+    /// if it is in a supported format.
+    pub fn from_manufacturer_data(company_id: u16, data: &[u8]) -> Option<Self> {
+        if company_id != RUUVI_COMPANY_ID {
+            return None;
+        }
+        match (*data.first()?, data.len()) {
+            (DATA_FORMAT_RAWV1, 14) => Some(Self::decode_rawv1(data)),
+            (DATA_FORMAT_RAWV2, 24) => Some(Self::decode_rawv2(data)),
+            _ => None,
+        }
+    }
+
+    fn decode_rawv1(data: &[u8]) -> Self {
+        // The integer part of the temperature is in sign-magnitude form.
+        let magnitude = f64::from(data[2] & 0x7f) + f64::from(data[3]) / 100.0;
+        let temperature = if data[2] & 0x80 == 0 {
+            magnitude
+        } else {
+            -magnitude
+        };
+        Self {
+            data_format: DATA_FORMAT_RAWV1,
+            temperature: Some(temperature),
+            humidity: Some(f64::from(data[1]) * 0.5),
+            pressure: Some(u32::from(u16::from_be_bytes([data[4], data[5]])) + 50_000),
+            acceleration: Some(Acceleration {
+                x: i16::from_be_bytes([data[6], data[7]]),
+                y: i16::from_be_bytes([data[8], data[9]]),
+                z: i16::from_be_bytes([data[10], data[11]]),
+            }),
+            battery_voltage: Some(u16::from_be_bytes([data[12], data[13]])),
+            tx_power: None,
+            movement_counter: None,
+            measurement_sequence: None,
+            mac_address: None,
+        }
+    }
+
+    fn decode_rawv2(data: &[u8]) -> Self {
+        // Each field uses its maximum value (or minimum for signed fields) to indicate that it is
+        // not available.
+        let temperature = i16::from_be_bytes([data[1], data[2]]);
+        let humidity = u16::from_be_bytes([data[3], data[4]]);
+        let pressure = u16::from_be_bytes([data[5], data[6]]);
+        let x = i16::from_be_bytes([data[7], data[8]]);
+        let y = i16::from_be_bytes([data[9], data[10]]);
+        let z = i16::from_be_bytes([data[11], data[12]]);
+        let power_info = u16::from_be_bytes([data[13], data[14]]);
+        let battery_voltage = power_info >> 5;
+        let tx_power = (power_info & 0x1f) as u8;
+        let movement_counter = data[15];
+        let measurement_sequence = u16::from_be_bytes([data[16], data[17]]);
+        let mac_address: [u8; 6] = data[18..24].try_into().unwrap();
+        Self {
+            data_format: DATA_FORMAT_RAWV2,
+            temperature: (temperature != i16::MIN).then(|| f64::from(temperature) * 0.005),
+            humidity: (humidity != u16::MAX).then(|| f64::from(humidity) * 0.0025),
+            pressure: (pressure != u16::MAX).then(|| u32::from(pressure) + 50_000),
+            acceleration: (x != i16::MIN && y != i16::MIN && z != i16::MIN)
+                .then_some(Acceleration { x, y, z }),
+            battery_voltage: (battery_voltage != 0x7ff).then_some(battery_voltage + 1600),
+            tx_power: (tx_power != 0x1f).then(|| -40 + 2 * tx_power as i8),
+            movement_counter: (movement_counter != u8::MAX).then_some(movement_counter),
+            measurement_sequence: (measurement_sequence != u16::MAX)
+                .then_some(measurement_sequence),
+            mac_address: (mac_address != [0xff; 6]).then(|| mac_address.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rawv1() {
+        // Test vector from the Ruuvi documentation.
+        let data = [
+            0x03, 0x29, 0x1a, 0x1e, 0xce, 0x1e, 0xfc, 0x18, 0xf9, 0x42, 0x02, 0xca, 0x0b, 0x53,
+        ];
+        let tag = RuuviTag::from_manufacturer_data(RUUVI_COMPANY_ID, &data).unwrap();
+        assert_eq!(tag.data_format, 3);
+        assert!((tag.temperature.unwrap() - 26.3).abs() < 1e-9);
+        assert_eq!(tag.humidity, Some(20.5));
+        assert_eq!(tag.pressure, Some(102_766));
+        assert_eq!(
+            tag.acceleration,
+            Some(Acceleration {
+                x: -1000,
+                y: -1726,
+                z: 714
+            })
+        );
+        assert_eq!(tag.battery_voltage, Some(2899));
+        assert_eq!(tag.mac_address, None);
+
+        let mut negative = data;
+        negative[2] = 0x81;
+        negative[3] = 0x45;
+        let tag = RuuviTag::from_manufacturer_data(RUUVI_COMPANY_ID, &negative).unwrap();
+        assert!((tag.temperature.unwrap() + 1.69).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rawv2() {
+        // Test vectors from the Ruuvi documentation.
+        let data = [
+            0x05, 0x12, 0xfc, 0x53, 0x94, 0xc3, 0x7c, 0x00, 0x04, 0xff, 0xfc, 0x04, 0x0c, 0xac,
+            0x36, 0x42, 0x00, 0xcd, 0xcb, 0xb8, 0x33, 0x4c, 0x88, 0x4f,
+        ];
+        let tag = RuuviTag::from_manufacturer_data(RUUVI_COMPANY_ID, &data).unwrap();
+        assert_eq!(
+            tag,
+            RuuviTag {
+                data_format: 5,
+                temperature: Some(24.3),
+                humidity: Some(53.49),
+                pressure: Some(100_044),
+                acceleration: Some(Acceleration {
+                    x: 4,
+                    y: -4,
+                    z: 1036
+                }),
+                battery_voltage: Some(2977),
+                tx_power: Some(4),
+                movement_counter: Some(66),
+                measurement_sequence: Some(205),
+                mac_address: Some("CB:B8:33:4C:88:4F".parse().unwrap()),
+            }
+        );
+
+        let invalid = [
+            0x05, 0x80, 0x00, 0xff, 0xff, 0xff, 0xff, 0x80, 0x00, 0x80, 0x00, 0x80, 0x00, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        ];
+        assert_eq!(
+            RuuviTag::from_manufacturer_data(RUUVI_COMPANY_ID, &invalid),
+            Some(RuuviTag {
+                data_format: 5,
+                temperature: None,
+                humidity: None,
+                pressure: None,
+                acceleration: None,
+                battery_voltage: None,
+                tx_power: None,
+                movement_counter: None,
+                measurement_sequence: None,
+                mac_address: None,
+            })
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(RuuviTag::from_manufacturer_data(0x1234, &[0x05; 24]), None);
+        assert_eq!(
+            RuuviTag::from_manufacturer_data(RUUVI_COMPANY_ID, &[0x05; 10]),
+            None
+        );
+        assert_eq!(
+            RuuviTag::from_manufacturer_data(RUUVI_COMPANY_ID, &[0x04; 24]),
+            None
+        );
+        assert_eq!(
+            RuuviTag::from_manufacturer_data(RUUVI_COMPANY_ID, &[]),
+            None
+        );
+    }
+}
@@ -0,0 +1,709 @@
+use bitflags::bitflags;
+use bluez_generated::{OrgBluezLEAdvertisingManager1, OrgBluezLEAdvertisingManager1Properties};
+use dbus::arg::{PropMap, Variant};
+use dbus::channel::Token;
+use dbus::nonblock::Proxy;
+use dbus::Path;
+use dbus_crossroads::Crossroads;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::runtime::Handle;
+use uuid::Uuid;
+
+use crate::export::{optional, seconds, serve, unique_path, unserve};
+use crate::{AdapterId, Appearance, BluetoothError, BluetoothSession, DBUS_METHOD_CALL_TIMEOUT};
+
+const ADVERTISEMENT_INTERFACE: &str = "org.bluez.LEAdvertisement1";
+
+/// The type of an LE advertisement.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AdvertisementType {
+    /// A non-connectable advertisement, just broadcasting data.
+    Broadcast,
+    /// A connectable advertisement, for remote devices to connect to the adapter.
+    Peripheral,
+}
+
+impl AdvertisementType {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Broadcast => "broadcast",
+            Self::Peripheral => "peripheral",
+        }
+    }
+}
+
+/// The PHY on which to send an extended advertisement on the secondary advertising channels.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SecondaryChannel {
+    /// LE 1M PHY.
+    OneM,
+    /// LE 2M PHY.
+    TwoM,
+    /// LE Coded PHY, for long range.
+    Coded,
+}
+
+impl SecondaryChannel {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::OneM => "1M",
+            Self::TwoM => "2M",
+            Self::Coded => "Coded",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "1M" => Some(Self::OneM),
+            "2M" => Some(Self::TwoM),
+            "Coded" => Some(Self::Coded),
+            _ => None,
+        }
+    }
+
+    /// Parse the secondary channels reported by BlueZ, ignoring any which aren't known.
+    pub(crate) fn from_strings(strings: &[String]) -> Vec<Self> {
+        strings.iter().filter_map(|s| Self::parse(s)).collect()
+    }
+}
+
+impl Display for SecondaryChannel {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+bitflags! {
+    /// Data which BlueZ can add to an advertisement itself, rather than it being set explicitly.
+    #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+    pub struct AdvertisingIncludes: u8 {
+        /// The TX power with which the advertisement is sent.
+        const TX_POWER = 0x01;
+        /// The appearance of the adapter.
+        const APPEARANCE = 0x02;
+        /// The name of the adapter.
+        const LOCAL_NAME = 0x04;
+        /// A resolvable set identifier, for coordinated sets.
+        const RSI = 0x08;
+    }
+}
+
+/// The BlueZ names of each include.
+const INCLUDE_NAMES: &[(AdvertisingIncludes, &str)] = &[
+    (AdvertisingIncludes::TX_POWER, "tx-power"),
+    (AdvertisingIncludes::APPEARANCE, "appearance"),
+    (AdvertisingIncludes::LOCAL_NAME, "local-name"),
+    (AdvertisingIncludes::RSI, "rsi"),
+];
+
+impl AdvertisingIncludes {
+    /// Get the BlueZ names of the includes which are set.
+    pub(crate) fn to_strings(self) -> Vec<String> {
+        INCLUDE_NAMES
+            .iter()
+            .filter(|(include, _)| self.contains(*include))
+            .map(|(_, name)| (*name).to_owned())
+            .collect()
+    }
+
+    /// Parse the includes reported by BlueZ. Unknown names are ignored rather than being an error,
+    /// as newer versions of BlueZ may support more.
+    pub(crate) fn from_strings(strings: &[String]) -> Self {
+        INCLUDE_NAMES
+            .iter()
+            .filter(|(_, name)| strings.iter().any(|s| s == name))
+            .fold(Self::empty(), |includes, (include, _)| includes | *include)
+    }
+}
+
+/// The LE advertising capabilities of an adapter, from BlueZ's `org.bluez.LEAdvertisingManager1`
+/// interface.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdvertisingCapabilities {
+    /// The number of advertisements which are currently registered with the adapter.
+    pub active_instances: u8,
+    /// The number of further advertisements which may be registered at the same time.
+    pub supported_instances: u8,
+    /// The data which BlueZ supports adding to advertisements itself.
+    pub supported_includes: AdvertisingIncludes,
+    /// The secondary channels which may be used for extended advertisements. This is empty if the
+    /// adapter doesn't support extended advertising.
+    pub supported_secondary_channels: Vec<SecondaryChannel>,
+}
+
+impl AdvertisingCapabilities {
+    pub(crate) fn from_properties(
+        properties: OrgBluezLEAdvertisingManager1Properties,
+    ) -> Result<Self, BluetoothError> {
+        Ok(Self {
+            active_instances: properties
+                .active_instances()
+                .ok_or(BluetoothError::RequiredPropertyMissing("ActiveInstances"))?,
+            supported_instances: properties.supported_instances().ok_or(
+                BluetoothError::RequiredPropertyMissing("SupportedInstances"),
+            )?,
+            supported_includes: properties
+                .supported_includes()
+                .map(|includes| AdvertisingIncludes::from_strings(includes))
+                .unwrap_or_default(),
+            // BlueZ only provides this property if extended advertising is supported.
+            supported_secondary_channels: properties
+                .supported_secondary_channels()
+                .map(|channels| SecondaryChannel::from_strings(channels))
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Returns whether the adapter supports extended advertising, i.e. sending advertisements on a
+    /// secondary channel.
+    pub fn extended_advertising(&self) -> bool {
+        !self.supported_secondary_channels.is_empty()
+    }
+}
+
+/// An LE advertisement to send from a local adapter.
+///
+/// Start advertising with
+/// [`BluetoothSession::start_advertising`](struct.BluetoothSession.html#method.start_advertising).
+///
+/// ```no_run
+/// # use bluez_async::{uuid_from_u16, AdapterId, Advertisement, AdvertisementType, BluetoothSession};
+/// # use std::time::Duration;
+/// # async fn example(session: &BluetoothSession, adapter: &AdapterId) -> Result<(), Box<dyn std::error::Error>> {
+/// let advertisement = Advertisement::new(AdvertisementType::Peripheral)
+///     .service_uuid(uuid_from_u16(0x180f))
+///     .local_name("Battery")
+///     .discoverable(true)
+///     .interval(Duration::from_millis(100), Duration::from_millis(200));
+/// let handle = session.start_advertising(adapter, &advertisement).await?;
+/// tokio::time::sleep(Duration::from_secs(60)).await;
+/// handle.unregister().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Advertisement {
+    advertisement_type: AdvertisementType,
+    service_uuids: Vec<Uuid>,
+    solicit_uuids: Vec<Uuid>,
+    manufacturer_data: HashMap<u16, Vec<u8>>,
+    service_data: HashMap<Uuid, Vec<u8>>,
+    local_name: Option<String>,
+    appearance: Option<Appearance>,
+    tx_power: Option<i16>,
+    includes: AdvertisingIncludes,
+    discoverable: Option<bool>,
+    discoverable_timeout: Option<Duration>,
+    duration: Option<Duration>,
+    timeout: Option<Duration>,
+    min_interval: Option<Duration>,
+    max_interval: Option<Duration>,
+    secondary_channel: Option<SecondaryChannel>,
+}
+
+impl Advertisement {
+    /// Create a new advertisement of the given type, with no data.
+    pub fn new(advertisement_type: AdvertisementType) -> Self {
+        Self {
+            advertisement_type,
+            service_uuids: vec![],
+            solicit_uuids: vec![],
+            manufacturer_data: HashMap::new(),
+            service_data: HashMap::new(),
+            local_name: None,
+            appearance: None,
+            tx_power: None,
+            includes: AdvertisingIncludes::empty(),
+            discoverable: None,
+            discoverable_timeout: None,
+            duration: None,
+            timeout: None,
+            min_interval: None,
+            max_interval: None,
+            secondary_channel: None,
+        }
+    }
+
+    /// Advertise the given service UUID.
+    pub fn service_uuid(mut self, uuid: Uuid) -> Self {
+        self.service_uuids.push(uuid);
+        self
+    }
+
+    /// Solicit remote devices to provide the service with the given UUID.
+    pub fn solicit_uuid(mut self, uuid: Uuid) -> Self {
+        self.solicit_uuids.push(uuid);
+        self
+    }
+
+    /// Advertise the given manufacturer-specific data, replacing any previously set for the same
+    /// manufacturer ID.
+    pub fn manufacturer_data(mut self, manufacturer_id: u16, data: Vec<u8>) -> Self {
+        self.manufacturer_data.insert(manufacturer_id, data);
+        self
+    }
+
+    /// Advertise the given service data, replacing any previously set for the same service UUID.
+    pub fn service_data(mut self, uuid: Uuid, data: Vec<u8>) -> Self {
+        self.service_data.insert(uuid, data);
+        self
+    }
+
+    /// Advertise the given local name, rather than the adapter's name.
+    pub fn local_name(mut self, local_name: impl Into<String>) -> Self {
+        self.local_name = Some(local_name.into());
+        self
+    }
+
+    /// Advertise the given appearance, rather than the adapter's.
+    pub fn appearance(mut self, appearance: Appearance) -> Self {
+        self.appearance = Some(appearance);
+        self
+    }
+
+    /// Request that the advertisement be sent with the given TX power in dBm, and include it in
+    /// the advertisement.
+    pub fn tx_power(mut self, tx_power: i16) -> Self {
+        self.tx_power = Some(tx_power);
+        self
+    }
+
+    /// Ask BlueZ to add the given data to the advertisement. This may be called several times to
+    /// include more.
+    pub fn include(mut self, includes: AdvertisingIncludes) -> Self {
+        self.includes |= includes;
+        self
+    }
+
+    /// Set whether to advertise as general discoverable. This only applies to peripheral
+    /// advertisements, and defaults to the adapter's `Discoverable` setting.
+    pub fn discoverable(mut self, discoverable: bool) -> Self {
+        self.discoverable = Some(discoverable);
+        self
+    }
+
+    /// Stop advertising as discoverable after the given time. This has a resolution of seconds.
+    pub fn discoverable_timeout(mut self, discoverable_timeout: Duration) -> Self {
+        self.discoverable_timeout = Some(discoverable_timeout);
+        self
+    }
+
+    /// Set how long the advertisement is sent for at a time when BlueZ is rotating between several
+    /// advertisements. This has a resolution of seconds.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Remove the advertisement after the given time. This has a resolution of seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the minimum and maximum advertising intervals. These have a resolution of milliseconds.
+    pub fn interval(mut self, min_interval: Duration, max_interval: Duration) -> Self {
+        self.min_interval = Some(min_interval);
+        self.max_interval = Some(max_interval);
+        self
+    }
+
+    /// Send the advertisement as an extended advertisement on the given secondary channel.
+    pub fn secondary_channel(mut self, secondary_channel: SecondaryChannel) -> Self {
+        self.secondary_channel = Some(secondary_channel);
+        self
+    }
+
+    /// Build a `Crossroads` to serve the advertisement at the given path.
+    fn to_crossroads(&self, path: &Path<'static>, released: Arc<AtomicBool>) -> Crossroads {
+        let mut cr = Crossroads::new();
+        let token = cr.register(ADVERTISEMENT_INTERFACE, |b| {
+            b.property("Type").get(|_, ad: &mut AdvertisementObject| {
+                Ok(ad.advertisement.advertisement_type.as_str().to_owned())
+            });
+            b.property("ServiceUUIDs")
+                .get(|_, ad| Ok(uuid_strings(&ad.advertisement.service_uuids)));
+            b.property("SolicitUUIDs")
+                .get(|_, ad| Ok(uuid_strings(&ad.advertisement.solicit_uuids)));
+            b.property("ManufacturerData").get(|_, ad| {
+                Ok(ad
+                    .advertisement
+                    .manufacturer_data
+                    .iter()
+                    .map(|(id, data)| (*id, Variant(data.clone())))
+                    .collect::<HashMap<_, _>>())
+            });
+            b.property("ServiceData").get(|_, ad| {
+                Ok(ad
+                    .advertisement
+                    .service_data
+                    .iter()
+                    .map(|(uuid, data)| (uuid.to_string(), Variant(data.clone())))
+                    .collect::<HashMap<_, _>>())
+            });
+            b.property("Includes")
+                .get(|_, ad| Ok(ad.advertisement.includes.to_strings()));
+            // Unset optional properties are left out of `GetAll`, so BlueZ uses its defaults.
+            b.property("LocalName")
+                .get(|_, ad| optional("LocalName", ad.advertisement.local_name.clone()));
+            b.property("Appearance")
+                .get(|_, ad| optional("Appearance", ad.advertisement.appearance.map(u16::from)));
+            b.property("TxPower")
+                .get(|_, ad| optional("TxPower", ad.advertisement.tx_power));
+            b.property("Discoverable")
+                .get(|_, ad| optional("Discoverable", ad.advertisement.discoverable));
+            b.property("DiscoverableTimeout").get(|_, ad| {
+                optional(
+                    "DiscoverableTimeout",
+                    ad.advertisement.discoverable_timeout.map(seconds),
+                )
+            });
+            b.property("Duration")
+                .get(|_, ad| optional("Duration", ad.advertisement.duration.map(seconds)));
+            b.property("Timeout")
+                .get(|_, ad| optional("Timeout", ad.advertisement.timeout.map(seconds)));
+            b.property("MinInterval")
+                .get(|_, ad| optional("MinInterval", ad.advertisement.min_interval.map(millis)));
+            b.property("MaxInterval")
+                .get(|_, ad| optional("MaxInterval", ad.advertisement.max_interval.map(millis)));
+            b.property("SecondaryChannel").get(|_, ad| {
+                optional(
+                    "SecondaryChannel",
+                    ad.advertisement
+                        .secondary_channel
+                        .map(|channel| channel.as_str().to_owned()),
+                )
+            });
+            b.method("Release", (), (), |_, ad, ()| {
+                log::debug!("BlueZ released advertisement");
+                ad.released.store(true, Ordering::Relaxed);
+                Ok(())
+            });
+        });
+        cr.insert(
+            path.clone(),
+            &[token],
+            AdvertisementObject {
+                advertisement: self.clone(),
+                released,
+            },
+        );
+        cr
+    }
+}
+
+/// The data of an exported `org.bluez.LEAdvertisement1` object.
+struct AdvertisementObject {
+    advertisement: Advertisement,
+    released: Arc<AtomicBool>,
+}
+
+fn uuid_strings(uuids: &[Uuid]) -> Vec<String> {
+    uuids.iter().map(Uuid::to_string).collect()
+}
+
+fn millis(duration: Duration) -> u32 {
+    u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
+/// A handle to an advertisement which has been registered with an adapter, from
+/// [`BluetoothSession::start_advertising`](struct.BluetoothSession.html#method.start_advertising).
+///
+/// The advertisement is unregistered when this is dropped.
+#[must_use = "the advertisement is unregistered when the AdvertisementHandle is dropped"]
+pub struct AdvertisementHandle {
+    session: BluetoothSession,
+    runtime: Handle,
+    adapter: AdapterId,
+    path: Path<'static>,
+    token: Option<Token>,
+    released: Arc<AtomicBool>,
+}
+
+impl Debug for AdvertisementHandle {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("AdvertisementHandle")
+            .field("adapter", &self.adapter)
+            .field("path", &self.path)
+            .field("released", &self.is_released())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AdvertisementHandle {
+    /// Export the given advertisement and register it with the given adapter.
+    pub(crate) async fn register(
+        session: &BluetoothSession,
+        adapter: &AdapterId,
+        advertisement: &Advertisement,
+    ) -> Result<Self, BluetoothError> {
+        let path = unique_path("advertisement");
+        let released = Arc::new(AtomicBool::new(false));
+        let cr = advertisement.to_crossroads(&path, released.clone());
+        let token = serve(session, &path, cr);
+        if let Err(e) = advertising_manager(session, adapter)
+            .register_advertisement(path.clone(), PropMap::new())
+            .await
+        {
+            unserve(session, token);
+            return Err(e.into());
+        }
+        Ok(Self {
+            session: session.clone(),
+            runtime: Handle::current(),
+            adapter: adapter.to_owned(),
+            path,
+            token: Some(token),
+            released,
+        })
+    }
+
+    /// The adapter with which the advertisement is registered.
+    pub fn adapter(&self) -> &AdapterId {
+        &self.adapter
+    }
+
+    /// Returns whether BlueZ has removed the advertisement itself, e.g. because its timeout
+    /// expired or the adapter was powered off.
+    pub fn is_released(&self) -> bool {
+        self.released.load(Ordering::Relaxed)
+    }
+
+    /// Unregister the advertisement, and wait until BlueZ has done so. This is the same as
+    /// dropping the handle, except that it waits and reports any error.
+    pub async fn unregister(mut self) -> Result<(), BluetoothError> {
+        match self.token.take() {
+            Some(token) => {
+                unregister(
+                    &self.session,
+                    &self.adapter,
+                    &self.path,
+                    token,
+                    self.is_released(),
+                )
+                .await
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for AdvertisementHandle {
+    fn drop(&mut self) {
+        if let Some(token) = self.token.take() {
+            let session = self.session.clone();
+            let adapter = self.adapter.clone();
+            let path = self.path.clone();
+            let released = self.is_released();
+            self.runtime.spawn(async move {
+                if let Err(e) = unregister(&session, &adapter, &path, token, released).await {
+                    log::error!("Unregistering advertisement {} failed: {}", path, e);
+                }
+            });
+        }
+    }
+}
+
+fn advertising_manager(
+    session: &BluetoothSession,
+    adapter: &AdapterId,
+) -> impl OrgBluezLEAdvertisingManager1 {
+    Proxy::new(
+        "org.bluez",
+        adapter.object_path.to_owned(),
+        DBUS_METHOD_CALL_TIMEOUT,
+        session.connection.clone(),
+    )
+}
+
+async fn unregister(
+    session: &BluetoothSession,
+    adapter: &AdapterId,
+    path: &Path<'static>,
+    token: Token,
+    released: bool,
+) -> Result<(), BluetoothError> {
+    // If BlueZ has already released the advertisement then there is nothing to unregister.
+    let result = if released {
+        Ok(())
+    } else {
+        advertising_manager(session, adapter)
+            .unregister_advertisement(path.clone())
+            .await
+    };
+    unserve(session, token);
+    Ok(result?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::RecordingSender;
+    use crate::{uuid_from_u16, AppearanceCategory};
+    use dbus::arg::RefArg;
+    use dbus::Message;
+
+    fn get_all(cr: &mut Crossroads, path: &Path<'static>) -> PropMap {
+        let mut message = Message::new_method_call(
+            ":1.0",
+            path.clone(),
+            "org.freedesktop.DBus.Properties",
+            "GetAll",
+        )
+        .unwrap()
+        .append1(ADVERTISEMENT_INTERFACE);
+        message.set_serial(1);
+        let sender = RecordingSender::default();
+        cr.handle_message(message, &sender).unwrap();
+        let replies = sender.0.into_inner().unwrap();
+        replies[0].read1().unwrap()
+    }
+
+    #[test]
+    fn capabilities() {
+        let mut properties = PropMap::new();
+        properties.insert("ActiveInstances".to_owned(), Variant(Box::new(1u8)));
+        properties.insert("SupportedInstances".to_owned(), Variant(Box::new(4u8)));
+        properties.insert(
+            "SupportedIncludes".to_owned(),
+            Variant(Box::new(vec![
+                "tx-power".to_owned(),
+                "appearance".to_owned(),
+                "something-new".to_owned(),
+            ])),
+        );
+        let capabilities = AdvertisingCapabilities::from_properties(
+            OrgBluezLEAdvertisingManager1Properties(&properties),
+        )
+        .unwrap();
+        assert_eq!(
+            capabilities,
+            AdvertisingCapabilities {
+                active_instances: 1,
+                supported_instances: 4,
+                supported_includes: AdvertisingIncludes::TX_POWER | AdvertisingIncludes::APPEARANCE,
+                supported_secondary_channels: vec![],
+            }
+        );
+        assert!(!capabilities.extended_advertising());
+
+        properties.insert(
+            "SupportedSecondaryChannels".to_owned(),
+            Variant(Box::new(vec!["1M".to_owned(), "Coded".to_owned()])),
+        );
+        let capabilities = AdvertisingCapabilities::from_properties(
+            OrgBluezLEAdvertisingManager1Properties(&properties),
+        )
+        .unwrap();
+        assert_eq!(
+            capabilities.supported_secondary_channels,
+            vec![SecondaryChannel::OneM, SecondaryChannel::Coded]
+        );
+        assert!(capabilities.extended_advertising());
+    }
+
+    #[test]
+    fn minimal_properties() {
+        let path = Path::from("/test/advertisement0");
+        let mut cr = Advertisement::new(AdvertisementType::Broadcast)
+            .to_crossroads(&path, Arc::new(AtomicBool::new(false)));
+        let properties = get_all(&mut cr, &path);
+
+        let mut names: Vec<_> = properties.keys().cloned().collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "Includes",
+                "ManufacturerData",
+                "ServiceData",
+                "ServiceUUIDs",
+                "SolicitUUIDs",
+                "Type"
+            ]
+        );
+        assert_eq!(properties["Type"].as_str(), Some("broadcast"));
+    }
+
+    #[test]
+    fn all_properties() {
+        let path = Path::from("/test/advertisement0");
+        let mut cr = Advertisement::new(AdvertisementType::Peripheral)
+            .service_uuid(uuid_from_u16(0x180f))
+            .manufacturer_data(0x004c, vec![1, 2, 3])
+            .service_data(uuid_from_u16(0xfeaa), vec![4, 5])
+            .local_name("Test")
+            .appearance(Appearance {
+                category: AppearanceCategory::Watch,
+                subcategory: 1,
+            })
+            .tx_power(-4)
+            .include(AdvertisingIncludes::TX_POWER)
+            .include(AdvertisingIncludes::LOCAL_NAME)
+            .discoverable(true)
+            .timeout(Duration::from_secs(30))
+            .interval(Duration::from_millis(100), Duration::from_millis(200))
+            .secondary_channel(SecondaryChannel::Coded)
+            .to_crossroads(&path, Arc::new(AtomicBool::new(false)));
+        let properties = get_all(&mut cr, &path);
+
+        assert_eq!(properties["Type"].as_str(), Some("peripheral"));
+        let service_uuids: Vec<_> = properties["ServiceUUIDs"]
+            .0
+            .as_iter()
+            .unwrap()
+            .map(|uuid| uuid.as_str().unwrap().to_owned())
+            .collect();
+        assert_eq!(service_uuids, vec!["0000180f-0000-1000-8000-00805f9b34fb"]);
+        let manufacturer_data: Vec<_> = properties["ManufacturerData"]
+            .0
+            .as_iter()
+            .unwrap()
+            .collect();
+        assert_eq!(manufacturer_data[0].as_u64(), Some(0x004c));
+        // The data is wrapped in a variant.
+        let data = manufacturer_data[1].as_iter().unwrap().next().unwrap();
+        assert_eq!(
+            data.as_iter()
+                .unwrap()
+                .map(|byte| byte.as_u64().unwrap())
+                .collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(properties["LocalName"].as_str(), Some("Test"));
+        assert_eq!(properties["Appearance"].as_u64(), Some(0x00c1));
+        assert_eq!(properties["TxPower"].as_i64(), Some(-4));
+        let includes: Vec<_> = properties["Includes"]
+            .0
+            .as_iter()
+            .unwrap()
+            .map(|include| include.as_str().unwrap().to_owned())
+            .collect();
+        assert_eq!(includes, vec!["tx-power", "local-name"]);
+        assert_eq!(properties["Discoverable"].as_u64(), Some(1));
+        assert_eq!(properties["Timeout"].as_u64(), Some(30));
+        assert_eq!(properties["MinInterval"].as_u64(), Some(100));
+        assert_eq!(properties["MaxInterval"].as_u64(), Some(200));
+        assert_eq!(properties["SecondaryChannel"].as_str(), Some("Coded"));
+        assert!(!properties.contains_key("Duration"));
+    }
+
+    #[test]
+    fn release() {
+        let path = Path::from("/test/advertisement0");
+        let released = Arc::new(AtomicBool::new(false));
+        let mut cr =
+            Advertisement::new(AdvertisementType::Broadcast).to_crossroads(&path, released.clone());
+        let mut message =
+            Message::new_method_call(":1.0", path, ADVERTISEMENT_INTERFACE, "Release").unwrap();
+        message.set_serial(1);
+        cr.handle_message(message, &RecordingSender::default())
+            .unwrap();
+        assert!(released.load(Ordering::Relaxed));
+    }
+}
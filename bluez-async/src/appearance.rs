@@ -0,0 +1,226 @@
+#[rustfmt::skip]
+mod generated;
+
+use std::fmt::{self, Display, Formatter};
+
+use self::generated::SUBCATEGORIES;
+
+/// The external appearance of a device, as defined by GAP and advertised in the Appearance AD
+/// type.
+///
+/// The 16-bit value consists of a 10-bit category and a 6-bit subcategory.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Appearance {
+    /// The general category of the device, e.g. heart rate sensor.
+    pub category: AppearanceCategory,
+    /// The 6-bit subcategory within the category, e.g. belt for a heart rate sensor. 0 means that
+    /// the subcategory is generic or unspecified.
+    pub subcategory: u8,
+}
+
+impl Appearance {
+    /// Get the name of the subcategory, as assigned by the Bluetooth SIG, if it is known. Returns
+    /// `None` for the generic subcategory 0.
+    pub fn subcategory_name(&self) -> Option<&'static str> {
+        let value = u16::from(*self);
+        SUBCATEGORIES
+            .binary_search_by_key(&value, |&(key, _)| key)
+            .ok()
+            .map(|index| SUBCATEGORIES[index].1)
+    }
+}
+
+impl From<u16> for Appearance {
+    fn from(value: u16) -> Self {
+        Self {
+            category: AppearanceCategory::from(value >> 6),
+            subcategory: (value & 0x3f) as u8,
+        }
+    }
+}
+
+impl From<Appearance> for u16 {
+    fn from(appearance: Appearance) -> Self {
+        u16::from(appearance.category) << 6 | u16::from(appearance.subcategory & 0x3f)
+    }
+}
+
+impl Display for Appearance {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.category)?;
+        match (self.subcategory, self.subcategory_name()) {
+            (0, _) => Ok(()),
+            (_, Some(name)) => write!(f, ": {}", name),
+            (subcategory, None) => write!(f, " (subcategory {})", subcategory),
+        }
+    }
+}
+
+macro_rules! appearance_categories {
+    ($($(#[$attr:meta])* $variant:ident = $value:literal => $name:literal,)*) => {
+        /// The category of an [`Appearance`], as assigned by the Bluetooth SIG.
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #[non_exhaustive]
+        pub enum AppearanceCategory {
+            $($(#[$attr])* $variant,)*
+            /// A category which is reserved or not yet known to this library.
+            Other(u16),
+        }
+
+        impl AppearanceCategory {
+            /// Get the human-readable name of the category.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                    Self::Other(_) => "Unknown category",
+                }
+            }
+        }
+
+        impl From<u16> for AppearanceCategory {
+            /// Convert a 10-bit category value to an `AppearanceCategory`.
+            fn from(value: u16) -> Self {
+                match value {
+                    $($value => Self::$variant,)*
+                    _ => Self::Other(value),
+                }
+            }
+        }
+
+        impl From<AppearanceCategory> for u16 {
+            fn from(category: AppearanceCategory) -> Self {
+                match category {
+                    $(AppearanceCategory::$variant => $value,)*
+                    AppearanceCategory::Other(value) => value & 0x3ff,
+                }
+            }
+        }
+    };
+}
+
+appearance_categories! {
+    /// Unknown or unspecified appearance.
+    Unknown = 0x00 => "Unknown",
+    Phone = 0x01 => "Phone",
+    Computer = 0x02 => "Computer",
+    Watch = 0x03 => "Watch",
+    Clock = 0x04 => "Clock",
+    Display = 0x05 => "Display",
+    RemoteControl = 0x06 => "Remote Control",
+    EyeGlasses = 0x07 => "Eye-glasses",
+    Tag = 0x08 => "Tag",
+    Keyring = 0x09 => "Keyring",
+    MediaPlayer = 0x0a => "Media Player",
+    BarcodeScanner = 0x0b => "Barcode Scanner",
+    Thermometer = 0x0c => "Thermometer",
+    HeartRateSensor = 0x0d => "Heart Rate Sensor",
+    BloodPressure = 0x0e => "Blood Pressure",
+    HumanInterfaceDevice = 0x0f => "Human Interface Device",
+    GlucoseMeter = 0x10 => "Glucose Meter",
+    RunningWalkingSensor = 0x11 => "Running Walking Sensor",
+    Cycling = 0x12 => "Cycling",
+    ControlDevice = 0x13 => "Control Device",
+    NetworkDevice = 0x14 => "Network Device",
+    Sensor = 0x15 => "Sensor",
+    LightFixtures = 0x16 => "Light Fixtures",
+    Fan = 0x17 => "Fan",
+    Hvac = 0x18 => "HVAC",
+    AirConditioning = 0x19 => "Air Conditioning",
+    Humidifier = 0x1a => "Humidifier",
+    Heating = 0x1b => "Heating",
+    AccessControl = 0x1c => "Access Control",
+    MotorizedDevice = 0x1d => "Motorized Device",
+    PowerDevice = 0x1e => "Power Device",
+    LightSource = 0x1f => "Light Source",
+    WindowCovering = 0x20 => "Window Covering",
+    AudioSink = 0x21 => "Audio Sink",
+    AudioSource = 0x22 => "Audio Source",
+    MotorizedVehicle = 0x23 => "Motorized Vehicle",
+    DomesticAppliance = 0x24 => "Domestic Appliance",
+    WearableAudioDevice = 0x25 => "Wearable Audio Device",
+    Aircraft = 0x26 => "Aircraft",
+    AvEquipment = 0x27 => "AV Equipment",
+    DisplayEquipment = 0x28 => "Display Equipment",
+    HearingAid = 0x29 => "Hearing aid",
+    Gaming = 0x2a => "Gaming",
+    Signage = 0x2b => "Signage",
+    PulseOximeter = 0x31 => "Pulse Oximeter",
+    WeightScale = 0x32 => "Weight Scale",
+    PersonalMobilityDevice = 0x33 => "Personal Mobility Device",
+    ContinuousGlucoseMonitor = 0x34 => "Continuous Glucose Monitor",
+    InsulinPump = 0x35 => "Insulin Pump",
+    MedicationDelivery = 0x36 => "Medication Delivery",
+    Spirometer = 0x37 => "Spirometer",
+    OutdoorSportsActivity = 0x51 => "Outdoor Sports Activity",
+    IndustrialMeasurementDevice = 0x52 => "Industrial Measurement Device",
+    IndustrialTools = 0x53 => "Industrial Tools",
+}
+
+impl Display for AppearanceCategory {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Other(value) => write!(f, "Unknown category ({:#05x})", value),
+            _ => f.write_str(self.as_str()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_u16() {
+        let appearance = Appearance::from(0x0341);
+        assert_eq!(
+            appearance,
+            Appearance {
+                category: AppearanceCategory::HeartRateSensor,
+                subcategory: 1,
+            }
+        );
+        assert_eq!(u16::from(appearance), 0x0341);
+        assert_eq!(
+            Appearance::from(0xffc0).category,
+            AppearanceCategory::Other(0x3ff)
+        );
+        assert_eq!(u16::from(Appearance::from(0xffc5)), 0xffc5);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Appearance::from(0x00c0).to_string(), "Watch");
+        assert_eq!(
+            Appearance::from(0xffc0).to_string(),
+            "Unknown category (0x3ff)"
+        );
+        assert_eq!(
+            Appearance::from(0x0341).to_string(),
+            "Heart Rate Sensor: Heart Rate Belt"
+        );
+        assert_eq!(
+            Appearance::from(0x0342).to_string(),
+            "Heart Rate Sensor (subcategory 2)"
+        );
+    }
+
+    #[test]
+    fn subcategory_name() {
+        assert_eq!(Appearance::from(0x00c0).subcategory_name(), None);
+        assert_eq!(
+            Appearance::from(0x00c2).subcategory_name(),
+            Some("Smartwatch")
+        );
+        assert_eq!(Appearance::from(0x03c2).subcategory_name(), Some("Mouse"));
+        assert_eq!(
+            Appearance::from(0x0d48).subcategory_name(),
+            Some("Insulin Pen")
+        );
+        assert_eq!(Appearance::from(0x0d42).subcategory_name(), None);
+        assert_eq!(
+            Appearance::from(0x0945).subcategory_name(),
+            Some("Left Earbud")
+        );
+        assert_eq!(Appearance::from(0x0101).subcategory_name(), None);
+    }
+}
@@ -0,0 +1,274 @@
+// This file is generated by assigned_numbers.py from the Bluetooth SIG assigned numbers.
+// Source revision: bluetooth-SIG/public 8c192a8effd1574d0c83e72ad91407511bcad209 (UUIDs and appearance values, via btuuid 0.1.1); company identifiers and member UUIDs from bluetooth-numbers-database v1 (via bluer 0.17.4)
+// Do not edit it by hand.
+
+/// GAP appearance subcategory names, keyed by the full appearance value.
+pub(super) const SUBCATEGORIES: &[(u16, &str)] = &[
+    (0x0081, "Desktop Workstation"),
+    (0x0082, "Server-class Computer"),
+    (0x0083, "Laptop"),
+    (0x0084, "Handheld PC/PDA (clamshell)"),
+    (0x0085, "Palm-size PC/PDA"),
+    (0x0086, "Wearable computer (watch size)"),
+    (0x0087, "Tablet"),
+    (0x0088, "Docking Station"),
+    (0x0089, "All in One"),
+    (0x008a, "Blade Server"),
+    (0x008b, "Convertible"),
+    (0x008c, "Detachable"),
+    (0x008d, "IoT Gateway"),
+    (0x008e, "Mini PC"),
+    (0x008f, "Stick PC"),
+    (0x00c1, "Sports Watch"),
+    (0x00c2, "Smartwatch"),
+    (0x0301, "Ear Thermometer"),
+    (0x0341, "Heart Rate Belt"),
+    (0x0381, "Arm Blood Pressure"),
+    (0x0382, "Wrist Blood Pressure"),
+    (0x03c1, "Keyboard"),
+    (0x03c2, "Mouse"),
+    (0x03c3, "Joystick"),
+    (0x03c4, "Gamepad"),
+    (0x03c5, "Digitizer Tablet"),
+    (0x03c6, "Card Reader"),
+    (0x03c7, "Digital Pen"),
+    (0x03c8, "Barcode Scanner"),
+    (0x03c9, "Touchpad"),
+    (0x03ca, "Presentation Remote"),
+    (0x0441, "In-Shoe Running Walking Sensor"),
+    (0x0442, "On-Shoe Running Walking Sensor"),
+    (0x0443, "On-Hip Running Walking Sensor"),
+    (0x0481, "Cycling Computer"),
+    (0x0482, "Speed Sensor"),
+    (0x0483, "Cadence Sensor"),
+    (0x0484, "Power Sensor"),
+    (0x0485, "Speed and Cadence Sensor"),
+    (0x04c1, "Switch"),
+    (0x04c2, "Multi-switch"),
+    (0x04c3, "Button"),
+    (0x04c4, "Slider"),
+    (0x04c5, "Rotary Switch"),
+    (0x04c6, "Touch Panel"),
+    (0x04c7, "Single Switch"),
+    (0x04c8, "Double Switch"),
+    (0x04c9, "Triple Switch"),
+    (0x04ca, "Battery Switch"),
+    (0x04cb, "Energy Harvesting Switch"),
+    (0x04cc, "Push Button"),
+    (0x04cd, "Dial"),
+    (0x0501, "Access Point"),
+    (0x0502, "Mesh Device"),
+    (0x0503, "Mesh Network Proxy"),
+    (0x0541, "Motion Sensor"),
+    (0x0542, "Air quality Sensor"),
+    (0x0543, "Temperature Sensor"),
+    (0x0544, "Humidity Sensor"),
+    (0x0545, "Leak Sensor"),
+    (0x0546, "Smoke Sensor"),
+    (0x0547, "Occupancy Sensor"),
+    (0x0548, "Contact Sensor"),
+    (0x0549, "Carbon Monoxide Sensor"),
+    (0x054a, "Carbon Dioxide Sensor"),
+    (0x054b, "Ambient Light Sensor"),
+    (0x054c, "Energy Sensor"),
+    (0x054d, "Color Light Sensor"),
+    (0x054e, "Rain Sensor"),
+    (0x054f, "Fire Sensor"),
+    (0x0550, "Wind Sensor"),
+    (0x0551, "Proximity Sensor"),
+    (0x0552, "Multi-Sensor"),
+    (0x0553, "Flush Mounted Sensor"),
+    (0x0554, "Ceiling Mounted Sensor"),
+    (0x0555, "Wall Mounted Sensor"),
+    (0x0556, "Multisensor"),
+    (0x0557, "Energy Meter"),
+    (0x0558, "Flame Detector"),
+    (0x0559, "Vehicle Tire Pressure Sensor"),
+    (0x0581, "Wall Light"),
+    (0x0582, "Ceiling Light"),
+    (0x0583, "Floor Light"),
+    (0x0584, "Cabinet Light"),
+    (0x0585, "Desk Light"),
+    (0x0586, "Troffer Light"),
+    (0x0587, "Pendant Light"),
+    (0x0588, "In-ground Light"),
+    (0x0589, "Flood Light"),
+    (0x058a, "Underwater Light"),
+    (0x058b, "Bollard with Light"),
+    (0x058c, "Pathway Light"),
+    (0x058d, "Garden Light"),
+    (0x058e, "Pole-top Light"),
+    (0x058f, "Spotlight"),
+    (0x0590, "Linear Light"),
+    (0x0591, "Street Light"),
+    (0x0592, "Shelves Light"),
+    (0x0593, "Bay Light"),
+    (0x0594, "Emergency Exit Light"),
+    (0x0595, "Light Controller"),
+    (0x0596, "Light Driver"),
+    (0x0597, "Bulb"),
+    (0x0598, "Low-bay Light"),
+    (0x0599, "High-bay Light"),
+    (0x05c1, "Ceiling Fan"),
+    (0x05c2, "Axial Fan"),
+    (0x05c3, "Exhaust Fan"),
+    (0x05c4, "Pedestal Fan"),
+    (0x05c5, "Desk Fan"),
+    (0x05c6, "Wall Fan"),
+    (0x0601, "Thermostat"),
+    (0x0602, "Humidifier"),
+    (0x0603, "De-humidifier"),
+    (0x0604, "Heater"),
+    (0x0605, "Radiator"),
+    (0x0606, "Boiler"),
+    (0x0607, "Heat Pump"),
+    (0x0608, "Infrared Heater"),
+    (0x0609, "Radiant Panel Heater"),
+    (0x060a, "Fan Heater"),
+    (0x060b, "Air Curtain"),
+    (0x06c1, "Radiator"),
+    (0x06c2, "Boiler"),
+    (0x06c3, "Heat Pump"),
+    (0x06c4, "Infrared Heater"),
+    (0x06c5, "Radiant Panel Heater"),
+    (0x06c6, "Fan Heater"),
+    (0x06c7, "Air Curtain"),
+    (0x0701, "Access Door"),
+    (0x0702, "Garage Door"),
+    (0x0703, "Emergency Exit Door"),
+    (0x0704, "Access Lock"),
+    (0x0705, "Elevator"),
+    (0x0706, "Window"),
+    (0x0707, "Entrance Gate"),
+    (0x0708, "Door Lock"),
+    (0x0709, "Locker"),
+    (0x0741, "Motorized Gate"),
+    (0x0742, "Awning"),
+    (0x0743, "Blinds or Shades"),
+    (0x0744, "Curtains"),
+    (0x0745, "Screen"),
+    (0x0781, "Power Outlet"),
+    (0x0782, "Power Strip"),
+    (0x0783, "Plug"),
+    (0x0784, "Power Supply"),
+    (0x0785, "LED Driver"),
+    (0x0786, "Fluorescent Lamp Gear"),
+    (0x0787, "HID Lamp Gear"),
+    (0x0788, "Charge Case"),
+    (0x0789, "Power Bank"),
+    (0x07c1, "Incandescent Light Bulb"),
+    (0x07c2, "LED Lamp"),
+    (0x07c3, "HID Lamp"),
+    (0x07c4, "Fluorescent Lamp"),
+    (0x07c5, "LED Array"),
+    (0x07c6, "Multi-Color LED Array"),
+    (0x07c7, "Low voltage halogen"),
+    (0x07c8, "Organic light emitting diode (OLED)"),
+    (0x0801, "Window Shades"),
+    (0x0802, "Window Blinds"),
+    (0x0803, "Window Awning"),
+    (0x0804, "Window Curtain"),
+    (0x0805, "Exterior Shutter"),
+    (0x0806, "Exterior Screen"),
+    (0x0841, "Standalone Speaker"),
+    (0x0842, "Soundbar"),
+    (0x0843, "Bookshelf Speaker"),
+    (0x0844, "Standmounted Speaker"),
+    (0x0845, "Speakerphone"),
+    (0x0881, "Microphone"),
+    (0x0882, "Alarm"),
+    (0x0883, "Bell"),
+    (0x0884, "Horn"),
+    (0x0885, "Broadcasting Device"),
+    (0x0886, "Service Desk"),
+    (0x0887, "Kiosk"),
+    (0x0888, "Broadcasting Room"),
+    (0x0889, "Auditorium"),
+    (0x08c1, "Car"),
+    (0x08c2, "Large Goods Vehicle"),
+    (0x08c3, "2-Wheeled Vehicle"),
+    (0x08c4, "Motorbike"),
+    (0x08c5, "Scooter"),
+    (0x08c6, "Moped"),
+    (0x08c7, "3-Wheeled Vehicle"),
+    (0x08c8, "Light Vehicle"),
+    (0x08c9, "Quad Bike"),
+    (0x08ca, "Minibus"),
+    (0x08cb, "Bus"),
+    (0x08cc, "Trolley"),
+    (0x08cd, "Agricultural Vehicle"),
+    (0x08ce, "Camper / Caravan"),
+    (0x08cf, "Recreational Vehicle / Motor Home"),
+    (0x0901, "Refrigerator"),
+    (0x0902, "Freezer"),
+    (0x0903, "Oven"),
+    (0x0904, "Microwave"),
+    (0x0905, "Toaster"),
+    (0x0906, "Washing Machine"),
+    (0x0907, "Dryer"),
+    (0x0908, "Coffee maker"),
+    (0x0909, "Clothes iron"),
+    (0x090a, "Curling iron"),
+    (0x090b, "Hair dryer"),
+    (0x090c, "Vacuum cleaner"),
+    (0x090d, "Robotic vacuum cleaner"),
+    (0x090e, "Rice cooker"),
+    (0x090f, "Clothes steamer"),
+    (0x0941, "Earbud"),
+    (0x0942, "Headset"),
+    (0x0943, "Headphones"),
+    (0x0944, "Neck Band"),
+    (0x0945, "Left Earbud"),
+    (0x0946, "Right Earbud"),
+    (0x0981, "Light Aircraft"),
+    (0x0982, "Microlight"),
+    (0x0983, "Paraglider"),
+    (0x0984, "Large Passenger Aircraft"),
+    (0x09c1, "Amplifier"),
+    (0x09c2, "Receiver"),
+    (0x09c3, "Radio"),
+    (0x09c4, "Tuner"),
+    (0x09c5, "Turntable"),
+    (0x09c6, "CD Player"),
+    (0x09c7, "DVD Player"),
+    (0x09c8, "Bluray Player"),
+    (0x09c9, "Optical Disc Player"),
+    (0x09ca, "Set-Top Box"),
+    (0x0a01, "Television"),
+    (0x0a02, "Monitor"),
+    (0x0a03, "Projector"),
+    (0x0a41, "In-ear hearing aid"),
+    (0x0a42, "Behind-ear hearing aid"),
+    (0x0a43, "Cochlear Implant"),
+    (0x0a81, "Home Video Game Console"),
+    (0x0a82, "Portable handheld console"),
+    (0x0ac1, "Digital Signage"),
+    (0x0ac2, "Electronic Label"),
+    (0x0c41, "Fingertip Pulse Oximeter"),
+    (0x0c42, "Wrist Worn Pulse Oximeter"),
+    (0x0cc1, "Powered Wheelchair"),
+    (0x0cc2, "Mobility Scooter"),
+    (0x0d41, "Insulin Pump, durable pump"),
+    (0x0d44, "Insulin Pump, patch pump"),
+    (0x0d48, "Insulin Pen"),
+    (0x0dc1, "Handheld Spirometer"),
+    (0x1441, "Location Display"),
+    (0x1442, "Location and Navigation Display"),
+    (0x1443, "Location Pod"),
+    (0x1444, "Location and Navigation Pod"),
+    (0x1481, "Torque Testing Device"),
+    (0x1482, "Caliper"),
+    (0x1483, "Dial Indicator"),
+    (0x1484, "Micrometer"),
+    (0x1485, "Height Gauge"),
+    (0x1486, "Force Gauge"),
+    (0x14c1, "Machine Tool Holder"),
+    (0x14c2, "Generic Clamping Device"),
+    (0x14c3, "Clamping Jaws/Jaw Chuck"),
+    (0x14c4, "Clamping (Collet) Chuck"),
+    (0x14c5, "Clamping Mandrel"),
+    (0x14c6, "Vise"),
+    (0x14c7, "Zero-Point Clamping System"),
+    (0x14c8, "Torque Wrench"),
+    (0x14c9, "Torque Screwdriver"),
+];
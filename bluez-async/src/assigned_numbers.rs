@@ -0,0 +1,139 @@
+//! Names for numbers assigned by the Bluetooth SIG, such as UUIDs and company identifiers.
+
+#[rustfmt::skip]
+mod generated;
+
+use std::fmt::{self, Display, Formatter};
+use uuid::Uuid;
+
+use self::generated::{APPEARANCES, CHARACTERISTICS, COMPANIES, DESCRIPTORS, MEMBERS, SERVICES};
+use crate::BleUuid;
+
+fn lookup(table: &'static [(u16, &'static str)], value: u16) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&value, |&(key, _)| key)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// Look up the name of the GATT service with the given 16-bit UUID.
+pub fn service_name(uuid: u16) -> Option<&'static str> {
+    lookup(SERVICES, uuid)
+}
+
+/// Look up the name of the GATT characteristic with the given 16-bit UUID.
+pub fn characteristic_name(uuid: u16) -> Option<&'static str> {
+    lookup(CHARACTERISTICS, uuid)
+}
+
+/// Look up the name of the GATT descriptor with the given 16-bit UUID.
+pub fn descriptor_name(uuid: u16) -> Option<&'static str> {
+    lookup(DESCRIPTORS, uuid)
+}
+
+/// Look up the name for the given 16-bit UUID assigned to a Bluetooth SIG member.
+pub fn member_name(uuid: u16) -> Option<&'static str> {
+    lookup(MEMBERS, uuid)
+}
+
+/// Look up the name of the company with the given identifier, as used for the keys of
+/// `DeviceInfo.manufacturer_data`.
+pub fn company_name(company_id: u16) -> Option<&'static str> {
+    lookup(COMPANIES, company_id)
+}
+
+/// Look up the name of the given GAP appearance value, including its category.
+pub fn appearance_name(appearance: u16) -> Option<&'static str> {
+    lookup(APPEARANCES, appearance)
+}
+
+/// Look up the name of the given UUID, if it is a 16-bit UUID assigned by the Bluetooth SIG for a
+/// service, characteristic, descriptor or member.
+pub(crate) fn uuid_name(uuid: &Uuid) -> Option<&'static str> {
+    let short = uuid.to_ble_u16()?;
+    service_name(short)
+        .or_else(|| characteristic_name(short))
+        .or_else(|| descriptor_name(short))
+        .or_else(|| member_name(short))
+}
+
+/// Wrapper to display a UUID along with its assigned name if it has one, e.g.
+/// `Battery Level (0x2a19)`.
+///
+/// Returned by [`BleUuid::named`](../trait.BleUuid.html#tymethod.named).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NamedUuid(pub Uuid);
+
+impl Display for NamedUuid {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0.name() {
+            Some(name) => write!(f, "{} ({})", name, self.0.succinctly()),
+            None => f.write_str(&self.0.succinctly()),
+        }
+    }
+}
+
+/// Wrapper to display a company identifier along with the name of the company if it is known, e.g.
+/// `Apple, Inc. (0x004c)`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct NamedCompany(pub u16);
+
+impl Display for NamedCompany {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match company_name(self.0) {
+            Some(name) => write!(f, "{} ({:#06x})", name, self.0),
+            None => write!(f, "{:#06x}", self.0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{uuid_from_u16, uuid_from_u32};
+
+    #[test]
+    fn tables_sorted() {
+        for table in [
+            SERVICES,
+            CHARACTERISTICS,
+            DESCRIPTORS,
+            MEMBERS,
+            COMPANIES,
+            APPEARANCES,
+        ] {
+            assert!(table.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+    }
+
+    #[test]
+    fn uuid_names() {
+        assert_eq!(uuid_from_u16(0x180f).name(), Some("Battery"));
+        assert_eq!(uuid_from_u16(0x2a19).name(), Some("Battery Level"));
+        assert_eq!(
+            uuid_from_u16(0x2902).name(),
+            Some("Client Characteristic Configuration")
+        );
+        assert_eq!(uuid_from_u16(0xfeaa).name(), Some("Eddystone"));
+        assert_eq!(uuid_from_u16(0x0000).name(), None);
+        assert_eq!(uuid_from_u32(0x12345678).name(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            uuid_from_u16(0x2a19).named().to_string(),
+            "Battery Level (0x2a19)"
+        );
+        assert_eq!(uuid_from_u32(0x12345678).named().to_string(), "0x12345678");
+        assert_eq!(NamedCompany(0x004c).to_string(), "Apple, Inc. (0x004c)");
+        assert_eq!(NamedCompany(0xfffe).to_string(), "0xfffe");
+    }
+
+    #[test]
+    fn appearances() {
+        assert_eq!(appearance_name(0x00c0), Some("Watch"));
+        assert_eq!(appearance_name(0x00c1), Some("Watch: Sports Watch"));
+        assert_eq!(appearance_name(0x00ff), None);
+    }
+}
@@ -0,0 +1,3780 @@
+// This file is generated by assigned_numbers.py from the Bluetooth SIG assigned numbers.
+// Source revision: bluetooth-SIG/public 8c192a8effd1574d0c83e72ad91407511bcad209 (UUIDs and appearance values, via btuuid 0.1.1); company identifiers and member UUIDs from bluetooth-numbers-database v1 (via bluer 0.17.4)
+// Do not edit it by hand.
+
+/// GATT service 16-bit UUIDs.
+pub(super) const SERVICES: &[(u16, &str)] = &[
+    (0x1800, "GAP"),
+    (0x1801, "GATT"),
+    (0x1802, "Immediate Alert"),
+    (0x1803, "Link Loss"),
+    (0x1804, "Tx Power"),
+    (0x1805, "Current Time"),
+    (0x1806, "Reference Time Update"),
+    (0x1807, "Next DST Change"),
+    (0x1808, "Glucose"),
+    (0x1809, "Health Thermometer"),
+    (0x180a, "Device Information"),
+    (0x180d, "Heart Rate"),
+    (0x180e, "Phone Alert Status"),
+    (0x180f, "Battery"),
+    (0x1810, "Blood Pressure"),
+    (0x1811, "Alert Notification"),
+    (0x1812, "Human Interface Device"),
+    (0x1813, "Scan Parameters"),
+    (0x1814, "Running Speed and Cadence"),
+    (0x1815, "Automation IO"),
+    (0x1816, "Cycling Speed and Cadence"),
+    (0x1818, "Cycling Power"),
+    (0x1819, "Location and Navigation"),
+    (0x181a, "Environmental Sensing"),
+    (0x181b, "Body Composition"),
+    (0x181c, "User Data"),
+    (0x181d, "Weight Scale"),
+    (0x181e, "Bond Management"),
+    (0x181f, "Continuous Glucose Monitoring"),
+    (0x1820, "Internet Protocol Support"),
+    (0x1821, "Indoor Positioning"),
+    (0x1822, "Pulse Oximeter"),
+    (0x1823, "HTTP Proxy"),
+    (0x1824, "Transport Discovery"),
+    (0x1825, "Object Transfer"),
+    (0x1826, "Fitness Machine"),
+    (0x1827, "Mesh Provisioning"),
+    (0x1828, "Mesh Proxy"),
+    (0x1829, "Reconnection Configuration"),
+    (0x183a, "Insulin Delivery"),
+    (0x183b, "Binary Sensor"),
+    (0x183c, "Emergency Configuration"),
+    (0x183d, "Authorization Control"),
+    (0x183e, "Physical Activity Monitor"),
+    (0x183f, "Elapsed Time"),
+    (0x1840, "Generic Health Sensor"),
+    (0x1843, "Audio Input Control"),
+    (0x1844, "Volume Control"),
+    (0x1845, "Volume Offset Control"),
+    (0x1846, "Coordinated Set Identification"),
+    (0x1847, "Device Time"),
+    (0x1848, "Media Control"),
+    (0x1849, "Generic Media Control"),
+    (0x184a, "Constant Tone Extension"),
+    (0x184b, "Telephone Bearer"),
+    (0x184c, "Generic Telephone Bearer"),
+    (0x184d, "Microphone Control"),
+    (0x184e, "Audio Stream Control"),
+    (0x184f, "Broadcast Audio Scan"),
+    (0x1850, "Published Audio Capabilities"),
+    (0x1851, "Basic Audio Announcement"),
+    (0x1852, "Broadcast Audio Announcement"),
+    (0x1853, "Common Audio"),
+    (0x1854, "Hearing Access"),
+    (0x1855, "Telephony and Media Audio"),
+    (0x1856, "Public Broadcast Announcement"),
+    (0x1857, "Electronic Shelf Label"),
+    (0x1858, "Gaming Audio"),
+    (0x1859, "Mesh Proxy Solicitation"),
+    (0x185a, "Industrial Measurement Device"),
+    (0x185b, "Ranging"),
+    (0x185c, "HID ISO"),
+];
+
+/// GATT characteristic 16-bit UUIDs.
+pub(super) const CHARACTERISTICS: &[(u16, &str)] = &[
+    (0x2a00, "Device Name"),
+    (0x2a01, "Appearance"),
+    (0x2a02, "Peripheral Privacy Flag"),
+    (0x2a03, "Reconnection Address"),
+    (0x2a04, "Peripheral Preferred Connection Parameters"),
+    (0x2a05, "Service Changed"),
+    (0x2a06, "Alert Level"),
+    (0x2a07, "Tx Power Level"),
+    (0x2a08, "Date Time"),
+    (0x2a09, "Day of Week"),
+    (0x2a0a, "Day Date Time"),
+    (0x2a0c, "Exact Time 256"),
+    (0x2a0d, "DST Offset"),
+    (0x2a0e, "Time Zone"),
+    (0x2a0f, "Local Time Information"),
+    (0x2a11, "Time with DST"),
+    (0x2a12, "Time Accuracy"),
+    (0x2a13, "Time Source"),
+    (0x2a14, "Reference Time Information"),
+    (0x2a16, "Time Update Control Point"),
+    (0x2a17, "Time Update State"),
+    (0x2a18, "Glucose Measurement"),
+    (0x2a19, "Battery Level"),
+    (0x2a1c, "Temperature Measurement"),
+    (0x2a1d, "Temperature Type"),
+    (0x2a1e, "Intermediate Temperature"),
+    (0x2a21, "Measurement Interval"),
+    (0x2a22, "Boot Keyboard Input Report"),
+    (0x2a23, "System ID"),
+    (0x2a24, "Model Number String"),
+    (0x2a25, "Serial Number String"),
+    (0x2a26, "Firmware Revision String"),
+    (0x2a27, "Hardware Revision String"),
+    (0x2a28, "Software Revision String"),
+    (0x2a29, "Manufacturer Name String"),
+    (0x2a2a, "IEEE 11073-20601 Regulatory Certification Data List"),
+    (0x2a2b, "Current Time"),
+    (0x2a2c, "Magnetic Declination"),
+    (0x2a31, "Scan Refresh"),
+    (0x2a32, "Boot Keyboard Output Report"),
+    (0x2a33, "Boot Mouse Input Report"),
+    (0x2a34, "Glucose Measurement Context"),
+    (0x2a35, "Blood Pressure Measurement"),
+    (0x2a36, "Intermediate Cuff Pressure"),
+    (0x2a37, "Heart Rate Measurement"),
+    (0x2a38, "Body Sensor Location"),
+    (0x2a39, "Heart Rate Control Point"),
+    (0x2a3f, "Alert Status"),
+    (0x2a40, "Ringer Control Point"),
+    (0x2a41, "Ringer Setting"),
+    (0x2a42, "Alert Category ID Bit Mask"),
+    (0x2a43, "Alert Category ID"),
+    (0x2a44, "Alert Notification Control Point"),
+    (0x2a45, "Unread Alert Status"),
+    (0x2a46, "New Alert"),
+    (0x2a47, "Supported New Alert Category"),
+    (0x2a48, "Supported Unread Alert Category"),
+    (0x2a49, "Blood Pressure Feature"),
+    (0x2a4a, "HID Information"),
+    (0x2a4b, "Report Map"),
+    (0x2a4c, "HID Control Point"),
+    (0x2a4d, "Report"),
+    (0x2a4e, "Protocol Mode"),
+    (0x2a4f, "Scan Interval Window"),
+    (0x2a50, "PnP ID"),
+    (0x2a51, "Glucose Feature"),
+    (0x2a52, "Record Access Control Point"),
+    (0x2a53, "RSC Measurement"),
+    (0x2a54, "RSC Feature"),
+    (0x2a55, "SC Control Point"),
+    (0x2a5a, "Aggregate"),
+    (0x2a5b, "CSC Measurement"),
+    (0x2a5c, "CSC Feature"),
+    (0x2a5d, "Sensor Location"),
+    (0x2a5e, "PLX Spot-Check Measurement"),
+    (0x2a5f, "PLX Continuous Measurement"),
+    (0x2a60, "PLX Features"),
+    (0x2a63, "Cycling Power Measurement"),
+    (0x2a64, "Cycling Power Vector"),
+    (0x2a65, "Cycling Power Feature"),
+    (0x2a66, "Cycling Power Control Point"),
+    (0x2a67, "Location and Speed"),
+    (0x2a68, "Navigation"),
+    (0x2a69, "Position Quality"),
+    (0x2a6a, "LN Feature"),
+    (0x2a6b, "LN Control Point"),
+    (0x2a6c, "Elevation"),
+    (0x2a6d, "Pressure"),
+    (0x2a6e, "Temperature"),
+    (0x2a6f, "Humidity"),
+    (0x2a70, "True Wind Speed"),
+    (0x2a71, "True Wind Direction"),
+    (0x2a72, "Apparent Wind Speed"),
+    (0x2a73, "Apparent Wind Direction"),
+    (0x2a74, "Gust Factor"),
+    (0x2a75, "Pollen Concentration"),
+    (0x2a76, "UV Index"),
+    (0x2a77, "Irradiance"),
+    (0x2a78, "Rainfall"),
+    (0x2a79, "Wind Chill"),
+    (0x2a7a, "Heat Index"),
+    (0x2a7b, "Dew Point"),
+    (0x2a7d, "Descriptor Value Changed"),
+    (0x2a7e, "Aerobic Heart Rate Lower Limit"),
+    (0x2a7f, "Aerobic Threshold"),
+    (0x2a80, "Age"),
+    (0x2a81, "Anaerobic Heart Rate Lower Limit"),
+    (0x2a82, "Anaerobic Heart Rate Upper Limit"),
+    (0x2a83, "Anaerobic Threshold"),
+    (0x2a84, "Aerobic Heart Rate Upper Limit"),
+    (0x2a85, "Date of Birth"),
+    (0x2a86, "Date of Threshold Assessment"),
+    (0x2a87, "Email Address"),
+    (0x2a88, "Fat Burn Heart Rate Lower Limit"),
+    (0x2a89, "Fat Burn Heart Rate Upper Limit"),
+    (0x2a8a, "First Name"),
+    (0x2a8b, "Five Zone Heart Rate Limits"),
+    (0x2a8c, "Gender"),
+    (0x2a8d, "Heart Rate Max"),
+    (0x2a8e, "Height"),
+    (0x2a8f, "Hip Circumference"),
+    (0x2a90, "Last Name"),
+    (0x2a91, "Maximum Recommended Heart Rate"),
+    (0x2a92, "Resting Heart Rate"),
+    (0x2a93, "Sport Type for Aerobic and Anaerobic Thresholds"),
+    (0x2a94, "Three Zone Heart Rate Limits"),
+    (0x2a95, "Two Zone Heart Rate Limits"),
+    (0x2a96, "VO2 Max"),
+    (0x2a97, "Waist Circumference"),
+    (0x2a98, "Weight"),
+    (0x2a99, "Database Change Increment"),
+    (0x2a9a, "User Index"),
+    (0x2a9b, "Body Composition Feature"),
+    (0x2a9c, "Body Composition Measurement"),
+    (0x2a9d, "Weight Measurement"),
+    (0x2a9e, "Weight Scale Feature"),
+    (0x2a9f, "User Control Point"),
+    (0x2aa0, "Magnetic Flux Density - 2D"),
+    (0x2aa1, "Magnetic Flux Density - 3D"),
+    (0x2aa2, "Language"),
+    (0x2aa3, "Barometric Pressure Trend"),
+    (0x2aa4, "Bond Management Control Point"),
+    (0x2aa5, "Bond Management Feature"),
+    (0x2aa6, "Central Address Resolution"),
+    (0x2aa7, "CGM Measurement"),
+    (0x2aa8, "CGM Feature"),
+    (0x2aa9, "CGM Status"),
+    (0x2aaa, "CGM Session Start Time"),
+    (0x2aab, "CGM Session Run Time"),
+    (0x2aac, "CGM Specific Ops Control Point"),
+    (0x2aad, "Indoor Positioning Configuration"),
+    (0x2aae, "Latitude"),
+    (0x2aaf, "Longitude"),
+    (0x2ab0, "Local North Coordinate"),
+    (0x2ab1, "Local East Coordinate"),
+    (0x2ab2, "Floor Number"),
+    (0x2ab3, "Altitude"),
+    (0x2ab4, "Uncertainty"),
+    (0x2ab5, "Location Name"),
+    (0x2ab6, "URI"),
+    (0x2ab7, "HTTP Headers"),
+    (0x2ab8, "HTTP Status Code"),
+    (0x2ab9, "HTTP Entity Body"),
+    (0x2aba, "HTTP Control Point"),
+    (0x2abb, "HTTPS Security"),
+    (0x2abc, "TDS Control Point"),
+    (0x2abd, "OTS Feature"),
+    (0x2abe, "Object Name"),
+    (0x2abf, "Object Type"),
+    (0x2ac0, "Object Size"),
+    (0x2ac1, "Object First-Created"),
+    (0x2ac2, "Object Last-Modified"),
+    (0x2ac3, "Object ID"),
+    (0x2ac4, "Object Properties"),
+    (0x2ac5, "Object Action Control Point"),
+    (0x2ac6, "Object List Control Point"),
+    (0x2ac7, "Object List Filter"),
+    (0x2ac8, "Object Changed"),
+    (0x2ac9, "Resolvable Private Address Only"),
+    (0x2acc, "Fitness Machine Feature"),
+    (0x2acd, "Treadmill Data"),
+    (0x2ace, "Cross Trainer Data"),
+    (0x2acf, "Step Climber Data"),
+    (0x2ad0, "Stair Climber Data"),
+    (0x2ad1, "Rower Data"),
+    (0x2ad2, "Indoor Bike Data"),
+    (0x2ad3, "Training Status"),
+    (0x2ad4, "Supported Speed Range"),
+    (0x2ad5, "Supported Inclination Range"),
+    (0x2ad6, "Supported Resistance Level Range"),
+    (0x2ad7, "Supported Heart Rate Range"),
+    (0x2ad8, "Supported Power Range"),
+    (0x2ad9, "Fitness Machine Control Point"),
+    (0x2ada, "Fitness Machine Status"),
+    (0x2adb, "Mesh Provisioning Data In"),
+    (0x2adc, "Mesh Provisioning Data Out"),
+    (0x2add, "Mesh Proxy Data In"),
+    (0x2ade, "Mesh Proxy Data Out"),
+    (0x2ae0, "Average Current"),
+    (0x2ae1, "Average Voltage"),
+    (0x2ae2, "Boolean"),
+    (0x2ae3, "Chromatic Distance from Planckian"),
+    (0x2ae4, "Chromaticity Coordinates"),
+    (0x2ae5, "Chromaticity in CCT and Duv Values"),
+    (0x2ae6, "Chromaticity Tolerance"),
+    (0x2ae7, "CIE 13.3-1995 Color Rendering Index"),
+    (0x2ae8, "Coefficient"),
+    (0x2ae9, "Correlated Color Temperature"),
+    (0x2aea, "Count 16"),
+    (0x2aeb, "Count 24"),
+    (0x2aec, "Country Code"),
+    (0x2aed, "Date UTC"),
+    (0x2aee, "Electric Current"),
+    (0x2aef, "Electric Current Range"),
+    (0x2af0, "Electric Current Specification"),
+    (0x2af1, "Electric Current Statistics"),
+    (0x2af2, "Energy"),
+    (0x2af3, "Energy in a Period of Day"),
+    (0x2af4, "Event Statistics"),
+    (0x2af5, "Fixed String 16"),
+    (0x2af6, "Fixed String 24"),
+    (0x2af7, "Fixed String 36"),
+    (0x2af8, "Fixed String 8"),
+    (0x2af9, "Generic Level"),
+    (0x2afa, "Global Trade Item Number"),
+    (0x2afb, "Illuminance"),
+    (0x2afc, "Luminous Efficacy"),
+    (0x2afd, "Luminous Energy"),
+    (0x2afe, "Luminous Exposure"),
+    (0x2aff, "Luminous Flux"),
+    (0x2b00, "Luminous Flux Range"),
+    (0x2b01, "Luminous Intensity"),
+    (0x2b02, "Mass Flow"),
+    (0x2b03, "Perceived Lightness"),
+    (0x2b04, "Percentage 8"),
+    (0x2b05, "Power"),
+    (0x2b06, "Power Specification"),
+    (0x2b07, "Relative Runtime in a Current Range"),
+    (0x2b08, "Relative Runtime in a Generic Level Range"),
+    (0x2b09, "Relative Value in a Voltage Range"),
+    (0x2b0a, "Relative Value in an Illuminance Range"),
+    (0x2b0b, "Relative Value in a Period of Day"),
+    (0x2b0c, "Relative Value in a Temperature Range"),
+    (0x2b0d, "Temperature 8"),
+    (0x2b0e, "Temperature 8 in a Period of Day"),
+    (0x2b0f, "Temperature 8 Statistics"),
+    (0x2b10, "Temperature Range"),
+    (0x2b11, "Temperature Statistics"),
+    (0x2b12, "Time Decihour 8"),
+    (0x2b13, "Time Exponential 8"),
+    (0x2b14, "Time Hour 24"),
+    (0x2b15, "Time Millisecond 24"),
+    (0x2b16, "Time Second 16"),
+    (0x2b17, "Time Second 8"),
+    (0x2b18, "Voltage"),
+    (0x2b19, "Voltage Specification"),
+    (0x2b1a, "Voltage Statistics"),
+    (0x2b1b, "Volume Flow"),
+    (0x2b1c, "Chromaticity Coordinate"),
+    (0x2b1d, "RC Feature"),
+    (0x2b1e, "RC Settings"),
+    (0x2b1f, "Reconnection Configuration Control Point"),
+    (0x2b20, "IDD Status Changed"),
+    (0x2b21, "IDD Status"),
+    (0x2b22, "IDD Annunciation Status"),
+    (0x2b23, "IDD Features"),
+    (0x2b24, "IDD Status Reader Control Point"),
+    (0x2b25, "IDD Command Control Point"),
+    (0x2b26, "IDD Command Data"),
+    (0x2b27, "IDD Record Access Control Point"),
+    (0x2b28, "IDD History Data"),
+    (0x2b29, "Client Supported Features"),
+    (0x2b2a, "Database Hash"),
+    (0x2b2b, "BSS Control Point"),
+    (0x2b2c, "BSS Response"),
+    (0x2b2d, "Emergency ID"),
+    (0x2b2e, "Emergency Text"),
+    (0x2b2f, "ACS Status"),
+    (0x2b30, "ACS Data In"),
+    (0x2b31, "ACS Data Out Notify"),
+    (0x2b32, "ACS Data Out Indicate"),
+    (0x2b33, "ACS Control Point"),
+    (0x2b34, "Enhanced Blood Pressure Measurement"),
+    (0x2b35, "Enhanced Intermediate Cuff Pressure"),
+    (0x2b36, "Blood Pressure Record"),
+    (0x2b37, "Registered User"),
+    (0x2b38, "BR-EDR Handover Data"),
+    (0x2b39, "Bluetooth SIG Data"),
+    (0x2b3a, "Server Supported Features"),
+    (0x2b3b, "Physical Activity Monitor Features"),
+    (0x2b3c, "General Activity Instantaneous Data"),
+    (0x2b3d, "General Activity Summary Data"),
+    (0x2b3e, "CardioRespiratory Activity Instantaneous Data"),
+    (0x2b3f, "CardioRespiratory Activity Summary Data"),
+    (0x2b40, "Step Counter Activity Summary Data"),
+    (0x2b41, "Sleep Activity Instantaneous Data"),
+    (0x2b42, "Sleep Activity Summary Data"),
+    (0x2b43, "Physical Activity Monitor Control Point"),
+    (0x2b44, "Physical Activity Current Session"),
+    (0x2b45, "Physical Activity Session Descriptor"),
+    (0x2b46, "Preferred Units"),
+    (0x2b47, "High Resolution Height"),
+    (0x2b48, "Middle Name"),
+    (0x2b49, "Stride Length"),
+    (0x2b4a, "Handedness"),
+    (0x2b4b, "Device Wearing Position"),
+    (0x2b4c, "Four Zone Heart Rate Limits"),
+    (0x2b4d, "High Intensity Exercise Threshold"),
+    (0x2b4e, "Activity Goal"),
+    (0x2b4f, "Sedentary Interval Notification"),
+    (0x2b50, "Caloric Intake"),
+    (0x2b51, "TMAP Role"),
+    (0x2b77, "Audio Input State"),
+    (0x2b78, "Gain Settings Attribute"),
+    (0x2b79, "Audio Input Type"),
+    (0x2b7a, "Audio Input Status"),
+    (0x2b7b, "Audio Input Control Point"),
+    (0x2b7c, "Audio Input Description"),
+    (0x2b7d, "Volume State"),
+    (0x2b7e, "Volume Control Point"),
+    (0x2b7f, "Volume Flags"),
+    (0x2b80, "Volume Offset State"),
+    (0x2b81, "Audio Location"),
+    (0x2b82, "Volume Offset Control Point"),
+    (0x2b83, "Audio Output Description"),
+    (0x2b84, "Set Identity Resolving Key"),
+    (0x2b85, "Coordinated Set Size"),
+    (0x2b86, "Set Member Lock"),
+    (0x2b87, "Set Member Rank"),
+    (0x2b88, "Encrypted Data Key Material"),
+    (0x2b89, "Apparent Energy 32"),
+    (0x2b8a, "Apparent Power"),
+    (0x2b8b, "Live Health Observations"),
+    (0x2b8c, "CO\\textsubscript{2} Concentration"),
+    (0x2b8d, "Cosine of the Angle"),
+    (0x2b8e, "Device Time Feature"),
+    (0x2b8f, "Device Time Parameters"),
+    (0x2b90, "Device Time"),
+    (0x2b91, "Device Time Control Point"),
+    (0x2b92, "Time Change Log Data"),
+    (0x2b93, "Media Player Name"),
+    (0x2b94, "Media Player Icon Object ID"),
+    (0x2b95, "Media Player Icon URL"),
+    (0x2b96, "Track Changed"),
+    (0x2b97, "Track Title"),
+    (0x2b98, "Track Duration"),
+    (0x2b99, "Track Position"),
+    (0x2b9a, "Playback Speed"),
+    (0x2b9b, "Seeking Speed"),
+    (0x2b9c, "Current Track Segments Object ID"),
+    (0x2b9d, "Current Track Object ID"),
+    (0x2b9e, "Next Track Object ID"),
+    (0x2b9f, "Parent Group Object ID"),
+    (0x2ba0, "Current Group Object ID"),
+    (0x2ba1, "Playing Order"),
+    (0x2ba2, "Playing Orders Supported"),
+    (0x2ba3, "Media State"),
+    (0x2ba4, "Media Control Point"),
+    (0x2ba5, "Media Control Point Opcodes Supported"),
+    (0x2ba6, "Search Results Object ID"),
+    (0x2ba7, "Search Control Point"),
+    (0x2ba8, "Energy 32"),
+    (0x2bad, "Constant Tone Extension Enable"),
+    (0x2bae, "Advertising Constant Tone Extension Minimum Length"),
+    (0x2baf, "Advertising Constant Tone Extension Minimum Transmit Count"),
+    (0x2bb0, "Advertising Constant Tone Extension Transmit Duration"),
+    (0x2bb1, "Advertising Constant Tone Extension Interval"),
+    (0x2bb2, "Advertising Constant Tone Extension PHY"),
+    (0x2bb3, "Bearer Provider Name"),
+    (0x2bb4, "Bearer UCI"),
+    (0x2bb5, "Bearer Technology"),
+    (0x2bb6, "Bearer URI Schemes Supported List"),
+    (0x2bb7, "Bearer Signal Strength"),
+    (0x2bb8, "Bearer Signal Strength Reporting Interval"),
+    (0x2bb9, "Bearer List Current Calls"),
+    (0x2bba, "Content Control ID"),
+    (0x2bbb, "Status Flags"),
+    (0x2bbc, "Incoming Call Target Bearer URI"),
+    (0x2bbd, "Call State"),
+    (0x2bbe, "Call Control Point"),
+    (0x2bbf, "Call Control Point Optional Opcodes"),
+    (0x2bc0, "Termination Reason"),
+    (0x2bc1, "Incoming Call"),
+    (0x2bc2, "Call Friendly Name"),
+    (0x2bc3, "Mute"),
+    (0x2bc4, "Sink ASE"),
+    (0x2bc5, "Source ASE"),
+    (0x2bc6, "ASE Control Point"),
+    (0x2bc7, "Broadcast Audio Scan Control Point"),
+    (0x2bc8, "Broadcast Receive State"),
+    (0x2bc9, "Sink PAC"),
+    (0x2bca, "Sink Audio Locations"),
+    (0x2bcb, "Source PAC"),
+    (0x2bcc, "Source Audio Locations"),
+    (0x2bcd, "Available Audio Contexts"),
+    (0x2bce, "Supported Audio Contexts"),
+    (0x2bcf, "Ammonia Concentration"),
+    (0x2bd0, "Carbon Monoxide Concentration"),
+    (0x2bd1, "Methane Concentration"),
+    (0x2bd2, "Nitrogen Dioxide Concentration"),
+    (0x2bd3, "Non-Methane Volatile Organic Compounds Concentration"),
+    (0x2bd4, "Ozone Concentration"),
+    (0x2bd5, "Particulate Matter - PM1 Concentration"),
+    (0x2bd6, "Particulate Matter - PM2.5 Concentration"),
+    (0x2bd7, "Particulate Matter - PM10 Concentration"),
+    (0x2bd8, "Sulfur Dioxide Concentration"),
+    (0x2bd9, "Sulfur Hexafluoride Concentration"),
+    (0x2bda, "Hearing Aid Features"),
+    (0x2bdb, "Hearing Aid Preset Control Point"),
+    (0x2bdc, "Active Preset Index"),
+    (0x2bdd, "Stored Health Observations"),
+    (0x2bde, "Fixed String 64"),
+    (0x2bdf, "High Temperature"),
+    (0x2be0, "High Voltage"),
+    (0x2be1, "Light Distribution"),
+    (0x2be2, "Light Output"),
+    (0x2be3, "Light Source Type"),
+    (0x2be4, "Noise"),
+    (0x2be5, "Relative Runtime in a Correlated Color Temperature Range"),
+    (0x2be6, "Time Second 32"),
+    (0x2be7, "VOC Concentration"),
+    (0x2be8, "Voltage Frequency"),
+    (0x2be9, "Battery Critical Status"),
+    (0x2bea, "Battery Health Status"),
+    (0x2beb, "Battery Health Information"),
+    (0x2bec, "Battery Information"),
+    (0x2bed, "Battery Level Status"),
+    (0x2bee, "Battery Time Status"),
+    (0x2bef, "Estimated Service Date"),
+    (0x2bf0, "Battery Energy Status"),
+    (0x2bf1, "Observation Schedule Changed"),
+    (0x2bf2, "Current Elapsed Time"),
+    (0x2bf3, "Health Sensor Features"),
+    (0x2bf4, "GHS Control Point"),
+    (0x2bf5, "LE GATT Security Levels"),
+    (0x2bf6, "ESL Address"),
+    (0x2bf7, "AP Sync Key Material"),
+    (0x2bf8, "ESL Response Key Material"),
+    (0x2bf9, "ESL Current Absolute Time"),
+    (0x2bfa, "ESL Display Information"),
+    (0x2bfb, "ESL Image Information"),
+    (0x2bfc, "ESL Sensor Information"),
+    (0x2bfd, "ESL LED Information"),
+    (0x2bfe, "ESL Control Point"),
+    (0x2bff, "UDI for Medical Devices"),
+    (0x2c00, "GMAP Role"),
+    (0x2c01, "UGG Features"),
+    (0x2c02, "UGT Features"),
+    (0x2c03, "BGS Features"),
+    (0x2c04, "BGR Features"),
+    (0x2c05, "Percentage 8 Steps"),
+    (0x2c06, "Acceleration"),
+    (0x2c07, "Force"),
+    (0x2c08, "Linear Position"),
+    (0x2c09, "Rotational Speed"),
+    (0x2c0a, "Length"),
+    (0x2c0b, "Torque"),
+    (0x2c0c, "IMD Status"),
+    (0x2c0d, "IMDS Descriptor Value Changed"),
+    (0x2c0e, "First Use Date"),
+    (0x2c0f, "Life Cycle Data"),
+    (0x2c10, "Work Cycle Data"),
+    (0x2c11, "Service Cycle Data"),
+    (0x2c12, "IMD Control"),
+    (0x2c13, "IMD Historical Data"),
+    (0x2c14, "RAS Features"),
+    (0x2c15, "Real-time Ranging Data"),
+    (0x2c16, "On-demand Ranging Data"),
+    (0x2c17, "RAS Control Point"),
+    (0x2c18, "Ranging Data Ready"),
+    (0x2c19, "Ranging Data Overwritten"),
+    (0x2c1b, "Humidity 8"),
+    (0x2c1c, "Illuminance 16"),
+    (0x2c1d, "Acceleration 3D"),
+    (0x2c1e, "Precise Acceleration 3D"),
+    (0x2c1f, "Acceleration Detection Status"),
+    (0x2c20, "Door/Window Status"),
+    (0x2c21, "Pushbutton Status 8"),
+    (0x2c22, "Contact Status 8"),
+    (0x2c23, "HID ISO Properties"),
+    (0x2c24, "LE HID Operation Mode"),
+];
+
+/// GATT descriptor 16-bit UUIDs.
+pub(super) const DESCRIPTORS: &[(u16, &str)] = &[
+    (0x2900, "Characteristic Extended Properties"),
+    (0x2901, "Characteristic User Description"),
+    (0x2902, "Client Characteristic Configuration"),
+    (0x2903, "Server Characteristic Configuration"),
+    (0x2904, "Characteristic Presentation Format"),
+    (0x2905, "Characteristic Aggregate Format"),
+    (0x2906, "Valid Range"),
+    (0x2907, "External Report Reference"),
+    (0x2908, "Report Reference"),
+    (0x2909, "Number of Digitals"),
+    (0x290a, "Value Trigger Setting"),
+    (0x290b, "Environmental Sensing Configuration"),
+    (0x290c, "Environmental Sensing Measurement"),
+    (0x290d, "Environmental Sensing Trigger Setting"),
+    (0x290e, "Time Trigger Setting"),
+    (0x290f, "Complete BR-EDR Transport Block Data"),
+    (0x2910, "Observation Schedule"),
+    (0x2911, "Valid Range and Accuracy"),
+    (0x2912, "Measurement Description"),
+    (0x2913, "Manufacturer Limits"),
+    (0x2914, "Process Tolerances"),
+    (0x2915, "IMD Trigger Setting"),
+];
+
+/// 16-bit UUIDs assigned to SIG members.
+pub(super) const MEMBERS: &[(u16, &str)] = &[
+    (0xfd6f, "Exposure Notification Service"),
+    (0xfe0f, "Signify Netherlands B.V. (formerly Phillips Lighting) Service"),
+    (0xfe2c, "Fast Pair Service"),
+    (0xfe59, "Secure DFU Service"),
+    (0xfeaa, "Eddystone"),
+    (0xfebb, "File Transfer Service by Adafruit"),
+];
+
+/// Company identifiers.
+pub(super) const COMPANIES: &[(u16, &str)] = &[
+    (0x0000, "Ericsson Technology Licensing"),
+    (0x0001, "Nokia Mobile Phones"),
+    (0x0002, "Intel Corp."),
+    (0x0003, "IBM Corp."),
+    (0x0004, "Toshiba Corp."),
+    (0x0005, "3Com"),
+    (0x0006, "Microsoft"),
+    (0x0007, "Lucent"),
+    (0x0008, "Motorola"),
+    (0x0009, "Infineon Technologies AG"),
+    (0x000a, "Qualcomm Technologies International, Ltd. (QTIL)"),
+    (0x000b, "Silicon Wave"),
+    (0x000c, "Digianswer A/S"),
+    (0x000d, "Texas Instruments Inc."),
+    (0x000e, "Parthus Technologies Inc."),
+    (0x000f, "Broadcom Corporation"),
+    (0x0010, "Mitel Semiconductor"),
+    (0x0011, "Widcomm, Inc."),
+    (0x0012, "Zeevo, Inc."),
+    (0x0013, "Atmel Corporation"),
+    (0x0014, "Mitsubishi Electric Corporation"),
+    (0x0015, "RTX Telecom A/S"),
+    (0x0016, "KC Technology Inc."),
+    (0x0017, "Newlogic"),
+    (0x0018, "Transilica, Inc."),
+    (0x0019, "Rohde & Schwarz GmbH & Co. KG"),
+    (0x001a, "TTPCom Limited"),
+    (0x001b, "Signia Technologies, Inc."),
+    (0x001c, "Conexant Systems Inc."),
+    (0x001d, "Qualcomm"),
+    (0x001e, "Inventel"),
+    (0x001f, "AVM Berlin"),
+    (0x0020, "BandSpeed, Inc."),
+    (0x0021, "Mansella Ltd"),
+    (0x0022, "NEC Corporation"),
+    (0x0023, "WavePlus Technology Co., Ltd."),
+    (0x0024, "Alcatel"),
+    (0x0025, "NXP Semiconductors (formerly Philips Semiconductors)"),
+    (0x0026, "C Technologies"),
+    (0x0027, "Open Interface"),
+    (0x0028, "R F Micro Devices"),
+    (0x0029, "Hitachi Ltd"),
+    (0x002a, "Symbol Technologies, Inc."),
+    (0x002b, "Tenovis"),
+    (0x002c, "Macronix International Co. Ltd."),
+    (0x002d, "GCT Semiconductor"),
+    (0x002e, "Norwood Systems"),
+    (0x002f, "MewTel Technology Inc."),
+    (0x0030, "ST Microelectronics"),
+    (0x0031, "Synopsys, Inc."),
+    (0x0032, "Red-M (Communications) Ltd"),
+    (0x0033, "Commil Ltd"),
+    (0x0034, "Computer Access Technology Corporation (CATC)"),
+    (0x0035, "Eclipse (HQ Espana) S.L."),
+    (0x0036, "Renesas Electronics Corporation"),
+    (0x0037, "Mobilian Corporation"),
+    (0x0038, "Syntronix Corporation"),
+    (0x0039, "Integrated System Solution Corp."),
+    (0x003a, "Panasonic Corporation (formerly Matsushita Electric Industrial Co., Ltd.)"),
+    (0x003b, "Gennum Corporation"),
+    (0x003c, "BlackBerry Limited        (formerly Research In Motion)"),
+    (0x003d, "IPextreme, Inc."),
+    (0x003e, "Systems and Chips, Inc"),
+    (0x003f, "Bluetooth SIG, Inc"),
+    (0x0040, "Seiko Epson Corporation"),
+    (0x0041, "Integrated Silicon Solution Taiwan, Inc."),
+    (0x0042, "CONWISE Technology Corporation Ltd"),
+    (0x0043, "PARROT AUTOMOTIVE SAS"),
+    (0x0044, "Socket Mobile"),
+    (0x0045, "Atheros Communications, Inc."),
+    (0x0046, "MediaTek, Inc."),
+    (0x0047, "Bluegiga"),
+    (0x0048, "Marvell Technology Group Ltd."),
+    (0x0049, "3DSP Corporation"),
+    (0x004a, "Accel Semiconductor Ltd."),
+    (0x004b, "Continental Automotive Systems"),
+    (0x004c, "Apple, Inc."),
+    (0x004d, "Staccato Communications, Inc."),
+    (0x004e, "Avago Technologies"),
+    (0x004f, "APT Ltd."),
+    (0x0050, "SiRF Technology, Inc."),
+    (0x0051, "Tzero Technologies, Inc."),
+    (0x0052, "J&M Corporation"),
+    (0x0053, "Free2move AB"),
+    (0x0054, "3DiJoy Corporation"),
+    (0x0055, "Plantronics, Inc."),
+    (0x0056, "Sony Ericsson Mobile Communications"),
+    (0x0057, "Harman International Industries, Inc."),
+    (0x0058, "Vizio, Inc."),
+    (0x0059, "Nordic Semiconductor ASA"),
+    (0x005a, "EM Microelectronic-Marin SA"),
+    (0x005b, "Ralink Technology Corporation"),
+    (0x005c, "Belkin International, Inc."),
+    (0x005d, "Realtek Semiconductor Corporation"),
+    (0x005e, "Stonestreet One, LLC"),
+    (0x005f, "Wicentric, Inc."),
+    (0x0060, "RivieraWaves S.A.S"),
+    (0x0061, "RDA Microelectronics"),
+    (0x0062, "Gibson Guitars"),
+    (0x0063, "MiCommand Inc."),
+    (0x0064, "Band XI International, LLC"),
+    (0x0065, "HP, Inc."),
+    (0x0066, "9Solutions Oy"),
+    (0x0067, "GN Netcom A/S"),
+    (0x0068, "General Motors"),
+    (0x0069, "A&D Engineering, Inc."),
+    (0x006a, "MindTree Ltd."),
+    (0x006b, "Polar Electro OY"),
+    (0x006c, "Beautiful Enterprise Co., Ltd."),
+    (0x006d, "BriarTek, Inc"),
+    (0x006e, "Summit Data Communications, Inc."),
+    (0x006f, "Sound ID"),
+    (0x0070, "Monster, LLC"),
+    (0x0071, "connectBlue AB"),
+    (0x0072, "ShangHai Super Smart Electronics Co. Ltd."),
+    (0x0073, "Group Sense Ltd."),
+    (0x0074, "Zomm, LLC"),
+    (0x0075, "Samsung Electronics Co. Ltd."),
+    (0x0076, "Creative Technology Ltd."),
+    (0x0077, "Laird Technologies"),
+    (0x0078, "Nike, Inc."),
+    (0x0079, "lesswire AG"),
+    (0x007a, "MStar Semiconductor, Inc."),
+    (0x007b, "Hanlynn Technologies"),
+    (0x007c, "A & R Cambridge"),
+    (0x007d, "Seers Technology Co., Ltd."),
+    (0x007e, "Sports Tracking Technologies Ltd."),
+    (0x007f, "Autonet Mobile"),
+    (0x0080, "DeLorme Publishing Company, Inc."),
+    (0x0081, "WuXi Vimicro"),
+    (0x0082, "Sennheiser Communications A/S"),
+    (0x0083, "TimeKeeping Systems, Inc."),
+    (0x0084, "Ludus Helsinki Ltd."),
+    (0x0085, "BlueRadios, Inc."),
+    (0x0086, "Equinux AG"),
+    (0x0087, "Garmin International, Inc."),
+    (0x0088, "Ecotest"),
+    (0x0089, "GN ReSound A/S"),
+    (0x008a, "Jawbone"),
+    (0x008b, "Topcon Positioning Systems, LLC"),
+    (0x008c, "Gimbal Inc. (formerly Qualcomm Labs, Inc. and Qualcomm Retail Solutions, Inc.)"),
+    (0x008d, "Zscan Software"),
+    (0x008e, "Quintic Corp"),
+    (0x008f, "Telit Wireless Solutions GmbH (formerly Stollmann E+V GmbH)"),
+    (0x0090, "Funai Electric Co., Ltd."),
+    (0x0091, "Advanced PANMOBIL systems GmbH & Co. KG"),
+    (0x0092, "ThinkOptics, Inc."),
+    (0x0093, "Universal Electronics, Inc."),
+    (0x0094, "Airoha Technology Corp."),
+    (0x0095, "NEC Lighting, Ltd."),
+    (0x0096, "ODM Technology, Inc."),
+    (0x0097, "ConnecteDevice Ltd."),
+    (0x0098, "zero1.tv GmbH"),
+    (0x0099, "i.Tech Dynamic Global Distribution Ltd."),
+    (0x009a, "Alpwise"),
+    (0x009b, "Jiangsu Toppower Automotive Electronics Co., Ltd."),
+    (0x009c, "Colorfy, Inc."),
+    (0x009d, "Geoforce Inc."),
+    (0x009e, "Bose Corporation"),
+    (0x009f, "Suunto Oy"),
+    (0x00a0, "Kensington Computer Products Group"),
+    (0x00a1, "SR-Medizinelektronik"),
+    (0x00a2, "Vertu Corporation Limited"),
+    (0x00a3, "Meta Watch Ltd."),
+    (0x00a4, "LINAK A/S"),
+    (0x00a5, "OTL Dynamics LLC"),
+    (0x00a6, "Panda Ocean Inc."),
+    (0x00a7, "Visteon Corporation"),
+    (0x00a8, "ARP Devices Limited"),
+    (0x00a9, "MARELLI EUROPE S.P.A. (formerly Magneti Marelli S.p.A.)"),
+    (0x00aa, "CAEN RFID srl"),
+    (0x00ab, "Ingenieur-Systemgruppe Zahn GmbH"),
+    (0x00ac, "Green Throttle Games"),
+    (0x00ad, "Peter Systemtechnik GmbH"),
+    (0x00ae, "Omegawave Oy"),
+    (0x00af, "Cinetix"),
+    (0x00b0, "Passif Semiconductor Corp"),
+    (0x00b1, "Saris Cycling Group, Inc"),
+    (0x00b2, "Bekey A/S"),
+    (0x00b3, "Clarinox Technologies Pty. Ltd."),
+    (0x00b4, "BDE Technology Co., Ltd."),
+    (0x00b5, "Swirl Networks"),
+    (0x00b6, "Meso international"),
+    (0x00b7, "TreLab Ltd"),
+    (0x00b8, "Qualcomm Innovation Center, Inc. (QuIC)"),
+    (0x00b9, "Johnson Controls, Inc."),
+    (0x00ba, "Starkey Laboratories Inc."),
+    (0x00bb, "S-Power Electronics Limited"),
+    (0x00bc, "Ace Sensor Inc"),
+    (0x00bd, "Aplix Corporation"),
+    (0x00be, "AAMP of America"),
+    (0x00bf, "Stalmart Technology Limited"),
+    (0x00c0, "AMICCOM Electronics Corporation"),
+    (0x00c1, "Shenzhen Excelsecu Data Technology Co.,Ltd"),
+    (0x00c2, "Geneq Inc."),
+    (0x00c3, "adidas AG"),
+    (0x00c4, "LG Electronics"),
+    (0x00c5, "Onset Computer Corporation"),
+    (0x00c6, "Selfly BV"),
+    (0x00c7, "Quuppa Oy."),
+    (0x00c8, "GeLo Inc"),
+    (0x00c9, "Evluma"),
+    (0x00ca, "MC10"),
+    (0x00cb, "Binauric SE"),
+    (0x00cc, "Beats Electronics"),
+    (0x00cd, "Microchip Technology Inc."),
+    (0x00ce, "Elgato Systems GmbH"),
+    (0x00cf, "ARCHOS SA"),
+    (0x00d0, "Dexcom, Inc."),
+    (0x00d1, "Polar Electro Europe B.V."),
+    (0x00d2, "Dialog Semiconductor B.V."),
+    (0x00d3, "Taixingbang Technology (HK) Co,. LTD."),
+    (0x00d4, "Kawantech"),
+    (0x00d5, "Austco Communication Systems"),
+    (0x00d6, "Timex Group USA, Inc."),
+    (0x00d7, "Qualcomm Technologies, Inc."),
+    (0x00d8, "Qualcomm Connected Experiences, Inc."),
+    (0x00d9, "Voyetra Turtle Beach"),
+    (0x00da, "txtr GmbH"),
+    (0x00db, "Biosentronics"),
+    (0x00dc, "Procter & Gamble"),
+    (0x00dd, "Hosiden Corporation"),
+    (0x00de, "Muzik LLC"),
+    (0x00df, "Misfit Wearables Corp"),
+    (0x00e0, "Google"),
+    (0x00e1, "Danlers Ltd"),
+    (0x00e2, "Semilink Inc"),
+    (0x00e3, "inMusic Brands, Inc"),
+    (0x00e4, "Laird Connectivity, Inc. formerly L.S. Research Inc."),
+    (0x00e5, "Eden Software Consultants Ltd."),
+    (0x00e6, "Freshtemp"),
+    (0x00e7, "KS Technologies"),
+    (0x00e8, "ACTS Technologies"),
+    (0x00e9, "Vtrack Systems"),
+    (0x00ea, "Nielsen-Kellerman Company"),
+    (0x00eb, "Server Technology Inc."),
+    (0x00ec, "BioResearch Associates"),
+    (0x00ed, "Jolly Logic, LLC"),
+    (0x00ee, "Above Average Outcomes, Inc."),
+    (0x00ef, "Bitsplitters GmbH"),
+    (0x00f0, "PayPal, Inc."),
+    (0x00f1, "Witron Technology Limited"),
+    (0x00f2, "Morse Project Inc."),
+    (0x00f3, "Kent Displays Inc."),
+    (0x00f4, "Nautilus Inc."),
+    (0x00f5, "Smartifier Oy"),
+    (0x00f6, "Elcometer Limited"),
+    (0x00f7, "VSN Technologies, Inc."),
+    (0x00f8, "AceUni Corp., Ltd."),
+    (0x00f9, "StickNFind"),
+    (0x00fa, "Crystal Code AB"),
+    (0x00fb, "KOUKAAM a.s."),
+    (0x00fc, "Delphi Corporation"),
+    (0x00fd, "ValenceTech Limited"),
+    (0x00fe, "Stanley Black and Decker"),
+    (0x00ff, "Typo Products, LLC"),
+    (0x0100, "TomTom International BV"),
+    (0x0101, "Fugoo, Inc."),
+    (0x0102, "Keiser Corporation"),
+    (0x0103, "Bang & Olufsen A/S"),
+    (0x0104, "PLUS Location Systems Pty Ltd"),
+    (0x0105, "Ubiquitous Computing Technology Corporation"),
+    (0x0106, "Innovative Yachtter Solutions"),
+    (0x0107, "William Demant Holding A/S"),
+    (0x0108, "Chicony Electronics Co., Ltd."),
+    (0x0109, "Atus BV"),
+    (0x010a, "Codegate Ltd"),
+    (0x010b, "ERi, Inc"),
+    (0x010c, "Transducers Direct, LLC"),
+    (0x010d, "DENSO TEN LIMITED (formerly Fujitsu Ten LImited)"),
+    (0x010e, "Audi AG"),
+    (0x010f, "HiSilicon Technologies CO., LIMITED"),
+    (0x0110, "Nippon Seiki Co., Ltd."),
+    (0x0111, "Steelseries ApS"),
+    (0x0112, "Visybl Inc."),
+    (0x0113, "Openbrain Technologies, Co., Ltd."),
+    (0x0114, "Xensr"),
+    (0x0115, "e.solutions"),
+    (0x0116, "10AK Technologies"),
+    (0x0117, "Wimoto Technologies Inc"),
+    (0x0118, "Radius Networks, Inc."),
+    (0x0119, "Wize Technology Co., Ltd."),
+    (0x011a, "Qualcomm Labs, Inc."),
+    (0x011b, "Hewlett Packard Enterprise"),
+    (0x011c, "Baidu"),
+    (0x011d, "Arendi AG"),
+    (0x011e, "Skoda Auto a.s."),
+    (0x011f, "Volkswagen AG"),
+    (0x0120, "Porsche AG"),
+    (0x0121, "Sino Wealth Electronic Ltd."),
+    (0x0122, "AirTurn, Inc."),
+    (0x0123, "Kinsa, Inc"),
+    (0x0124, "HID Global"),
+    (0x0125, "SEAT es"),
+    (0x0126, "Promethean Ltd."),
+    (0x0127, "Salutica Allied Solutions"),
+    (0x0128, "GPSI Group Pty Ltd"),
+    (0x0129, "Nimble Devices Oy"),
+    (0x012a, "Changzhou Yongse Infotech        Co., Ltd."),
+    (0x012b, "SportIQ"),
+    (0x012c, "TEMEC Instruments B.V."),
+    (0x012d, "Sony Corporation"),
+    (0x012e, "ASSA ABLOY"),
+    (0x012f, "Clarion Co. Inc."),
+    (0x0130, "Warehouse Innovations"),
+    (0x0131, "Cypress Semiconductor"),
+    (0x0132, "MADS Inc"),
+    (0x0133, "Blue Maestro Limited"),
+    (0x0134, "Resolution Products, Ltd."),
+    (0x0135, "Aireware LLC"),
+    (0x0136, "Silvair, Inc."),
+    (0x0137, "Prestigio Plaza Ltd."),
+    (0x0138, "NTEO Inc."),
+    (0x0139, "Focus Systems Corporation"),
+    (0x013a, "Tencent Holdings Ltd."),
+    (0x013b, "Allegion"),
+    (0x013c, "Murata Manufacturing Co., Ltd."),
+    (0x013d, "WirelessWERX"),
+    (0x013e, "Nod, Inc."),
+    (0x013f, "B&B Manufacturing Company"),
+    (0x0140, "Alpine Electronics (China) Co., Ltd"),
+    (0x0141, "FedEx Services"),
+    (0x0142, "Grape Systems Inc."),
+    (0x0143, "Bkon Connect"),
+    (0x0144, "Lintech GmbH"),
+    (0x0145, "Novatel Wireless"),
+    (0x0146, "Ciright"),
+    (0x0147, "Mighty Cast, Inc."),
+    (0x0148, "Ambimat Electronics"),
+    (0x0149, "Perytons Ltd."),
+    (0x014a, "Tivoli Audio, LLC"),
+    (0x014b, "Master Lock"),
+    (0x014c, "Mesh-Net Ltd"),
+    (0x014d, "HUIZHOU DESAY SV AUTOMOTIVE CO., LTD."),
+    (0x014e, "Tangerine, Inc."),
+    (0x014f, "B&W Group Ltd."),
+    (0x0150, "Pioneer Corporation"),
+    (0x0151, "OnBeep"),
+    (0x0152, "Vernier Software & Technology"),
+    (0x0153, "ROL Ergo"),
+    (0x0154, "Pebble Technology"),
+    (0x0155, "NETATMO"),
+    (0x0156, "Accumulate AB"),
+    (0x0157, "Anhui Huami Information Technology Co., Ltd."),
+    (0x0158, "Inmite s.r.o."),
+    (0x0159, "ChefSteps, Inc."),
+    (0x015a, "micas AG"),
+    (0x015b, "Biomedical Research Ltd."),
+    (0x015c, "Pitius Tec S.L."),
+    (0x015d, "Estimote, Inc."),
+    (0x015e, "Unikey Technologies, Inc."),
+    (0x015f, "Timer Cap Co."),
+    (0x0160, "Awox formerly AwoX"),
+    (0x0161, "yikes"),
+    (0x0162, "MADSGlobalNZ Ltd."),
+    (0x0163, "PCH International"),
+    (0x0164, "Qingdao Yeelink Information Technology Co., Ltd."),
+    (0x0165, "Milwaukee Tool (Formally Milwaukee Electric Tools)"),
+    (0x0166, "MISHIK Pte Ltd"),
+    (0x0167, "Ascensia Diabetes Care US Inc."),
+    (0x0168, "Spicebox LLC"),
+    (0x0169, "emberlight"),
+    (0x016a, "Cooper-Atkins Corporation"),
+    (0x016b, "Qblinks"),
+    (0x016c, "MYSPHERA"),
+    (0x016d, "LifeScan Inc"),
+    (0x016e, "Volantic AB"),
+    (0x016f, "Podo Labs, Inc"),
+    (0x0170, "Roche Diabetes Care AG"),
+    (0x0171, "Amazon.com Services, LLC (formerly Amazon Fulfillment Service)"),
+    (0x0172, "Connovate Technology Private Limited"),
+    (0x0173, "Kocomojo, LLC"),
+    (0x0174, "Everykey Inc."),
+    (0x0175, "Dynamic Controls"),
+    (0x0176, "SentriLock"),
+    (0x0177, "I-SYST inc."),
+    (0x0178, "CASIO COMPUTER CO., LTD."),
+    (0x0179, "LAPIS Technology Co., Ltd. formerly LAPIS Semiconductor Co., Ltd."),
+    (0x017a, "Telemonitor, Inc."),
+    (0x017b, "taskit GmbH"),
+    (0x017c, "Daimler AG"),
+    (0x017d, "BatAndCat"),
+    (0x017e, "BluDotz Ltd"),
+    (0x017f, "XTel Wireless ApS"),
+    (0x0180, "Gigaset Communications GmbH"),
+    (0x0181, "Gecko Health Innovations, Inc."),
+    (0x0182, "HOP Ubiquitous"),
+    (0x0183, "Walt Disney"),
+    (0x0184, "Nectar"),
+    (0x0185, "bel'apps LLC"),
+    (0x0186, "CORE Lighting Ltd"),
+    (0x0187, "Seraphim Sense Ltd"),
+    (0x0188, "Unico RBC"),
+    (0x0189, "Physical Enterprises Inc."),
+    (0x018a, "Able Trend Technology Limited"),
+    (0x018b, "Konica Minolta, Inc."),
+    (0x018c, "Wilo SE"),
+    (0x018d, "Extron Design Services"),
+    (0x018e, "Fitbit, Inc."),
+    (0x018f, "Fireflies Systems"),
+    (0x0190, "Intelletto Technologies Inc."),
+    (0x0191, "FDK CORPORATION"),
+    (0x0192, "Cloudleaf, Inc"),
+    (0x0193, "Maveric Automation LLC"),
+    (0x0194, "Acoustic Stream Corporation"),
+    (0x0195, "Zuli"),
+    (0x0196, "Paxton Access Ltd"),
+    (0x0197, "WiSilica Inc."),
+    (0x0198, "VENGIT Korlatolt Felelossegu Tarsasag"),
+    (0x0199, "SALTO SYSTEMS S.L."),
+    (0x019a, "TRON Forum (formerly T-Engine Forum)"),
+    (0x019b, "CUBETECH s.r.o."),
+    (0x019c, "Cokiya Incorporated"),
+    (0x019d, "CVS Health"),
+    (0x019e, "Ceruus"),
+    (0x019f, "Strainstall Ltd"),
+    (0x01a0, "Channel Enterprises (HK) Ltd."),
+    (0x01a1, "FIAMM"),
+    (0x01a2, "GIGALANE.CO.,LTD"),
+    (0x01a3, "EROAD"),
+    (0x01a4, "Mine Safety Appliances"),
+    (0x01a5, "Icon Health and Fitness"),
+    (0x01a6, "Wille Engineering (formely as Asandoo GmbH)"),
+    (0x01a7, "ENERGOUS CORPORATION"),
+    (0x01a8, "Taobao"),
+    (0x01a9, "Canon Inc."),
+    (0x01aa, "Geophysical Technology Inc."),
+    (0x01ab, "Facebook, Inc."),
+    (0x01ac, "Trividia Health, Inc."),
+    (0x01ad, "FlightSafety International"),
+    (0x01ae, "Earlens Corporation"),
+    (0x01af, "Sunrise Micro Devices, Inc."),
+    (0x01b0, "Star Micronics Co., Ltd."),
+    (0x01b1, "Netizens Sp. z o.o."),
+    (0x01b2, "Nymi Inc."),
+    (0x01b3, "Nytec, Inc."),
+    (0x01b4, "Trineo Sp. z o.o."),
+    (0x01b5, "Nest Labs Inc."),
+    (0x01b6, "LM Technologies Ltd"),
+    (0x01b7, "General Electric Company"),
+    (0x01b8, "i+D3 S.L."),
+    (0x01b9, "HANA Micron"),
+    (0x01ba, "Stages Cycling LLC"),
+    (0x01bb, "Cochlear Bone Anchored Solutions AB"),
+    (0x01bc, "SenionLab AB"),
+    (0x01bd, "Syszone Co., Ltd"),
+    (0x01be, "Pulsate Mobile Ltd."),
+    (0x01bf, "Hong Kong HunterSun Electronic Limited"),
+    (0x01c0, "pironex GmbH"),
+    (0x01c1, "BRADATECH Corp."),
+    (0x01c2, "Transenergooil AG"),
+    (0x01c3, "Bunch"),
+    (0x01c4, "DME Microelectronics"),
+    (0x01c5, "Bitcraze AB"),
+    (0x01c6, "HASWARE Inc."),
+    (0x01c7, "Abiogenix Inc."),
+    (0x01c8, "Poly-Control ApS"),
+    (0x01c9, "Avi-on"),
+    (0x01ca, "Laerdal Medical AS"),
+    (0x01cb, "Fetch My Pet"),
+    (0x01cc, "Sam Labs Ltd."),
+    (0x01cd, "Chengdu Synwing Technology Ltd"),
+    (0x01ce, "HOUWA SYSTEM DESIGN, k.k."),
+    (0x01cf, "BSH"),
+    (0x01d0, "Primus Inter Pares Ltd"),
+    (0x01d1, "August Home, Inc"),
+    (0x01d2, "Gill Electronics"),
+    (0x01d3, "Sky Wave Design"),
+    (0x01d4, "Newlab S.r.l."),
+    (0x01d5, "ELAD srl"),
+    (0x01d6, "G-wearables inc."),
+    (0x01d7, "Squadrone Systems Inc."),
+    (0x01d8, "Code Corporation"),
+    (0x01d9, "Savant Systems LLC"),
+    (0x01da, "Logitech International SA"),
+    (0x01db, "Innblue Consulting"),
+    (0x01dc, "iParking Ltd."),
+    (0x01dd, "Koninklijke Philips Electronics N.V."),
+    (0x01de, "Minelab Electronics Pty Limited"),
+    (0x01df, "Bison Group Ltd."),
+    (0x01e0, "Widex A/S"),
+    (0x01e1, "Jolla Ltd"),
+    (0x01e2, "Lectronix, Inc."),
+    (0x01e3, "Caterpillar Inc"),
+    (0x01e4, "Freedom Innovations"),
+    (0x01e5, "Dynamic Devices Ltd"),
+    (0x01e6, "Technology Solutions (UK) Ltd"),
+    (0x01e7, "IPS Group Inc."),
+    (0x01e8, "STIR"),
+    (0x01e9, "Sano, Inc."),
+    (0x01ea, "Advanced Application Design, Inc."),
+    (0x01eb, "AutoMap LLC"),
+    (0x01ec, "Spreadtrum Communications Shanghai Ltd"),
+    (0x01ed, "CuteCircuit LTD"),
+    (0x01ee, "Valeo Service"),
+    (0x01ef, "Fullpower Technologies, Inc."),
+    (0x01f0, "KloudNation"),
+    (0x01f1, "Zebra Technologies Corporation"),
+    (0x01f2, "Itron, Inc."),
+    (0x01f3, "The University of Tokyo"),
+    (0x01f4, "UTC Fire and Security"),
+    (0x01f5, "Cool Webthings Limited"),
+    (0x01f6, "DJO Global"),
+    (0x01f7, "Gelliner Limited"),
+    (0x01f8, "Anyka (Guangzhou) Microelectronics Technology Co, LTD"),
+    (0x01f9, "Medtronic Inc."),
+    (0x01fa, "Gozio Inc."),
+    (0x01fb, "Form Lifting, LLC"),
+    (0x01fc, "Wahoo Fitness, LLC"),
+    (0x01fd, "Kontakt Micro-Location Sp. z o.o."),
+    (0x01fe, "Radio Systems Corporation"),
+    (0x01ff, "Freescale Semiconductor, Inc."),
+    (0x0200, "Verifone Systems Pte Ltd. Taiwan Branch"),
+    (0x0201, "AR Timing"),
+    (0x0202, "Rigado LLC"),
+    (0x0203, "Kemppi Oy"),
+    (0x0204, "Tapcentive Inc."),
+    (0x0205, "Smartbotics Inc."),
+    (0x0206, "Otter Products, LLC"),
+    (0x0207, "STEMP Inc."),
+    (0x0208, "LumiGeek LLC"),
+    (0x0209, "InvisionHeart Inc."),
+    (0x020a, "Macnica Inc."),
+    (0x020b, "Jaguar Land Rover Limited"),
+    (0x020c, "CoroWare Technologies, Inc"),
+    (0x020d, "Simplo Technology Co., LTD"),
+    (0x020e, "Omron Healthcare Co., LTD"),
+    (0x020f, "Comodule GMBH"),
+    (0x0210, "ikeGPS"),
+    (0x0211, "Telink Semiconductor Co. Ltd"),
+    (0x0212, "Interplan Co., Ltd"),
+    (0x0213, "Wyler AG"),
+    (0x0214, "IK Multimedia Production srl"),
+    (0x0215, "Lukoton Experience Oy"),
+    (0x0216, "MTI Ltd"),
+    (0x0217, "Tech4home, Lda"),
+    (0x0218, "Hiotech AB"),
+    (0x0219, "DOTT Limited"),
+    (0x021a, "Blue Speck Labs, LLC"),
+    (0x021b, "Cisco Systems, Inc"),
+    (0x021c, "Mobicomm Inc"),
+    (0x021d, "Edamic"),
+    (0x021e, "Goodnet, Ltd"),
+    (0x021f, "Luster Leaf Products        Inc"),
+    (0x0220, "Manus Machina BV"),
+    (0x0221, "Mobiquity Networks Inc"),
+    (0x0222, "Praxis Dynamics"),
+    (0x0223, "Philip Morris Products S.A."),
+    (0x0224, "Comarch SA"),
+    (0x0225, "Nestlé Nespresso S.A."),
+    (0x0226, "Merlinia A/S"),
+    (0x0227, "LifeBEAM Technologies"),
+    (0x0228, "Twocanoes Labs, LLC"),
+    (0x0229, "Muoverti Limited"),
+    (0x022a, "Stamer Musikanlagen GMBH"),
+    (0x022b, "Tesla Motors"),
+    (0x022c, "Pharynks Corporation"),
+    (0x022d, "Lupine"),
+    (0x022e, "Siemens AG"),
+    (0x022f, "Huami (Shanghai) Culture Communication CO., LTD"),
+    (0x0230, "Foster Electric Company, Ltd"),
+    (0x0231, "ETA SA"),
+    (0x0232, "x-Senso Solutions Kft"),
+    (0x0233, "Shenzhen SuLong Communication Ltd"),
+    (0x0234, "FengFan (BeiJing) Technology Co, Ltd"),
+    (0x0235, "Qrio Inc"),
+    (0x0236, "Pitpatpet Ltd"),
+    (0x0237, "MSHeli s.r.l."),
+    (0x0238, "Trakm8 Ltd"),
+    (0x0239, "JIN CO, Ltd"),
+    (0x023a, "Alatech Tehnology"),
+    (0x023b, "Beijing CarePulse Electronic Technology Co, Ltd"),
+    (0x023c, "Awarepoint"),
+    (0x023d, "ViCentra B.V."),
+    (0x023e, "Raven Industries"),
+    (0x023f, "WaveWare Technologies Inc."),
+    (0x0240, "Argenox Technologies"),
+    (0x0241, "Bragi GmbH"),
+    (0x0242, "16Lab Inc"),
+    (0x0243, "Masimo Corp"),
+    (0x0244, "Iotera Inc"),
+    (0x0245, "Endress+Hauser"),
+    (0x0246, "ACKme Networks, Inc."),
+    (0x0247, "FiftyThree Inc."),
+    (0x0248, "Parker Hannifin Corp"),
+    (0x0249, "Transcranial Ltd"),
+    (0x024a, "Uwatec AG"),
+    (0x024b, "Orlan LLC"),
+    (0x024c, "Blue Clover Devices"),
+    (0x024d, "M-Way Solutions GmbH"),
+    (0x024e, "Microtronics Engineering GmbH"),
+    (0x024f, "Schneider Schreibgeräte GmbH"),
+    (0x0250, "Sapphire Circuits LLC"),
+    (0x0251, "Lumo Bodytech Inc."),
+    (0x0252, "UKC Technosolution"),
+    (0x0253, "Xicato Inc."),
+    (0x0254, "Playbrush"),
+    (0x0255, "Dai Nippon Printing Co., Ltd."),
+    (0x0256, "G24 Power Limited"),
+    (0x0257, "AdBabble Local Commerce Inc."),
+    (0x0258, "Devialet SA"),
+    (0x0259, "ALTYOR"),
+    (0x025a, "University of Applied Sciences Valais/Haute Ecole Valaisanne"),
+    (0x025b, "Five Interactive, LLC dba Zendo"),
+    (0x025c, "NetEase（Hangzhou）Network co.Ltd."),
+    (0x025d, "Lexmark International Inc."),
+    (0x025e, "Fluke Corporation"),
+    (0x025f, "Yardarm Technologies"),
+    (0x0260, "SensaRx"),
+    (0x0261, "SECVRE GmbH"),
+    (0x0262, "Glacial Ridge Technologies"),
+    (0x0263, "Identiv, Inc."),
+    (0x0264, "DDS, Inc."),
+    (0x0265, "SMK Corporation"),
+    (0x0266, "Schawbel Technologies LLC"),
+    (0x0267, "XMI Systems SA"),
+    (0x0268, "Cerevo"),
+    (0x0269, "Torrox GmbH & Co KG"),
+    (0x026a, "Gemalto"),
+    (0x026b, "DEKA Research & Development Corp."),
+    (0x026c, "Domster Tadeusz Szydlowski"),
+    (0x026d, "Technogym SPA"),
+    (0x026e, "FLEURBAEY BVBA"),
+    (0x026f, "Aptcode Solutions"),
+    (0x0270, "LSI ADL Technology"),
+    (0x0271, "Animas Corp"),
+    (0x0272, "Alps Alpine Co., Ltd."),
+    (0x0273, "OCEASOFT"),
+    (0x0274, "Motsai Research"),
+    (0x0275, "Geotab"),
+    (0x0276, "E.G.O. Elektro-Geraetebau GmbH"),
+    (0x0277, "bewhere inc"),
+    (0x0278, "Johnson Outdoors Inc"),
+    (0x0279, "steute Schaltgerate GmbH & Co. KG"),
+    (0x027a, "Ekomini inc."),
+    (0x027b, "DEFA AS"),
+    (0x027c, "Aseptika Ltd"),
+    (0x027d, "HUAWEI Technologies Co., Ltd."),
+    (0x027e, "HabitAware, LLC"),
+    (0x027f, "ruwido austria gmbh"),
+    (0x0280, "ITEC corporation"),
+    (0x0281, "StoneL"),
+    (0x0282, "Sonova AG"),
+    (0x0283, "Maven Machines, Inc."),
+    (0x0284, "Synapse Electronics"),
+    (0x0285, "Standard Innovation Inc."),
+    (0x0286, "RF Code, Inc."),
+    (0x0287, "Wally Ventures S.L."),
+    (0x0288, "Willowbank Electronics Ltd"),
+    (0x0289, "SK Telecom"),
+    (0x028a, "Jetro AS"),
+    (0x028b, "Code Gears LTD"),
+    (0x028c, "NANOLINK APS"),
+    (0x028d, "IF, LLC"),
+    (0x028e, "RF Digital Corp"),
+    (0x028f, "Church & Dwight Co., Inc"),
+    (0x0290, "Multibit Oy"),
+    (0x0291, "CliniCloud Inc"),
+    (0x0292, "SwiftSensors"),
+    (0x0293, "Blue Bite"),
+    (0x0294, "ELIAS GmbH"),
+    (0x0295, "Sivantos GmbH"),
+    (0x0296, "Petzl"),
+    (0x0297, "storm power ltd"),
+    (0x0298, "EISST Ltd"),
+    (0x0299, "Inexess Technology Simma KG"),
+    (0x029a, "Currant, Inc."),
+    (0x029b, "C2 Development, Inc."),
+    (0x029c, "Blue Sky Scientific, LLC"),
+    (0x029d, "ALOTTAZS LABS, LLC"),
+    (0x029e, "Kupson spol. s r.o."),
+    (0x029f, "Areus Engineering GmbH"),
+    (0x02a0, "Impossible Camera GmbH"),
+    (0x02a1, "InventureTrack Systems"),
+    (0x02a2, "LockedUp"),
+    (0x02a3, "Itude"),
+    (0x02a4, "Pacific Lock Company"),
+    (0x02a5, "Tendyron Corporation ( 天地融科技股份有限公司 )"),
+    (0x02a6, "Robert Bosch GmbH"),
+    (0x02a7, "Illuxtron international B.V."),
+    (0x02a8, "miSport Ltd."),
+    (0x02a9, "Chargelib"),
+    (0x02aa, "Doppler Lab"),
+    (0x02ab, "BBPOS Limited"),
+    (0x02ac, "RTB Elektronik GmbH & Co. KG"),
+    (0x02ad, "Rx Networks, Inc."),
+    (0x02ae, "WeatherFlow, Inc."),
+    (0x02af, "Technicolor USA Inc."),
+    (0x02b0, "Bestechnic(Shanghai),Ltd"),
+    (0x02b1, "Raden Inc"),
+    (0x02b2, "JouZen Oy"),
+    (0x02b3, "CLABER S.P.A."),
+    (0x02b4, "Hyginex, Inc."),
+    (0x02b5, "HANSHIN ELECTRIC RAILWAY CO.,LTD."),
+    (0x02b6, "Schneider Electric"),
+    (0x02b7, "Oort Technologies LLC"),
+    (0x02b8, "Chrono Therapeutics"),
+    (0x02b9, "Rinnai Corporation"),
+    (0x02ba, "Swissprime Technologies AG"),
+    (0x02bb, "Koha.,Co.Ltd"),
+    (0x02bc, "Genevac Ltd"),
+    (0x02bd, "Chemtronics"),
+    (0x02be, "Seguro Technology Sp. z o.o."),
+    (0x02bf, "Redbird Flight Simulations"),
+    (0x02c0, "Dash Robotics"),
+    (0x02c1, "LINE Corporation"),
+    (0x02c2, "Guillemot Corporation"),
+    (0x02c3, "Techtronic Power Tools Technology Limited"),
+    (0x02c4, "Wilson Sporting Goods"),
+    (0x02c5, "Lenovo (Singapore) Pte Ltd. ( 联想（新加坡） )"),
+    (0x02c6, "Ayatan Sensors"),
+    (0x02c7, "Electronics Tomorrow Limited"),
+    (0x02c8, "OneSpan"),
+    (0x02c9, "PayRange Inc."),
+    (0x02ca, "ABOV Semiconductor"),
+    (0x02cb, "AINA-Wireless Inc."),
+    (0x02cc, "Eijkelkamp Soil & Water"),
+    (0x02cd, "BMA ergonomics b.v."),
+    (0x02ce, "Teva Branded Pharmaceutical Products R&D, Inc."),
+    (0x02cf, "Anima"),
+    (0x02d0, "3M"),
+    (0x02d1, "Empatica Srl"),
+    (0x02d2, "Afero, Inc."),
+    (0x02d3, "Powercast Corporation"),
+    (0x02d4, "Secuyou ApS"),
+    (0x02d5, "OMRON Corporation"),
+    (0x02d6, "Send Solutions"),
+    (0x02d7, "NIPPON SYSTEMWARE CO.,LTD."),
+    (0x02d8, "Neosfar"),
+    (0x02d9, "Fliegl Agrartechnik GmbH"),
+    (0x02da, "Gilvader"),
+    (0x02db, "Digi International Inc (R)"),
+    (0x02dc, "DeWalch Technologies, Inc."),
+    (0x02dd, "Flint Rehabilitation Devices, LLC"),
+    (0x02de, "Samsung SDS Co., Ltd."),
+    (0x02df, "Blur Product Development"),
+    (0x02e0, "University of Michigan"),
+    (0x02e1, "Victron Energy BV"),
+    (0x02e2, "NTT docomo"),
+    (0x02e3, "Carmanah Technologies Corp."),
+    (0x02e4, "Bytestorm Ltd."),
+    (0x02e5, "Espressif Incorporated ( 乐鑫信息科技(上海)有限公司 )"),
+    (0x02e6, "Unwire"),
+    (0x02e7, "Connected Yard, Inc."),
+    (0x02e8, "American Music Environments"),
+    (0x02e9, "Sensogram Technologies, Inc."),
+    (0x02ea, "Fujitsu Limited"),
+    (0x02eb, "Ardic Technology"),
+    (0x02ec, "Delta Systems, Inc"),
+    (0x02ed, "HTC Corporation"),
+    (0x02ee, "Citizen Holdings Co., Ltd."),
+    (0x02ef, "SMART-INNOVATION.inc"),
+    (0x02f0, "Blackrat Software"),
+    (0x02f1, "The Idea Cave, LLC"),
+    (0x02f2, "GoPro, Inc."),
+    (0x02f3, "AuthAir, Inc"),
+    (0x02f4, "Vensi, Inc."),
+    (0x02f5, "Indagem Tech LLC"),
+    (0x02f6, "Intemo Technologies"),
+    (0x02f7, "DreamVisions co., Ltd."),
+    (0x02f8, "Runteq Oy Ltd"),
+    (0x02f9, "IMAGINATION TECHNOLOGIES LTD"),
+    (0x02fa, "CoSTAR TEchnologies"),
+    (0x02fb, "Clarius Mobile Health Corp."),
+    (0x02fc, "Shanghai Frequen Microelectronics Co., Ltd."),
+    (0x02fd, "Uwanna, Inc."),
+    (0x02fe, "Lierda Science & Technology Group Co., Ltd."),
+    (0x02ff, "Silicon Laboratories"),
+    (0x0300, "World Moto Inc."),
+    (0x0301, "Giatec Scientific Inc."),
+    (0x0302, "Loop Devices, Inc"),
+    (0x0303, "IACA electronique"),
+    (0x0304, "Proxy Technologies, Inc."),
+    (0x0305, "Swipp ApS"),
+    (0x0306, "Life Laboratory Inc."),
+    (0x0307, "FUJI INDUSTRIAL CO.,LTD."),
+    (0x0308, "Surefire, LLC"),
+    (0x0309, "Dolby Labs"),
+    (0x030a, "Ellisys"),
+    (0x030b, "Magnitude Lighting Converters"),
+    (0x030c, "Hilti AG"),
+    (0x030d, "Devdata S.r.l."),
+    (0x030e, "Deviceworx"),
+    (0x030f, "Shortcut Labs"),
+    (0x0310, "SGL Italia S.r.l."),
+    (0x0311, "PEEQ DATA"),
+    (0x0312, "Ducere Technologies Pvt Ltd"),
+    (0x0313, "DiveNav, Inc."),
+    (0x0314, "RIIG AI Sp. z o.o."),
+    (0x0315, "Thermo Fisher Scientific"),
+    (0x0316, "AG Measurematics Pvt. Ltd."),
+    (0x0317, "CHUO Electronics CO., LTD."),
+    (0x0318, "Aspenta International"),
+    (0x0319, "Eugster Frismag AG"),
+    (0x031a, "Wurth Elektronik eiSos GmbH & Co. KG ( formerly Amber wireless GmbH)"),
+    (0x031b, "HQ Inc"),
+    (0x031c, "Lab Sensor Solutions"),
+    (0x031d, "Enterlab ApS"),
+    (0x031e, "Eyefi, Inc."),
+    (0x031f, "MetaSystem S.p.A."),
+    (0x0320, "SONO ELECTRONICS. CO., LTD"),
+    (0x0321, "Jewelbots"),
+    (0x0322, "Compumedics Limited"),
+    (0x0323, "Rotor Bike Components"),
+    (0x0324, "Astro, Inc."),
+    (0x0325, "Amotus Solutions"),
+    (0x0326, "Healthwear Technologies (Changzhou)Ltd"),
+    (0x0327, "Essex Electronics"),
+    (0x0328, "Grundfos A/S"),
+    (0x0329, "Eargo, Inc."),
+    (0x032a, "Electronic Design Lab"),
+    (0x032b, "ESYLUX"),
+    (0x032c, "NIPPON SMT.CO.,Ltd"),
+    (0x032d, "BM innovations GmbH"),
+    (0x032e, "indoormap"),
+    (0x032f, "OttoQ Inc"),
+    (0x0330, "North Pole Engineering"),
+    (0x0331, "3flares Technologies Inc."),
+    (0x0332, "Electrocompaniet A.S."),
+    (0x0333, "Mul-T-Lock"),
+    (0x0334, "Corentium AS"),
+    (0x0335, "Enlighted Inc"),
+    (0x0336, "GISTIC"),
+    (0x0337, "AJP2 Holdings, LLC"),
+    (0x0338, "COBI GmbH"),
+    (0x0339, "Blue Sky Scientific, LLC"),
+    (0x033a, "Appception, Inc."),
+    (0x033b, "Courtney Thorne Limited"),
+    (0x033c, "Virtuosys"),
+    (0x033d, "TPV Technology Limited"),
+    (0x033e, "Monitra SA"),
+    (0x033f, "Automation Components, Inc."),
+    (0x0340, "Letsense s.r.l."),
+    (0x0341, "Etesian Technologies LLC"),
+    (0x0342, "GERTEC BRASIL LTDA."),
+    (0x0343, "Drekker Development Pty. Ltd."),
+    (0x0344, "Whirl Inc"),
+    (0x0345, "Locus Positioning"),
+    (0x0346, "Acuity Brands Lighting, Inc"),
+    (0x0347, "Prevent Biometrics"),
+    (0x0348, "Arioneo"),
+    (0x0349, "VersaMe"),
+    (0x034a, "Vaddio"),
+    (0x034b, "Libratone A/S"),
+    (0x034c, "HM Electronics, Inc."),
+    (0x034d, "TASER International, Inc."),
+    (0x034e, "SafeTrust Inc."),
+    (0x034f, "Heartland Payment Systems"),
+    (0x0350, "Bitstrata Systems Inc."),
+    (0x0351, "Pieps GmbH"),
+    (0x0352, "iRiding(Xiamen)Technology Co.,Ltd."),
+    (0x0353, "Alpha Audiotronics, Inc."),
+    (0x0354, "TOPPAN FORMS CO.,LTD."),
+    (0x0355, "Sigma Designs, Inc."),
+    (0x0356, "Spectrum Brands, Inc."),
+    (0x0357, "Polymap Wireless"),
+    (0x0358, "MagniWare Ltd."),
+    (0x0359, "Novotec Medical GmbH"),
+    (0x035a, "Medicom Innovation Partner a/s"),
+    (0x035b, "Matrix Inc."),
+    (0x035c, "Eaton Corporation"),
+    (0x035d, "KYS"),
+    (0x035e, "Naya Health, Inc."),
+    (0x035f, "Acromag"),
+    (0x0360, "Insulet Corporation"),
+    (0x0361, "Wellinks Inc."),
+    (0x0362, "ON Semiconductor"),
+    (0x0363, "FREELAP SA"),
+    (0x0364, "Favero Electronics Srl"),
+    (0x0365, "BioMech Sensor LLC"),
+    (0x0366, "BOLTT Sports technologies Private limited"),
+    (0x0367, "Saphe International"),
+    (0x0368, "Metormote AB"),
+    (0x0369, "littleBits"),
+    (0x036a, "SetPoint Medical"),
+    (0x036b, "BRControls Products BV"),
+    (0x036c, "Zipcar"),
+    (0x036d, "AirBolt Pty Ltd"),
+    (0x036e, "KeepTruckin Inc"),
+    (0x036f, "Motiv, Inc."),
+    (0x0370, "Wazombi Labs OÜ"),
+    (0x0371, "ORBCOMM"),
+    (0x0372, "Nixie Labs, Inc."),
+    (0x0373, "AppNearMe Ltd"),
+    (0x0374, "Holman Industries"),
+    (0x0375, "Expain AS"),
+    (0x0376, "Electronic Temperature Instruments Ltd"),
+    (0x0377, "Plejd AB"),
+    (0x0378, "Propeller Health"),
+    (0x0379, "Shenzhen iMCO Electronic Technology Co.,Ltd"),
+    (0x037a, "Algoria"),
+    (0x037b, "Apption Labs Inc."),
+    (0x037c, "Cronologics Corporation"),
+    (0x037d, "MICRODIA Ltd."),
+    (0x037e, "lulabytes S.L."),
+    (0x037f, "Société des Produits Nestlé S.A. (formerly Nestec S.A.)"),
+    (0x0380, "LLC \"MEGA-F service\""),
+    (0x0381, "Sharp Corporation"),
+    (0x0382, "Precision Outcomes Ltd"),
+    (0x0383, "Kronos Incorporated"),
+    (0x0384, "OCOSMOS Co., Ltd."),
+    (0x0385, "Embedded Electronic Solutions Ltd. dba e2Solutions"),
+    (0x0386, "Aterica Inc."),
+    (0x0387, "BluStor PMC, Inc."),
+    (0x0388, "Kapsch TrafficCom AB"),
+    (0x0389, "ActiveBlu Corporation"),
+    (0x038a, "Kohler Mira Limited"),
+    (0x038b, "Noke"),
+    (0x038c, "Appion Inc."),
+    (0x038d, "Resmed Ltd"),
+    (0x038e, "Crownstone B.V."),
+    (0x038f, "Xiaomi Inc."),
+    (0x0390, "INFOTECH s.r.o."),
+    (0x0391, "Thingsquare AB"),
+    (0x0392, "T&D"),
+    (0x0393, "LAVAZZA S.p.A."),
+    (0x0394, "Netclearance Systems, Inc."),
+    (0x0395, "SDATAWAY"),
+    (0x0396, "BLOKS GmbH"),
+    (0x0397, "LEGO System A/S"),
+    (0x0398, "Thetatronics Ltd"),
+    (0x0399, "Nikon Corporation"),
+    (0x039a, "NeST"),
+    (0x039b, "South Silicon Valley Microelectronics"),
+    (0x039c, "ALE International"),
+    (0x039d, "CareView Communications, Inc."),
+    (0x039e, "SchoolBoard Limited"),
+    (0x039f, "Molex Corporation"),
+    (0x03a0, "IVT Wireless Limited"),
+    (0x03a1, "Alpine Labs LLC"),
+    (0x03a2, "Candura Instruments"),
+    (0x03a3, "SmartMovt Technology Co., Ltd"),
+    (0x03a4, "Token Zero Ltd"),
+    (0x03a5, "ACE CAD Enterprise Co., Ltd. (ACECAD)"),
+    (0x03a6, "Medela, Inc"),
+    (0x03a7, "AeroScout"),
+    (0x03a8, "Esrille Inc."),
+    (0x03a9, "THINKERLY SRL"),
+    (0x03aa, "Exon Sp. z o.o."),
+    (0x03ab, "Meizu Technology Co., Ltd."),
+    (0x03ac, "Smablo LTD"),
+    (0x03ad, "XiQ"),
+    (0x03ae, "Allswell Inc."),
+    (0x03af, "Comm-N-Sense Corp DBA Verigo"),
+    (0x03b0, "VIBRADORM GmbH"),
+    (0x03b1, "Otodata Wireless Network Inc."),
+    (0x03b2, "Propagation Systems Limited"),
+    (0x03b3, "Midwest Instruments & Controls"),
+    (0x03b4, "Alpha Nodus, inc."),
+    (0x03b5, "petPOMM, Inc"),
+    (0x03b6, "Mattel"),
+    (0x03b7, "Airbly Inc."),
+    (0x03b8, "A-Safe Limited"),
+    (0x03b9, "FREDERIQUE CONSTANT SA"),
+    (0x03ba, "Maxscend Microelectronics Company Limited"),
+    (0x03bb, "Abbott"),
+    (0x03bc, "ASB Bank Ltd"),
+    (0x03bd, "amadas"),
+    (0x03be, "Applied Science, Inc."),
+    (0x03bf, "iLumi Solutions Inc."),
+    (0x03c0, "Arch Systems Inc."),
+    (0x03c1, "Ember Technologies, Inc."),
+    (0x03c2, "Snapchat Inc"),
+    (0x03c3, "Casambi Technologies Oy"),
+    (0x03c4, "Pico Technology Inc."),
+    (0x03c5, "St. Jude Medical, Inc."),
+    (0x03c6, "Intricon"),
+    (0x03c7, "Structural Health Systems, Inc."),
+    (0x03c8, "Avvel International"),
+    (0x03c9, "Gallagher Group"),
+    (0x03ca, "In2things Automation Pvt. Ltd."),
+    (0x03cb, "SYSDEV Srl"),
+    (0x03cc, "Vonkil Technologies Ltd"),
+    (0x03cd, "Wynd Technologies, Inc."),
+    (0x03ce, "CONTRINEX S.A."),
+    (0x03cf, "MIRA, Inc."),
+    (0x03d0, "Watteam Ltd"),
+    (0x03d1, "Density Inc."),
+    (0x03d2, "IOT Pot India Private Limited"),
+    (0x03d3, "Sigma Connectivity AB"),
+    (0x03d4, "PEG PEREGO SPA"),
+    (0x03d5, "Wyzelink Systems Inc."),
+    (0x03d6, "Yota Devices LTD"),
+    (0x03d7, "FINSECUR"),
+    (0x03d8, "Zen-Me Labs Ltd"),
+    (0x03d9, "3IWare Co., Ltd."),
+    (0x03da, "EnOcean GmbH"),
+    (0x03db, "Instabeat, Inc"),
+    (0x03dc, "Nima Labs"),
+    (0x03dd, "Andreas Stihl AG & Co. KG"),
+    (0x03de, "Nathan Rhoades LLC"),
+    (0x03df, "Grob Technologies, LLC"),
+    (0x03e0, "Actions (Zhuhai) Technology Co., Limited"),
+    (0x03e1, "SPD Development Company Ltd"),
+    (0x03e2, "Sensoan Oy"),
+    (0x03e3, "Qualcomm Life Inc"),
+    (0x03e4, "Chip-ing AG"),
+    (0x03e5, "ffly4u"),
+    (0x03e6, "IoT Instruments Oy"),
+    (0x03e7, "TRUE Fitness Technology"),
+    (0x03e8, "Reiner Kartengeraete GmbH & Co. KG."),
+    (0x03e9, "SHENZHEN LEMONJOY TECHNOLOGY CO., LTD."),
+    (0x03ea, "Hello Inc."),
+    (0x03eb, "Evollve Inc."),
+    (0x03ec, "Jigowatts Inc."),
+    (0x03ed, "BASIC MICRO.COM,INC."),
+    (0x03ee, "CUBE TECHNOLOGIES"),
+    (0x03ef, "foolography GmbH"),
+    (0x03f0, "CLINK"),
+    (0x03f1, "Hestan Smart Cooking Inc."),
+    (0x03f2, "WindowMaster A/S"),
+    (0x03f3, "Flowscape AB"),
+    (0x03f4, "PAL Technologies Ltd"),
+    (0x03f5, "WHERE, Inc."),
+    (0x03f6, "Iton Technology Corp."),
+    (0x03f7, "Owl Labs Inc."),
+    (0x03f8, "Rockford Corp."),
+    (0x03f9, "Becon Technologies Co.,Ltd."),
+    (0x03fa, "Vyassoft Technologies Inc"),
+    (0x03fb, "Nox Medical"),
+    (0x03fc, "Kimberly-Clark"),
+    (0x03fd, "Trimble Navigation Ltd."),
+    (0x03fe, "Littelfuse"),
+    (0x03ff, "Withings"),
+    (0x0400, "i-developer IT Beratung UG"),
+    (0x0401, "Relations Inc."),
+    (0x0402, "Sears Holdings Corporation"),
+    (0x0403, "Gantner Electronic GmbH"),
+    (0x0404, "Authomate Inc"),
+    (0x0405, "Vertex International, Inc."),
+    (0x0406, "Airtago"),
+    (0x0407, "Swiss Audio SA"),
+    (0x0408, "ToGetHome Inc."),
+    (0x0409, "AXIS"),
+    (0x040a, "Openmatics"),
+    (0x040b, "Jana Care Inc."),
+    (0x040c, "Senix Corporation"),
+    (0x040d, "NorthStar Battery Company, LLC"),
+    (0x040e, "SKF (U.K.) Limited"),
+    (0x040f, "CO-AX Technology, Inc."),
+    (0x0410, "Fender Musical Instruments"),
+    (0x0411, "Luidia Inc"),
+    (0x0412, "SEFAM"),
+    (0x0413, "Wireless Cables Inc"),
+    (0x0414, "Lightning Protection International Pty Ltd"),
+    (0x0415, "Uber Technologies Inc"),
+    (0x0416, "SODA GmbH"),
+    (0x0417, "Fatigue Science"),
+    (0x0418, "Reserved"),
+    (0x0419, "Novalogy LTD"),
+    (0x041a, "Friday Labs Limited"),
+    (0x041b, "OrthoAccel Technologies"),
+    (0x041c, "WaterGuru, Inc."),
+    (0x041d, "Benning Elektrotechnik und Elektronik GmbH & Co. KG"),
+    (0x041e, "Dell Computer Corporation"),
+    (0x041f, "Kopin Corporation"),
+    (0x0420, "TecBakery GmbH"),
+    (0x0421, "Backbone Labs, Inc."),
+    (0x0422, "DELSEY SA"),
+    (0x0423, "Chargifi Limited"),
+    (0x0424, "Trainesense Ltd."),
+    (0x0425, "Unify Software and Solutions GmbH & Co. KG"),
+    (0x0426, "Husqvarna AB"),
+    (0x0427, "Focus fleet and fuel management inc"),
+    (0x0428, "SmallLoop, LLC"),
+    (0x0429, "Prolon Inc."),
+    (0x042a, "BD Medical"),
+    (0x042b, "iMicroMed Incorporated"),
+    (0x042c, "Ticto N.V."),
+    (0x042d, "Meshtech AS"),
+    (0x042e, "MemCachier Inc."),
+    (0x042f, "Danfoss A/S"),
+    (0x0430, "SnapStyk Inc."),
+    (0x0431, "Amway Corporation"),
+    (0x0432, "Silk Labs, Inc."),
+    (0x0433, "Pillsy Inc."),
+    (0x0434, "Hatch Baby, Inc."),
+    (0x0435, "Blocks Wearables Ltd."),
+    (0x0436, "Drayson Technologies (Europe) Limited"),
+    (0x0437, "eBest IOT Inc."),
+    (0x0438, "Helvar Ltd"),
+    (0x0439, "Radiance Technologies"),
+    (0x043a, "Nuheara Limited"),
+    (0x043b, "Appside co., ltd."),
+    (0x043c, "DeLaval"),
+    (0x043d, "Coiler Corporation"),
+    (0x043e, "Thermomedics, Inc."),
+    (0x043f, "Tentacle Sync GmbH"),
+    (0x0440, "Valencell, Inc."),
+    (0x0441, "iProtoXi Oy"),
+    (0x0442, "SECOM CO., LTD."),
+    (0x0443, "Tucker International LLC"),
+    (0x0444, "Metanate Limited"),
+    (0x0445, "Kobian Canada Inc."),
+    (0x0446, "NETGEAR, Inc."),
+    (0x0447, "Fabtronics Australia Pty Ltd"),
+    (0x0448, "Grand Centrix GmbH"),
+    (0x0449, "1UP USA.com llc"),
+    (0x044a, "SHIMANO INC."),
+    (0x044b, "Nain Inc."),
+    (0x044c, "LifeStyle Lock, LLC"),
+    (0x044d, "VEGA Grieshaber KG"),
+    (0x044e, "Xtrava Inc."),
+    (0x044f, "TTS Tooltechnic Systems AG & Co. KG"),
+    (0x0450, "Teenage Engineering AB"),
+    (0x0451, "Tunstall Nordic AB"),
+    (0x0452, "Svep Design Center AB"),
+    (0x0453, "Qorvo Utrecht B.V. formerly GreenPeak Technologies BV"),
+    (0x0454, "Sphinx Electronics GmbH & Co KG"),
+    (0x0455, "Atomation"),
+    (0x0456, "Nemik Consulting Inc"),
+    (0x0457, "RF INNOVATION"),
+    (0x0458, "Mini Solution Co., Ltd."),
+    (0x0459, "Lumenetix, Inc"),
+    (0x045a, "2048450 Ontario Inc"),
+    (0x045b, "SPACEEK LTD"),
+    (0x045c, "Delta T Corporation"),
+    (0x045d, "Boston Scientific Corporation"),
+    (0x045e, "Nuviz, Inc."),
+    (0x045f, "Real Time Automation, Inc."),
+    (0x0460, "Kolibree"),
+    (0x0461, "vhf elektronik GmbH"),
+    (0x0462, "Bonsai Systems GmbH"),
+    (0x0463, "Fathom Systems Inc."),
+    (0x0464, "Bellman & Symfon"),
+    (0x0465, "International Forte Group LLC"),
+    (0x0466, "CycleLabs Solutions inc."),
+    (0x0467, "Codenex Oy"),
+    (0x0468, "Kynesim Ltd"),
+    (0x0469, "Palago AB"),
+    (0x046a, "INSIGMA INC."),
+    (0x046b, "PMD Solutions"),
+    (0x046c, "Qingdao Realtime Technology Co., Ltd."),
+    (0x046d, "BEGA Gantenbrink-Leuchten KG"),
+    (0x046e, "Pambor Ltd."),
+    (0x046f, "Develco Products A/S"),
+    (0x0470, "iDesign s.r.l."),
+    (0x0471, "TiVo Corp"),
+    (0x0472, "Control-J Pty Ltd"),
+    (0x0473, "Steelcase, Inc."),
+    (0x0474, "iApartment co., ltd."),
+    (0x0475, "Icom inc."),
+    (0x0476, "Oxstren Wearable Technologies Private Limited"),
+    (0x0477, "Blue Spark Technologies"),
+    (0x0478, "FarSite Communications Limited"),
+    (0x0479, "mywerk system GmbH"),
+    (0x047a, "Sinosun Technology Co., Ltd."),
+    (0x047b, "MIYOSHI ELECTRONICS CORPORATION"),
+    (0x047c, "POWERMAT LTD"),
+    (0x047d, "Occly LLC"),
+    (0x047e, "OurHub Dev IvS"),
+    (0x047f, "Pro-Mark, Inc."),
+    (0x0480, "Dynometrics Inc."),
+    (0x0481, "Quintrax Limited"),
+    (0x0482, "POS Tuning Udo Vosshenrich GmbH & Co. KG"),
+    (0x0483, "Multi Care Systems B.V."),
+    (0x0484, "Revol Technologies Inc"),
+    (0x0485, "SKIDATA AG"),
+    (0x0486, "DEV TECNOLOGIA INDUSTRIA, COMERCIO E MANUTENCAO DE EQUIPAMENTOS LTDA. - ME"),
+    (0x0487, "Centrica Connected Home"),
+    (0x0488, "Automotive Data Solutions Inc"),
+    (0x0489, "Igarashi Engineering"),
+    (0x048a, "Taelek Oy"),
+    (0x048b, "CP Electronics Limited"),
+    (0x048c, "Vectronix AG"),
+    (0x048d, "S-Labs Sp. z o.o."),
+    (0x048e, "Companion Medical, Inc."),
+    (0x048f, "BlueKitchen GmbH"),
+    (0x0490, "Matting AB"),
+    (0x0491, "SOREX - Wireless Solutions GmbH"),
+    (0x0492, "ADC Technology, Inc."),
+    (0x0493, "Lynxemi Pte Ltd"),
+    (0x0494, "SENNHEISER electronic GmbH & Co. KG"),
+    (0x0495, "LMT Mercer Group, Inc"),
+    (0x0496, "Polymorphic Labs LLC"),
+    (0x0497, "Cochlear Limited"),
+    (0x0498, "METER Group, Inc. USA"),
+    (0x0499, "Ruuvi Innovations Ltd."),
+    (0x049a, "Situne AS"),
+    (0x049b, "nVisti, LLC"),
+    (0x049c, "DyOcean"),
+    (0x049d, "Uhlmann & Zacher GmbH"),
+    (0x049e, "AND!XOR LLC"),
+    (0x049f, "tictote AB"),
+    (0x04a0, "Vypin, LLC"),
+    (0x04a1, "PNI Sensor Corporation"),
+    (0x04a2, "ovrEngineered, LLC"),
+    (0x04a3, "GT-tronics HK Ltd"),
+    (0x04a4, "Herbert Waldmann GmbH & Co. KG"),
+    (0x04a5, "Guangzhou FiiO Electronics Technology Co.,Ltd"),
+    (0x04a6, "Vinetech Co., Ltd"),
+    (0x04a7, "Dallas Logic Corporation"),
+    (0x04a8, "BioTex, Inc."),
+    (0x04a9, "DISCOVERY SOUND TECHNOLOGY, LLC"),
+    (0x04aa, "LINKIO SAS"),
+    (0x04ab, "Harbortronics, Inc."),
+    (0x04ac, "Undagrid B.V."),
+    (0x04ad, "Shure Inc"),
+    (0x04ae, "ERM Electronic Systems LTD"),
+    (0x04af, "BIOROWER Handelsagentur GmbH"),
+    (0x04b0, "Weba Sport und Med. Artikel GmbH"),
+    (0x04b1, "Kartographers Technologies Pvt. Ltd."),
+    (0x04b2, "The Shadow on the Moon"),
+    (0x04b3, "mobike (Hong Kong) Limited"),
+    (0x04b4, "Inuheat Group AB"),
+    (0x04b5, "Swiftronix AB"),
+    (0x04b6, "Diagnoptics Technologies"),
+    (0x04b7, "Analog Devices, Inc."),
+    (0x04b8, "Soraa Inc."),
+    (0x04b9, "CSR Building Products Limited"),
+    (0x04ba, "Crestron Electronics, Inc."),
+    (0x04bb, "Neatebox Ltd"),
+    (0x04bc, "Draegerwerk AG & Co. KGaA"),
+    (0x04bd, "AlbynMedical"),
+    (0x04be, "Averos FZCO"),
+    (0x04bf, "VIT Initiative, LLC"),
+    (0x04c0, "Statsports International"),
+    (0x04c1, "Sospitas, s.r.o."),
+    (0x04c2, "Dmet Products Corp."),
+    (0x04c3, "Mantracourt Electronics Limited"),
+    (0x04c4, "TeAM Hutchins AB"),
+    (0x04c5, "Seibert Williams Glass, LLC"),
+    (0x04c6, "Insta GmbH"),
+    (0x04c7, "Svantek Sp. z o.o."),
+    (0x04c8, "Shanghai Flyco Electrical Appliance Co., Ltd."),
+    (0x04c9, "Thornwave Labs Inc"),
+    (0x04ca, "Steiner-Optik GmbH"),
+    (0x04cb, "Novo Nordisk A/S"),
+    (0x04cc, "Enflux Inc."),
+    (0x04cd, "Safetech Products LLC"),
+    (0x04ce, "GOOOLED S.R.L."),
+    (0x04cf, "DOM Sicherheitstechnik GmbH & Co. KG"),
+    (0x04d0, "Olympus Corporation"),
+    (0x04d1, "KTS GmbH"),
+    (0x04d2, "Anloq Technologies Inc."),
+    (0x04d3, "Queercon, Inc"),
+    (0x04d4, "5th Element Ltd"),
+    (0x04d5, "Gooee Limited"),
+    (0x04d6, "LUGLOC LLC"),
+    (0x04d7, "Blincam, Inc."),
+    (0x04d8, "FUJIFILM Corporation"),
+    (0x04d9, "RandMcNally"),
+    (0x04da, "Franceschi Marina snc"),
+    (0x04db, "Engineered Audio, LLC."),
+    (0x04dc, "IOTTIVE (OPC) PRIVATE LIMITED"),
+    (0x04dd, "4MOD Technology"),
+    (0x04de, "Lutron Electronics Co., Inc."),
+    (0x04df, "Emerson"),
+    (0x04e0, "Guardtec, Inc."),
+    (0x04e1, "REACTEC LIMITED"),
+    (0x04e2, "EllieGrid"),
+    (0x04e3, "Under Armour"),
+    (0x04e4, "Woodenshark"),
+    (0x04e5, "Avack Oy"),
+    (0x04e6, "Smart Solution Technology, Inc."),
+    (0x04e7, "REHABTRONICS INC."),
+    (0x04e8, "STABILO International"),
+    (0x04e9, "Busch Jaeger Elektro GmbH"),
+    (0x04ea, "Pacific Bioscience Laboratories, Inc"),
+    (0x04eb, "Bird Home Automation GmbH"),
+    (0x04ec, "Motorola Solutions"),
+    (0x04ed, "R9 Technology, Inc."),
+    (0x04ee, "Auxivia"),
+    (0x04ef, "DaisyWorks, Inc"),
+    (0x04f0, "Kosi Limited"),
+    (0x04f1, "Theben AG"),
+    (0x04f2, "InDreamer Techsol Private Limited"),
+    (0x04f3, "Cerevast Medical"),
+    (0x04f4, "ZanCompute Inc."),
+    (0x04f5, "Pirelli Tyre S.P.A."),
+    (0x04f6, "McLear Limited"),
+    (0x04f7, "Shenzhen Huiding Technology Co.,Ltd."),
+    (0x04f8, "Convergence Systems Limited"),
+    (0x04f9, "Interactio"),
+    (0x04fa, "Androtec GmbH"),
+    (0x04fb, "Benchmark Drives GmbH & Co. KG"),
+    (0x04fc, "SwingLync L. L. C."),
+    (0x04fd, "Tapkey GmbH"),
+    (0x04fe, "Woosim Systems Inc."),
+    (0x04ff, "Microsemi Corporation"),
+    (0x0500, "Wiliot LTD."),
+    (0x0501, "Polaris IND"),
+    (0x0502, "Specifi-Kali LLC"),
+    (0x0503, "Locoroll, Inc"),
+    (0x0504, "PHYPLUS Inc"),
+    (0x0505, "InPlay Inc."),
+    (0x0506, "Hager"),
+    (0x0507, "Yellowcog"),
+    (0x0508, "Axes System sp. z o. o."),
+    (0x0509, "myLIFTER Inc."),
+    (0x050a, "Shake-on B.V."),
+    (0x050b, "Vibrissa Inc."),
+    (0x050c, "OSRAM GmbH"),
+    (0x050d, "TRSystems GmbH"),
+    (0x050e, "Yichip Microelectronics (Hangzhou) Co.,Ltd."),
+    (0x050f, "Foundation Engineering LLC"),
+    (0x0510, "UNI-ELECTRONICS, INC."),
+    (0x0511, "Brookfield Equinox LLC"),
+    (0x0512, "Soprod SA"),
+    (0x0513, "9974091 Canada Inc."),
+    (0x0514, "FIBRO GmbH"),
+    (0x0515, "RB Controls Co., Ltd."),
+    (0x0516, "Footmarks"),
+    (0x0517, "Amtronic Sverige AB (formerly Amcore AB)"),
+    (0x0518, "MAMORIO.inc"),
+    (0x0519, "Tyto Life LLC"),
+    (0x051a, "Leica Camera AG"),
+    (0x051b, "Angee Technologies Ltd."),
+    (0x051c, "EDPS"),
+    (0x051d, "OFF Line Co., Ltd."),
+    (0x051e, "Detect Blue Limited"),
+    (0x051f, "Setec Pty Ltd"),
+    (0x0520, "Target Corporation"),
+    (0x0521, "IAI Corporation"),
+    (0x0522, "NS Tech, Inc."),
+    (0x0523, "MTG Co., Ltd."),
+    (0x0524, "Hangzhou iMagic Technology Co., Ltd"),
+    (0x0525, "HONGKONG NANO IC TECHNOLOGIES        CO., LIMITED"),
+    (0x0526, "Honeywell International Inc."),
+    (0x0527, "Albrecht JUNG"),
+    (0x0528, "Lunera Lighting Inc."),
+    (0x0529, "Lumen UAB"),
+    (0x052a, "Keynes Controls Ltd"),
+    (0x052b, "Novartis AG"),
+    (0x052c, "Geosatis SA"),
+    (0x052d, "EXFO, Inc."),
+    (0x052e, "LEDVANCE GmbH"),
+    (0x052f, "Center ID Corp."),
+    (0x0530, "Adolene, Inc."),
+    (0x0531, "D&M Holdings Inc."),
+    (0x0532, "CRESCO Wireless, Inc."),
+    (0x0533, "Nura Operations Pty Ltd"),
+    (0x0534, "Frontiergadget, Inc."),
+    (0x0535, "Smart Component Technologies Limited"),
+    (0x0536, "ZTR Control Systems LLC"),
+    (0x0537, "MetaLogics Corporation"),
+    (0x0538, "Medela AG"),
+    (0x0539, "OPPLE Lighting Co., Ltd"),
+    (0x053a, "Savitech Corp.,"),
+    (0x053b, "prodigy"),
+    (0x053c, "Screenovate Technologies Ltd"),
+    (0x053d, "TESA SA"),
+    (0x053e, "CLIM8 LIMITED"),
+    (0x053f, "Silergy Corp"),
+    (0x0540, "SilverPlus, Inc"),
+    (0x0541, "Sharknet srl"),
+    (0x0542, "Mist Systems, Inc."),
+    (0x0543, "MIWA LOCK CO.,Ltd"),
+    (0x0544, "OrthoSensor, Inc."),
+    (0x0545, "Candy Hoover Group s.r.l"),
+    (0x0546, "Apexar Technologies S.A."),
+    (0x0547, "LOGICDATA d.o.o."),
+    (0x0548, "Knick Elektronische Messgeraete GmbH & Co. KG"),
+    (0x0549, "Smart Technologies and Investment Limited"),
+    (0x054a, "Linough Inc."),
+    (0x054b, "Advanced Electronic Designs, Inc."),
+    (0x054c, "Carefree Scott Fetzer Co Inc"),
+    (0x054d, "Sensome"),
+    (0x054e, "FORTRONIK storitve d.o.o."),
+    (0x054f, "Sinnoz"),
+    (0x0550, "Versa Networks, Inc."),
+    (0x0551, "Sylero"),
+    (0x0552, "Avempace SARL"),
+    (0x0553, "Nintendo Co., Ltd."),
+    (0x0554, "National Instruments"),
+    (0x0555, "KROHNE Messtechnik GmbH"),
+    (0x0556, "Otodynamics Ltd"),
+    (0x0557, "Arwin Technology Limited"),
+    (0x0558, "benegear, inc."),
+    (0x0559, "Newcon Optik"),
+    (0x055a, "CANDY HOUSE, Inc."),
+    (0x055b, "FRANKLIN TECHNOLOGY INC"),
+    (0x055c, "Lely"),
+    (0x055d, "Valve Corporation"),
+    (0x055e, "Hekatron Vertriebs GmbH"),
+    (0x055f, "PROTECH S.A.S. DI GIRARDI ANDREA & C."),
+    (0x0560, "Sarita CareTech APS (formerly Sarita CareTech IVS)"),
+    (0x0561, "Finder S.p.A."),
+    (0x0562, "Thalmic Labs Inc."),
+    (0x0563, "Steinel Vertrieb GmbH"),
+    (0x0564, "Beghelli Spa"),
+    (0x0565, "Beijing Smartspace Technologies Inc."),
+    (0x0566, "CORE TRANSPORT TECHNOLOGIES NZ LIMITED"),
+    (0x0567, "Xiamen Everesports Goods Co., Ltd"),
+    (0x0568, "Bodyport Inc."),
+    (0x0569, "Audionics System, INC."),
+    (0x056a, "Flipnavi Co.,Ltd."),
+    (0x056b, "Rion Co., Ltd."),
+    (0x056c, "Long Range Systems, LLC"),
+    (0x056d, "Redmond Industrial Group LLC"),
+    (0x056e, "VIZPIN INC."),
+    (0x056f, "BikeFinder AS"),
+    (0x0570, "Consumer Sleep Solutions LLC"),
+    (0x0571, "PSIKICK, INC."),
+    (0x0572, "AntTail.com"),
+    (0x0573, "Lighting Science Group Corp."),
+    (0x0574, "AFFORDABLE ELECTRONICS INC"),
+    (0x0575, "Integral Memroy Plc"),
+    (0x0576, "Globalstar, Inc."),
+    (0x0577, "True Wearables, Inc."),
+    (0x0578, "Wellington Drive Technologies Ltd"),
+    (0x0579, "Ensemble Tech Private Limited"),
+    (0x057a, "OMNI Remotes"),
+    (0x057b, "Duracell U.S. Operations Inc."),
+    (0x057c, "Toor Technologies LLC"),
+    (0x057d, "Instinct Performance"),
+    (0x057e, "Beco, Inc"),
+    (0x057f, "Scuf Gaming International, LLC"),
+    (0x0580, "ARANZ Medical Limited"),
+    (0x0581, "LYS TECHNOLOGIES LTD"),
+    (0x0582, "Breakwall Analytics, LLC"),
+    (0x0583, "Code Blue Communications"),
+    (0x0584, "Gira Giersiepen GmbH & Co. KG"),
+    (0x0585, "Hearing Lab Technology"),
+    (0x0586, "LEGRAND"),
+    (0x0587, "Derichs GmbH"),
+    (0x0588, "ALT-TEKNIK LLC"),
+    (0x0589, "Star Technologies"),
+    (0x058a, "START TODAY CO.,LTD."),
+    (0x058b, "Maxim Integrated Products"),
+    (0x058c, "MERCK Kommanditgesellschaft auf Aktien"),
+    (0x058d, "Jungheinrich Aktiengesellschaft"),
+    (0x058e, "Oculus VR, LLC"),
+    (0x058f, "HENDON SEMICONDUCTORS PTY LTD"),
+    (0x0590, "Pur3 Ltd"),
+    (0x0591, "Viasat Group S.p.A."),
+    (0x0592, "IZITHERM"),
+    (0x0593, "Spaulding Clinical Research"),
+    (0x0594, "Kohler Company"),
+    (0x0595, "Inor Process AB"),
+    (0x0596, "My Smart Blinds"),
+    (0x0597, "RadioPulse Inc"),
+    (0x0598, "rapitag GmbH"),
+    (0x0599, "Lazlo326, LLC."),
+    (0x059a, "Teledyne Lecroy, Inc."),
+    (0x059b, "Dataflow Systems Limited"),
+    (0x059c, "Macrogiga Electronics"),
+    (0x059d, "Tandem Diabetes Care"),
+    (0x059e, "Polycom, Inc."),
+    (0x059f, "Fisher & Paykel Healthcare"),
+    (0x05a0, "RCP Software Oy"),
+    (0x05a1, "Shanghai Xiaoyi Technology Co.,Ltd."),
+    (0x05a2, "ADHERIUM(NZ) LIMITED"),
+    (0x05a3, "Axiomware Systems Incorporated"),
+    (0x05a4, "O. E. M. Controls, Inc."),
+    (0x05a5, "Kiiroo BV"),
+    (0x05a6, "Telecon Mobile Limited"),
+    (0x05a7, "Sonos Inc"),
+    (0x05a8, "Tom Allebrandi Consulting"),
+    (0x05a9, "Monidor"),
+    (0x05aa, "Tramex Limited"),
+    (0x05ab, "Nofence AS"),
+    (0x05ac, "GoerTek Dynaudio Co., Ltd."),
+    (0x05ad, "INIA"),
+    (0x05ae, "CARMATE MFG.CO.,LTD"),
+    (0x05af, "OV LOOP, INC. (formerly ONvocal)"),
+    (0x05b0, "NewTec GmbH"),
+    (0x05b1, "Medallion Instrumentation Systems"),
+    (0x05b2, "CAREL INDUSTRIES S.P.A."),
+    (0x05b3, "Parabit Systems, Inc."),
+    (0x05b4, "White Horse Scientific ltd"),
+    (0x05b5, "verisilicon"),
+    (0x05b6, "Elecs Industry Co.,Ltd."),
+    (0x05b7, "Beijing Pinecone Electronics Co.,Ltd."),
+    (0x05b8, "Ambystoma Labs Inc."),
+    (0x05b9, "Suzhou Pairlink Network Technology"),
+    (0x05ba, "igloohome"),
+    (0x05bb, "Oxford Metrics plc"),
+    (0x05bc, "Leviton Mfg. Co., Inc."),
+    (0x05bd, "ULC Robotics Inc."),
+    (0x05be, "RFID Global by Softwork SrL"),
+    (0x05bf, "Real-World-Systems Corporation"),
+    (0x05c0, "Nalu Medical, Inc."),
+    (0x05c1, "P.I.Engineering"),
+    (0x05c2, "Grote Industries"),
+    (0x05c3, "Runtime, Inc."),
+    (0x05c4, "Codecoup sp. z o.o. sp. k."),
+    (0x05c5, "SELVE GmbH & Co. KG"),
+    (0x05c6, "Smart Animal Training Systems, LLC"),
+    (0x05c7, "Lippert Components, INC"),
+    (0x05c8, "SOMFY SAS"),
+    (0x05c9, "TBS Electronics B.V."),
+    (0x05ca, "MHL Custom Inc"),
+    (0x05cb, "LucentWear LLC"),
+    (0x05cc, "WATTS ELECTRONICS"),
+    (0x05cd, "RJ Brands LLC"),
+    (0x05ce, "V-ZUG Ltd"),
+    (0x05cf, "Biowatch SA"),
+    (0x05d0, "Anova Applied Electronics"),
+    (0x05d1, "Lindab AB"),
+    (0x05d2, "frogblue TECHNOLOGY GmbH"),
+    (0x05d3, "Acurable Limited"),
+    (0x05d4, "LAMPLIGHT Co., Ltd."),
+    (0x05d5, "TEGAM, Inc."),
+    (0x05d6, "Zhuhai Jieli technology Co.,Ltd"),
+    (0x05d7, "modum.io AG"),
+    (0x05d8, "Farm Jenny LLC"),
+    (0x05d9, "Toyo Electronics Corporation"),
+    (0x05da, "Applied Neural Research Corp"),
+    (0x05db, "Avid Identification Systems, Inc."),
+    (0x05dc, "Petronics Inc."),
+    (0x05dd, "essentim GmbH"),
+    (0x05de, "QT Medical INC."),
+    (0x05df, "VIRTUALCLINIC.DIRECT LIMITED"),
+    (0x05e0, "Viper Design LLC"),
+    (0x05e1, "Human, Incorporated"),
+    (0x05e2, "stAPPtronics GmbH"),
+    (0x05e3, "Elemental Machines, Inc."),
+    (0x05e4, "Taiyo Yuden Co., Ltd"),
+    (0x05e5, "INEO ENERGY& SYSTEMS"),
+    (0x05e6, "Motion Instruments Inc."),
+    (0x05e7, "PressurePro"),
+    (0x05e8, "COWBOY"),
+    (0x05e9, "iconmobile GmbH"),
+    (0x05ea, "ACS-Control-System GmbH"),
+    (0x05eb, "Bayerische Motoren Werke AG"),
+    (0x05ec, "Gycom Svenska AB"),
+    (0x05ed, "Fuji Xerox Co., Ltd"),
+    (0x05ee, "Glide Inc."),
+    (0x05ef, "SIKOM AS"),
+    (0x05f0, "beken"),
+    (0x05f1, "The Linux Foundation"),
+    (0x05f2, "Try and E CO.,LTD."),
+    (0x05f3, "SeeScan"),
+    (0x05f4, "Clearity, LLC"),
+    (0x05f5, "GS TAG"),
+    (0x05f6, "DPTechnics"),
+    (0x05f7, "TRACMO, INC."),
+    (0x05f8, "Anki Inc."),
+    (0x05f9, "Hagleitner Hygiene International GmbH"),
+    (0x05fa, "Konami Sports Life Co., Ltd."),
+    (0x05fb, "Arblet Inc."),
+    (0x05fc, "Masbando GmbH"),
+    (0x05fd, "Innoseis"),
+    (0x05fe, "Niko nv"),
+    (0x05ff, "Wellnomics Ltd"),
+    (0x0600, "iRobot Corporation"),
+    (0x0601, "Schrader Electronics"),
+    (0x0602, "Geberit International AG"),
+    (0x0603, "Fourth Evolution Inc"),
+    (0x0604, "Cell2Jack LLC"),
+    (0x0605, "FMW electronic Futterer u. Maier-Wolf OHG"),
+    (0x0606, "John Deere"),
+    (0x0607, "Rookery Technology Ltd"),
+    (0x0608, "KeySafe-Cloud"),
+    (0x0609, "BUCHI Labortechnik AG"),
+    (0x060a, "IQAir AG"),
+    (0x060b, "Triax Technologies Inc"),
+    (0x060c, "Vuzix Corporation"),
+    (0x060d, "TDK Corporation"),
+    (0x060e, "Blueair AB"),
+    (0x060f, "Signify Netherlands"),
+    (0x0610, "ADH GUARDIAN USA LLC"),
+    (0x0611, "Beurer GmbH"),
+    (0x0612, "Playfinity AS"),
+    (0x0613, "Hans Dinslage GmbH"),
+    (0x0614, "OnAsset Intelligence, Inc."),
+    (0x0615, "INTER ACTION Corporation"),
+    (0x0616, "OS42 UG (haftungsbeschraenkt)"),
+    (0x0617, "WIZCONNECTED COMPANY LIMITED"),
+    (0x0618, "Audio-Technica Corporation"),
+    (0x0619, "Six Guys Labs, s.r.o."),
+    (0x061a, "R.W. Beckett Corporation"),
+    (0x061b, "silex technology, inc."),
+    (0x061c, "Univations Limited"),
+    (0x061d, "SENS Innovation ApS"),
+    (0x061e, "Diamond Kinetics, Inc."),
+    (0x061f, "Phrame Inc."),
+    (0x0620, "Forciot Oy"),
+    (0x0621, "Noordung d.o.o."),
+    (0x0622, "Beam Labs, LLC"),
+    (0x0623, "Philadelphia Scientific (U.K.) Limited"),
+    (0x0624, "Biovotion AG"),
+    (0x0625, "Square Panda, Inc."),
+    (0x0626, "Amplifico"),
+    (0x0627, "WEG S.A."),
+    (0x0628, "Ensto Oy"),
+    (0x0629, "PHONEPE PVT LTD"),
+    (0x062a, "Lunatico Astronomia SL"),
+    (0x062b, "MinebeaMitsumi Inc."),
+    (0x062c, "ASPion GmbH"),
+    (0x062d, "Vossloh-Schwabe Deutschland GmbH"),
+    (0x062e, "Procept"),
+    (0x062f, "ONKYO Corporation"),
+    (0x0630, "Asthrea D.O.O."),
+    (0x0631, "Fortiori Design LLC"),
+    (0x0632, "Hugo Muller GmbH & Co KG"),
+    (0x0633, "Wangi Lai PLT"),
+    (0x0634, "Fanstel Corp"),
+    (0x0635, "Crookwood"),
+    (0x0636, "ELECTRONICA INTEGRAL DE SONIDO S.A."),
+    (0x0637, "GiP Innovation Tools GmbH"),
+    (0x0638, "LX SOLUTIONS PTY LIMITED"),
+    (0x0639, "Shenzhen Minew Technologies Co., Ltd."),
+    (0x063a, "Prolojik Limited"),
+    (0x063b, "Kromek Group Plc"),
+    (0x063c, "Contec Medical Systems Co., Ltd."),
+    (0x063d, "Xradio Technology Co.,Ltd."),
+    (0x063e, "The Indoor Lab, LLC"),
+    (0x063f, "LDL TECHNOLOGY"),
+    (0x0640, "Parkifi"),
+    (0x0641, "Revenue Collection Systems FRANCE SAS"),
+    (0x0642, "Bluetrum Technology Co.,Ltd"),
+    (0x0643, "makita corporation"),
+    (0x0644, "Apogee Instruments"),
+    (0x0645, "BM3"),
+    (0x0646, "SGV Group Holding GmbH & Co. KG"),
+    (0x0647, "MED-EL"),
+    (0x0648, "Ultune Technologies"),
+    (0x0649, "Ryeex Technology Co.,Ltd."),
+    (0x064a, "Open Research Institute, Inc."),
+    (0x064b, "Scale-Tec, Ltd"),
+    (0x064c, "Zumtobel Group AG"),
+    (0x064d, "iLOQ Oy"),
+    (0x064e, "KRUXWorks Technologies Private Limited"),
+    (0x064f, "Digital Matter Pty Ltd"),
+    (0x0650, "Coravin, Inc."),
+    (0x0651, "Stasis Labs, Inc."),
+    (0x0652, "ITZ Innovations- und Technologiezentrum GmbH"),
+    (0x0653, "Meggitt SA"),
+    (0x0654, "Ledlenser GmbH & Co. KG"),
+    (0x0655, "Renishaw PLC"),
+    (0x0656, "ZhuHai AdvanPro Technology Company Limited"),
+    (0x0657, "Meshtronix Limited"),
+    (0x0658, "Payex Norge AS"),
+    (0x0659, "UnSeen Technologies Oy"),
+    (0x065a, "Zound Industries International AB"),
+    (0x065b, "Sesam Solutions BV"),
+    (0x065c, "PixArt Imaging Inc."),
+    (0x065d, "Panduit Corp."),
+    (0x065e, "Alo AB"),
+    (0x065f, "Ricoh Company Ltd"),
+    (0x0660, "RTC Industries, Inc."),
+    (0x0661, "Mode Lighting Limited"),
+    (0x0662, "Particle Industries, Inc."),
+    (0x0663, "Advanced Telemetry Systems, Inc."),
+    (0x0664, "RHA TECHNOLOGIES LTD"),
+    (0x0665, "Pure International Limited"),
+    (0x0666, "WTO Werkzeug-Einrichtungen GmbH"),
+    (0x0667, "Spark Technology Labs Inc."),
+    (0x0668, "Bleb Technology srl"),
+    (0x0669, "Livanova USA, Inc."),
+    (0x066a, "Brady Worldwide Inc."),
+    (0x066b, "DewertOkin GmbH"),
+    (0x066c, "Ztove ApS"),
+    (0x066d, "Venso EcoSolutions AB"),
+    (0x066e, "Eurotronik Kranj d.o.o."),
+    (0x066f, "Hug Technology Ltd"),
+    (0x0670, "Gema Switzerland GmbH"),
+    (0x0671, "Buzz Products Ltd."),
+    (0x0672, "Kopi"),
+    (0x0673, "Innova Ideas Limited"),
+    (0x0674, "BeSpoon"),
+    (0x0675, "Deco Enterprises, Inc."),
+    (0x0676, "Expai Solutions Private Limited"),
+    (0x0677, "Innovation First, Inc."),
+    (0x0678, "SABIK Offshore GmbH"),
+    (0x0679, "4iiii Innovations Inc."),
+    (0x067a, "The Energy Conservatory, Inc."),
+    (0x067b, "I.FARM, INC."),
+    (0x067c, "Tile, Inc."),
+    (0x067d, "Form Athletica Inc."),
+    (0x067e, "MbientLab Inc"),
+    (0x067f, "NETGRID S.N.C. DI BISSOLI MATTEO, CAMPOREALE SIMONE, TOGNETTI FEDERICO"),
+    (0x0680, "Mannkind Corporation"),
+    (0x0681, "Trade FIDES a.s."),
+    (0x0682, "Photron Limited"),
+    (0x0683, "Eltako GmbH"),
+    (0x0684, "Dermalapps, LLC"),
+    (0x0685, "Greenwald Industries"),
+    (0x0686, "inQs Co., Ltd."),
+    (0x0687, "Cherry GmbH"),
+    (0x0688, "Amsted Digital Solutions Inc."),
+    (0x0689, "Tacx b.v."),
+    (0x068a, "Raytac Corporation"),
+    (0x068b, "Jiangsu Teranovo Tech Co., Ltd."),
+    (0x068c, "Changzhou Sound Dragon Electronics and Acoustics Co., Ltd"),
+    (0x068d, "JetBeep Inc."),
+    (0x068e, "Razer Inc."),
+    (0x068f, "JRM Group Limited"),
+    (0x0690, "Eccrine Systems, Inc."),
+    (0x0691, "Curie Point AB"),
+    (0x0692, "Georg Fischer AG"),
+    (0x0693, "Hach - Danaher"),
+    (0x0694, "T&A Laboratories LLC"),
+    (0x0695, "Koki Holdings Co., Ltd."),
+    (0x0696, "Gunakar Private Limited"),
+    (0x0697, "Stemco Products Inc"),
+    (0x0698, "Wood IT Security, LLC"),
+    (0x0699, "RandomLab SAS"),
+    (0x069a, "Adero, Inc. (formerly as TrackR, Inc.)"),
+    (0x069b, "Dragonchip Limited"),
+    (0x069c, "Noomi AB"),
+    (0x069d, "Vakaros LLC"),
+    (0x069e, "Delta Electronics, Inc."),
+    (0x069f, "FlowMotion Technologies AS"),
+    (0x06a0, "OBIQ Location Technology Inc."),
+    (0x06a1, "Cardo Systems, Ltd"),
+    (0x06a2, "Globalworx GmbH"),
+    (0x06a3, "Nymbus, LLC"),
+    (0x06a4, "Sanyo Techno Solutions Tottori Co., Ltd."),
+    (0x06a5, "TEKZITEL PTY LTD"),
+    (0x06a6, "Roambee Corporation"),
+    (0x06a7, "Chipsea Technologies (ShenZhen) Corp."),
+    (0x06a8, "GD Midea Air-Conditioning Equipment Co., Ltd."),
+    (0x06a9, "Soundmax Electronics Limited"),
+    (0x06aa, "Produal Oy"),
+    (0x06ab, "HMS Industrial Networks AB"),
+    (0x06ac, "Ingchips Technology Co., Ltd."),
+    (0x06ad, "InnovaSea Systems Inc."),
+    (0x06ae, "SenseQ Inc."),
+    (0x06af, "Shoof Technologies"),
+    (0x06b0, "BRK Brands, Inc."),
+    (0x06b1, "SimpliSafe, Inc."),
+    (0x06b2, "Tussock Innovation 2013 Limited"),
+    (0x06b3, "The Hablab ApS"),
+    (0x06b4, "Sencilion Oy"),
+    (0x06b5, "Wabilogic Ltd."),
+    (0x06b6, "Sociometric Solutions, Inc."),
+    (0x06b7, "iCOGNIZE GmbH"),
+    (0x06b8, "ShadeCraft, Inc"),
+    (0x06b9, "Beflex Inc."),
+    (0x06ba, "Beaconzone Ltd"),
+    (0x06bb, "Leaftronix Analogic Solutions Private Limited"),
+    (0x06bc, "TWS Srl"),
+    (0x06bd, "ABB Oy"),
+    (0x06be, "HitSeed Oy"),
+    (0x06bf, "Delcom Products Inc."),
+    (0x06c0, "CAME S.p.A."),
+    (0x06c1, "Alarm.com Holdings, Inc"),
+    (0x06c2, "Measurlogic Inc."),
+    (0x06c3, "King I Electronics.Co.,Ltd"),
+    (0x06c4, "Dream Labs GmbH"),
+    (0x06c5, "Urban Compass, Inc"),
+    (0x06c6, "Simm Tronic Limited"),
+    (0x06c7, "Somatix Inc"),
+    (0x06c8, "Storz & Bickel GmbH & Co. KG"),
+    (0x06c9, "MYLAPS B.V."),
+    (0x06ca, "Shenzhen Zhongguang Infotech Technology Development Co., Ltd"),
+    (0x06cb, "Dyeware, LLC"),
+    (0x06cc, "Dongguan SmartAction Technology Co.,Ltd."),
+    (0x06cd, "DIG Corporation"),
+    (0x06ce, "FIOR & GENTZ"),
+    (0x06cf, "Belparts N.V."),
+    (0x06d0, "Etekcity Corporation"),
+    (0x06d1, "Meyer Sound Laboratories, Incorporated"),
+    (0x06d2, "CeoTronics AG"),
+    (0x06d3, "TriTeq Lock and Security, LLC"),
+    (0x06d4, "DYNAKODE TECHNOLOGY PRIVATE LIMITED"),
+    (0x06d5, "Sensirion AG"),
+    (0x06d6, "JCT Healthcare Pty Ltd"),
+    (0x06d7, "FUBA Automotive Electronics GmbH"),
+    (0x06d8, "AW Company"),
+    (0x06d9, "Shanghai Mountain View Silicon Co.,Ltd."),
+    (0x06da, "Zliide Technologies ApS"),
+    (0x06db, "Automatic Labs, Inc."),
+    (0x06dc, "Industrial Network Controls, LLC"),
+    (0x06dd, "Intellithings Ltd."),
+    (0x06de, "Navcast, Inc."),
+    (0x06df, "Hubbell Lighting, Inc."),
+    (0x06e0, "Avaya"),
+    (0x06e1, "Milestone AV Technologies LLC"),
+    (0x06e2, "Alango Technologies Ltd"),
+    (0x06e3, "Spinlock Ltd"),
+    (0x06e4, "Aluna"),
+    (0x06e5, "OPTEX CO.,LTD."),
+    (0x06e6, "NIHON DENGYO KOUSAKU"),
+    (0x06e7, "VELUX A/S"),
+    (0x06e8, "Almendo Technologies GmbH"),
+    (0x06e9, "Zmartfun Electronics, Inc."),
+    (0x06ea, "SafeLine Sweden AB"),
+    (0x06eb, "Houston Radar LLC"),
+    (0x06ec, "Sigur"),
+    (0x06ed, "J Neades Ltd"),
+    (0x06ee, "Avantis Systems Limited"),
+    (0x06ef, "ALCARE Co., Ltd."),
+    (0x06f0, "Chargy Technologies, SL"),
+    (0x06f1, "Shibutani Co., Ltd."),
+    (0x06f2, "Trapper Data AB"),
+    (0x06f3, "Alfred International Inc."),
+    (0x06f4, "Near Field Solutions Ltd"),
+    (0x06f5, "Vigil Technologies Inc."),
+    (0x06f6, "Vitulo Plus BV"),
+    (0x06f7, "WILKA Schliesstechnik GmbH"),
+    (0x06f8, "BodyPlus Technology Co.,Ltd"),
+    (0x06f9, "happybrush GmbH"),
+    (0x06fa, "Enequi AB"),
+    (0x06fb, "Sartorius AG"),
+    (0x06fc, "Tom Communication Industrial Co.,Ltd."),
+    (0x06fd, "ESS Embedded System Solutions Inc."),
+    (0x06fe, "Mahr GmbH"),
+    (0x06ff, "Redpine Signals Inc"),
+    (0x0700, "TraqFreq LLC"),
+    (0x0701, "PAFERS TECH"),
+    (0x0702, "Akciju sabiedriba \"SAF TEHNIKA\""),
+    (0x0703, "Beijing Jingdong Century Trading Co., Ltd."),
+    (0x0704, "JBX Designs Inc."),
+    (0x0705, "AB Electrolux"),
+    (0x0706, "Wernher von Braun Center for ASdvanced Research"),
+    (0x0707, "Essity Hygiene and Health Aktiebolag"),
+    (0x0708, "Be Interactive Co., Ltd"),
+    (0x0709, "Carewear Corp."),
+    (0x070a, "Huf Hülsbeck & Fürst GmbH & Co. KG"),
+    (0x070b, "Element Products, Inc."),
+    (0x070c, "Beijing Winner Microelectronics Co.,Ltd"),
+    (0x070d, "SmartSnugg Pty Ltd"),
+    (0x070e, "FiveCo Sarl"),
+    (0x070f, "California Things Inc."),
+    (0x0710, "Audiodo AB"),
+    (0x0711, "ABAX AS"),
+    (0x0712, "Bull Group Company Limited"),
+    (0x0713, "Respiri Limited"),
+    (0x0714, "MindPeace Safety LLC"),
+    (0x0715, "Vgyan Solutions"),
+    (0x0716, "Altonics"),
+    (0x0717, "iQsquare BV"),
+    (0x0718, "IDIBAIX enginneering"),
+    (0x0719, "ECSG"),
+    (0x071a, "REVSMART WEARABLE HK CO LTD"),
+    (0x071b, "Precor"),
+    (0x071c, "F5 Sports, Inc"),
+    (0x071d, "exoTIC Systems"),
+    (0x071e, "DONGGUAN HELE ELECTRONICS CO., LTD"),
+    (0x071f, "Dongguan Liesheng Electronic Co.Ltd"),
+    (0x0720, "Oculeve, Inc."),
+    (0x0721, "Clover Network, Inc."),
+    (0x0722, "Xiamen Eholder Electronics Co.Ltd"),
+    (0x0723, "Ford Motor Company"),
+    (0x0724, "Guangzhou SuperSound Information Technology Co.,Ltd"),
+    (0x0725, "Tedee Sp. z o.o."),
+    (0x0726, "PHC Corporation"),
+    (0x0727, "STALKIT AS"),
+    (0x0728, "Eli Lilly and Company"),
+    (0x0729, "SwaraLink Technologies"),
+    (0x072a, "JMR embedded systems GmbH"),
+    (0x072b, "Bitkey Inc."),
+    (0x072c, "GWA Hygiene GmbH"),
+    (0x072d, "Safera Oy"),
+    (0x072e, "Open Platform Systems LLC"),
+    (0x072f, "OnePlus Electronics (Shenzhen) Co., Ltd."),
+    (0x0730, "Wildlife Acoustics, Inc."),
+    (0x0731, "ABLIC Inc."),
+    (0x0732, "Dairy Tech, Inc."),
+    (0x0733, "Iguanavation, Inc."),
+    (0x0734, "DiUS Computing Pty Ltd"),
+    (0x0735, "UpRight Technologies LTD"),
+    (0x0736, "FrancisFund, LLC"),
+    (0x0737, "LLC Navitek"),
+    (0x0738, "Glass Security Pte Ltd"),
+    (0x0739, "Jiangsu Qinheng Co., Ltd."),
+    (0x073a, "Chandler Systems Inc."),
+    (0x073b, "Fantini Cosmi s.p.a."),
+    (0x073c, "Acubit ApS"),
+    (0x073d, "Beijing Hao Heng Tian Tech Co., Ltd."),
+    (0x073e, "Bluepack S.R.L."),
+    (0x073f, "Beijing Unisoc Technologies Co., Ltd."),
+    (0x0740, "HITIQ LIMITED"),
+    (0x0741, "MAC SRL"),
+    (0x0742, "DML LLC"),
+    (0x0743, "Sanofi"),
+    (0x0744, "SOCOMEC"),
+    (0x0745, "WIZNOVA, Inc."),
+    (0x0746, "Seitec Elektronik GmbH"),
+    (0x0747, "OR Technologies Pty Ltd"),
+    (0x0748, "GuangZhou KuGou Computer Technology Co.Ltd"),
+    (0x0749, "DIAODIAO (Beijing) Technology Co., Ltd."),
+    (0x074a, "Illusory Studios LLC"),
+    (0x074b, "Sarvavid Software Solutions LLP"),
+    (0x074c, "iopool s.a."),
+    (0x074d, "Amtech Systems, LLC"),
+    (0x074e, "EAGLE DETECTION SA"),
+    (0x074f, "MEDIATECH S.R.L."),
+    (0x0750, "Hamilton Professional Services of Canada Incorporated"),
+    (0x0751, "Changsha JEMO IC Design Co.,Ltd"),
+    (0x0752, "Elatec GmbH"),
+    (0x0753, "JLG Industries, Inc."),
+    (0x0754, "Michael Parkin"),
+    (0x0755, "Brother Industries, Ltd"),
+    (0x0756, "Lumens For Less, Inc"),
+    (0x0757, "ELA Innovation"),
+    (0x0758, "umanSense AB"),
+    (0x0759, "Shanghai InGeek Cyber Security Co., Ltd."),
+    (0x075a, "HARMAN CO.,LTD."),
+    (0x075b, "Smart Sensor Devices AB"),
+    (0x075c, "Antitronics Inc."),
+    (0x075d, "RHOMBUS SYSTEMS, INC."),
+    (0x075e, "Katerra Inc."),
+    (0x075f, "Remote Solution Co., LTD."),
+    (0x0760, "Vimar SpA"),
+    (0x0761, "Mantis Tech LLC"),
+    (0x0762, "TerOpta Ltd"),
+    (0x0763, "PIKOLIN S.L."),
+    (0x0764, "WWZN Information Technology Company Limited"),
+    (0x0765, "Voxx International"),
+    (0x0766, "ART AND PROGRAM, INC."),
+    (0x0767, "NITTO DENKO ASIA TECHNICAL CENTRE PTE. LTD."),
+    (0x0768, "Peloton Interactive Inc."),
+    (0x0769, "Force Impact Technologies"),
+    (0x076a, "Dmac Mobile Developments, LLC"),
+    (0x076b, "Engineered Medical Technologies"),
+    (0x076c, "Noodle Technology inc"),
+    (0x076d, "Graesslin GmbH"),
+    (0x076e, "WuQi technologies, Inc."),
+    (0x076f, "Successful Endeavours Pty Ltd"),
+    (0x0770, "InnoCon Medical ApS"),
+    (0x0771, "Corvex Connected Safety"),
+    (0x0772, "Thirdwayv Inc."),
+    (0x0773, "Echoflex Solutions Inc."),
+    (0x0774, "C-MAX Asia Limited"),
+    (0x0775, "4eBusiness GmbH"),
+    (0x0776, "Cyber Transport Control GmbH"),
+    (0x0777, "Cue"),
+    (0x0778, "KOAMTAC INC."),
+    (0x0779, "Loopshore Oy"),
+    (0x077a, "Niruha Systems Private Limited"),
+    (0x077b, "AmaterZ, Inc."),
+    (0x077c, "radius co., ltd."),
+    (0x077d, "Sensority, s.r.o."),
+    (0x077e, "Sparkage Inc."),
+    (0x077f, "Glenview Software Corporation"),
+    (0x0780, "Finch Technologies Ltd."),
+    (0x0781, "Qingping Technology (Beijing) Co., Ltd."),
+    (0x0782, "DeviceDrive AS"),
+    (0x0783, "ESEMBER LIMITED LIABILITY COMPANY"),
+    (0x0784, "audifon GmbH & Co. KG"),
+    (0x0785, "O2 Micro, Inc."),
+    (0x0786, "HLP Controls Pty Limited"),
+    (0x0787, "Pangaea Solution"),
+    (0x0788, "BubblyNet, LLC"),
+    (0x0789, "Not Assigned"),
+    (0x078a, "The Wildflower Foundation"),
+    (0x078b, "Optikam Tech Inc."),
+    (0x078c, "MINIBREW HOLDING B.V"),
+    (0x078d, "Cybex GmbH"),
+    (0x078e, "FUJIMIC NIIGATA, INC."),
+    (0x078f, "Hanna Instruments, Inc."),
+    (0x0790, "KOMPAN A/S"),
+    (0x0791, "Scosche Industries, Inc."),
+    (0x0792, "Provo Craft"),
+    (0x0793, "AEV spol. s r.o."),
+    (0x0794, "The Coca-Cola Company"),
+    (0x0795, "GASTEC CORPORATION"),
+    (0x0796, "StarLeaf Ltd"),
+    (0x0797, "Water-i.d. GmbH"),
+    (0x0798, "HoloKit, Inc."),
+    (0x0799, "PlantChoir Inc."),
+    (0x079a, "GuangDong Oppo Mobile Telecommunications Corp., Ltd."),
+    (0x079b, "CST ELECTRONICS (PROPRIETARY) LIMITED"),
+    (0x079c, "Sky UK Limited"),
+    (0x079d, "Digibale Pty Ltd"),
+    (0x079e, "Smartloxx GmbH"),
+    (0x079f, "Pune Scientific LLP"),
+    (0x07a0, "Regent Beleuchtungskorper AG"),
+    (0x07a1, "Apollo Neuroscience, Inc."),
+    (0x07a2, "Roku, Inc."),
+    (0x07a3, "Comcast Cable"),
+    (0x07a4, "Xiamen Mage Information Technology Co., Ltd."),
+    (0x07a5, "RAB Lighting, Inc."),
+    (0x07a6, "Musen Connect, Inc."),
+    (0x07a7, "Zume, Inc."),
+    (0x07a8, "conbee GmbH"),
+    (0x07a9, "Bruel & Kjaer Sound & Vibration"),
+    (0x07aa, "The Kroger Co."),
+    (0x07ab, "Granite River Solutions, Inc."),
+    (0x07ac, "LoupeDeck Oy"),
+    (0x07ad, "New H3C Technologies Co.,Ltd"),
+    (0x07ae, "Aurea Solucoes Tecnologicas Ltda."),
+    (0x07af, "Hong Kong Bouffalo Lab Limited"),
+    (0x07b0, "GV Concepts Inc."),
+    (0x07b1, "Thomas Dynamics, LLC"),
+    (0x07b2, "Moeco IOT Inc."),
+    (0x07b3, "2N TELEKOMUNIKACE a.s."),
+    (0x07b4, "Hormann KG Antriebstechnik"),
+    (0x07b5, "CRONO CHIP, S.L."),
+    (0x07b6, "Soundbrenner Limited"),
+    (0x07b7, "ETABLISSEMENTS GEORGES RENAULT"),
+    (0x07b8, "iSwip"),
+    (0x07b9, "Epona Biotec Limited"),
+    (0x07ba, "Battery-Biz Inc."),
+    (0x07bb, "EPIC S.R.L."),
+    (0x07bc, "KD CIRCUITS LLC"),
+    (0x07bd, "Genedrive Diagnostics Ltd"),
+    (0x07be, "Axentia Technologies AB"),
+    (0x07bf, "REGULA Ltd."),
+    (0x07c0, "Biral AG"),
+    (0x07c1, "A.W. Chesterton Company"),
+    (0x07c2, "Radinn AB"),
+    (0x07c3, "CIMTechniques, Inc."),
+    (0x07c4, "Johnson Health Tech NA"),
+    (0x07c5, "June Life, Inc."),
+    (0x07c6, "Bluenetics GmbH"),
+    (0x07c7, "iaconicDesign Inc."),
+    (0x07c8, "WRLDS Creations AB"),
+    (0x07c9, "Skullcandy, Inc."),
+    (0x07ca, "Modul-System HH AB"),
+    (0x07cb, "West Pharmaceutical Services, Inc."),
+    (0x07cc, "Barnacle Systems Inc."),
+    (0x07cd, "Smart Wave Technologies Canada Inc"),
+    (0x07ce, "Shanghai Top-Chip Microelectronics Tech. Co., LTD"),
+    (0x07cf, "NeoSensory, Inc."),
+    (0x07d0, "Hangzhou Tuya Information        Technology Co., Ltd"),
+    (0x07d1, "Shanghai Panchip Microelectronics Co., Ltd"),
+    (0x07d2, "React Accessibility Limited"),
+    (0x07d3, "LIVNEX Co.,Ltd."),
+    (0x07d4, "Kano Computing Limited"),
+    (0x07d5, "hoots classic GmbH"),
+    (0x07d6, "ecobee Inc."),
+    (0x07d7, "Nanjing Qinheng Microelectronics Co., Ltd"),
+    (0x07d8, "SOLUTIONS AMBRA INC."),
+    (0x07d9, "Micro-Design, Inc."),
+    (0x07da, "STARLITE Co., Ltd."),
+    (0x07db, "Remedee Labs"),
+    (0x07dc, "ThingOS GmbH"),
+    (0x07dd, "Linear Circuits"),
+    (0x07de, "Unlimited Engineering SL"),
+    (0x07df, "Snap-on Incorporated"),
+    (0x07e0, "Edifier International Limited"),
+    (0x07e1, "Lucie Labs"),
+    (0x07e2, "Alfred Kaercher SE & Co. KG"),
+    (0x07e3, "Audiowise Technology Inc."),
+    (0x07e4, "Geeksme S.L."),
+    (0x07e5, "Minut, Inc."),
+    (0x07e6, "Autogrow Systems Limited"),
+    (0x07e7, "Komfort IQ, Inc."),
+    (0x07e8, "Packetcraft, Inc."),
+    (0x07e9, "Häfele GmbH & Co KG"),
+    (0x07ea, "ShapeLog, Inc."),
+    (0x07eb, "NOVABASE S.R.L."),
+    (0x07ec, "Frecce LLC"),
+    (0x07ed, "Joule IQ, INC."),
+    (0x07ee, "KidzTek LLC"),
+    (0x07ef, "Aktiebolaget Sandvik Coromant"),
+    (0x07f0, "e-moola.com Pty Ltd"),
+    (0x07f1, "Zimi Innovations Pty Ltd"),
+    (0x07f2, "SERENE GROUP, INC"),
+    (0x07f3, "DIGISINE ENERGYTECH CO. LTD."),
+    (0x07f4, "MEDIRLAB Orvosbiologiai Fejleszto Korlatolt Felelossegu Tarsasag"),
+    (0x07f5, "Byton North America Corporation"),
+    (0x07f6, "Shenzhen TonliScience and Technology Development Co.,Ltd"),
+    (0x07f7, "Cesar Systems Ltd."),
+    (0x07f8, "quip NYC Inc."),
+    (0x07f9, "Direct Communication Solutions, Inc."),
+    (0x07fa, "Klipsch Group, Inc."),
+    (0x07fb, "Access Co., Ltd"),
+    (0x07fc, "Renault SA"),
+    (0x07fd, "JSK CO., LTD."),
+    (0x07fe, "BIROTA"),
+    (0x07ff, "maxon motor ltd."),
+    (0x0800, "Optek"),
+    (0x0801, "CRONUS ELECTRONICS LTD"),
+    (0x0802, "NantSound, Inc."),
+    (0x0803, "Domintell s.a."),
+    (0x0804, "Andon Health Co.,Ltd"),
+    (0x0805, "Urbanminded Ltd"),
+    (0x0806, "TYRI Sweden AB"),
+    (0x0807, "ECD Electronic Components GmbH Dresden"),
+    (0x0808, "SISTEMAS KERN, SOCIEDAD ANÓMINA"),
+    (0x0809, "Trulli Audio"),
+    (0x080a, "Altaneos"),
+    (0x080b, "Nanoleaf Canada Limited"),
+    (0x080c, "Ingy B.V."),
+    (0x080d, "Azbil Co."),
+    (0x080e, "TATTCOM LLC"),
+    (0x080f, "Paradox Engineering SA"),
+    (0x0810, "LECO Corporation"),
+    (0x0811, "Becker Antriebe GmbH"),
+    (0x0812, "Mstream Technologies., Inc."),
+    (0x0813, "Flextronics International USA Inc."),
+    (0x0814, "Ossur hf."),
+    (0x0815, "SKC Inc"),
+    (0x0816, "SPICA SYSTEMS LLC"),
+    (0x0817, "Wangs Alliance Corporation"),
+    (0x0818, "tatwah SA"),
+    (0x0819, "Hunter Douglas Inc"),
+    (0x081a, "Shenzhen Conex"),
+    (0x081b, "DIM3"),
+    (0x081c, "Bobrick Washroom Equipment, Inc."),
+    (0x081d, "Potrykus Holdings and Development LLC"),
+    (0x081e, "iNFORM Technology GmbH"),
+    (0x081f, "eSenseLab LTD"),
+    (0x0820, "Brilliant Home Technology, Inc."),
+    (0x0821, "INOVA Geophysical, Inc."),
+    (0x0822, "adafruit industries"),
+    (0x0823, "Nexite Ltd"),
+    (0x0824, "8Power Limited"),
+    (0x0825, "CME PTE. LTD."),
+    (0x0826, "Hyundai Motor Company"),
+    (0x0827, "Kickmaker"),
+    (0x0828, "Shanghai Suisheng Information Technology Co., Ltd."),
+    (0x0829, "HEXAGON"),
+    (0x082a, "Mitutoyo Corporation"),
+    (0x082b, "shenzhen fitcare electronics Co.,Ltd"),
+    (0x082c, "INGICS TECHNOLOGY CO., LTD."),
+    (0x082d, "INCUS PERFORMANCE LTD."),
+    (0x082e, "ABB S.p.A."),
+    (0x082f, "Blippit AB"),
+    (0x0830, "Core Health and Fitness LLC"),
+    (0x0831, "Foxble, LLC"),
+    (0x0832, "Intermotive,Inc."),
+    (0x0833, "Conneqtech B.V."),
+    (0x0834, "RIKEN KEIKI CO., LTD.,"),
+    (0x0835, "Canopy Growth Corporation"),
+    (0x0836, "Bitwards Oy"),
+    (0x0837, "vivo Mobile Communication Co., Ltd."),
+    (0x0838, "Etymotic Research, Inc."),
+    (0x0839, "A puissance 3"),
+    (0x083a, "BPW Bergische Achsen Kommanditgesellschaft"),
+    (0x083b, "Piaggio Fast Forward"),
+    (0x083c, "BeerTech LTD"),
+    (0x083d, "Tokenize, Inc."),
+    (0x083e, "Zorachka LTD"),
+    (0x083f, "D-Link Corp."),
+    (0x0840, "Down Range Systems LLC"),
+    (0x0841, "General Luminaire (Shanghai) Co., Ltd."),
+    (0x0842, "Tangshan HongJia electronic technology co., LTD."),
+    (0x0843, "FRAGRANCE DELIVERY TECHNOLOGIES LTD"),
+    (0x0844, "Pepperl + Fuchs GmbH"),
+    (0x0845, "Dometic Corporation"),
+    (0x0846, "USound GmbH"),
+    (0x0847, "DNANUDGE LIMITED"),
+    (0x0848, "JUJU JOINTS CANADA CORP."),
+    (0x0849, "Dopple Technologies B.V."),
+    (0x084a, "ARCOM"),
+    (0x084b, "Biotechware SRL"),
+    (0x084c, "ORSO Inc."),
+    (0x084d, "SafePort"),
+    (0x084e, "Carol Cole Company"),
+    (0x084f, "Embedded Fitness B.V."),
+    (0x0850, "Yealink (Xiamen) Network Technology Co.,LTD"),
+    (0x0851, "Subeca, Inc."),
+    (0x0852, "Cognosos, Inc."),
+    (0x0853, "Pektron Group Limited"),
+    (0x0854, "Tap Sound System"),
+    (0x0855, "Helios Hockey, Inc."),
+    (0x0856, "Canopy Growth Corporation"),
+    (0x0857, "Parsyl Inc"),
+    (0x0858, "SOUNDBOKS"),
+    (0x0859, "BlueUp"),
+    (0x085a, "DAKATECH"),
+    (0x085b, "RICOH ELECTRONIC DEVICES CO., LTD."),
+    (0x085c, "ACOS CO.,LTD."),
+    (0x085d, "Guilin Zhishen Information Technology Co.,Ltd."),
+    (0x085e, "Krog Systems LLC"),
+    (0x085f, "COMPEGPS TEAM,SOCIEDAD LIMITADA"),
+    (0x0860, "Alflex Products B.V."),
+    (0x0861, "SmartSensor Labs Ltd"),
+    (0x0862, "SmartDrive Inc."),
+    (0x0863, "Yo-tronics Technology Co., Ltd."),
+    (0x0864, "Rafaelmicro"),
+    (0x0865, "Emergency Lighting Products Limited"),
+    (0x0866, "LAONZ Co.,Ltd"),
+    (0x0867, "Western Digital Techologies, Inc."),
+    (0x0868, "WIOsense GmbH & Co. KG"),
+    (0x0869, "EVVA Sicherheitstechnologie GmbH"),
+    (0x086a, "Odic Incorporated"),
+    (0x086b, "Pacific Track, LLC"),
+    (0x086c, "Revvo Technologies, Inc."),
+    (0x086d, "Biometrika d.o.o."),
+    (0x086e, "Vorwerk Elektrowerke GmbH & Co. KG"),
+    (0x086f, "Trackunit A/S"),
+    (0x0870, "Wyze Labs, Inc"),
+    (0x0871, "Dension Elektronikai Kft. (formerly: Dension Audio Systems Ltd.)"),
+    (0x0872, "11 Health & Technologies Limited"),
+    (0x0873, "Innophase Incorporated"),
+    (0x0874, "Treegreen Limited"),
+    (0x0875, "Berner International LLC"),
+    (0x0876, "SmartResQ ApS"),
+    (0x0877, "Tome, Inc."),
+    (0x0878, "The Chamberlain Group, Inc."),
+    (0x0879, "MIZUNO Corporation"),
+    (0x087a, "ZRF, LLC"),
+    (0x087b, "BYSTAMP"),
+    (0x087c, "Crosscan GmbH"),
+    (0x087d, "Konftel AB"),
+    (0x087e, "1bar.net Limited"),
+    (0x087f, "Phillips Connect Technologies LLC"),
+    (0x0880, "imagiLabs AB"),
+    (0x0881, "Optalert"),
+    (0x0882, "PSYONIC, Inc."),
+    (0x0883, "Wintersteiger AG"),
+    (0x0884, "Controlid Industria, Comercio de Hardware e Servicos de Tecnologia Ltda"),
+    (0x0885, "LEVOLOR, INC."),
+    (0x0886, "Xsens Technologies B.V."),
+    (0x0887, "Hydro-Gear Limited Partnership"),
+    (0x0888, "EnPointe Fencing Pty Ltd"),
+    (0x0889, "XANTHIO"),
+    (0x088a, "sclak s.r.l."),
+    (0x088b, "Tricorder Arraay Technologies LLC"),
+    (0x088c, "GB Solution co.,Ltd"),
+    (0x088d, "Soliton Systems K.K."),
+    (0x088e, "GIGA-TMS INC"),
+    (0x088f, "Tait International Limited"),
+    (0x0890, "NICHIEI INTEC CO., LTD."),
+    (0x0891, "SmartWireless GmbH & Co. KG"),
+    (0x0892, "Ingenieurbuero Birnfeld UG (haftungsbeschraenkt)"),
+    (0x0893, "Maytronics Ltd"),
+    (0x0894, "EPIFIT"),
+    (0x0895, "Gimer medical"),
+    (0x0896, "Nokian Renkaat Oyj"),
+    (0x0897, "Current Lighting Solutions LLC"),
+    (0x0898, "Sensibo, Inc."),
+    (0x0899, "SFS unimarket AG"),
+    (0x089a, "Private limited company \"Teltonika\""),
+    (0x089b, "Saucon Technologies"),
+    (0x089c, "Embedded Devices Co. Company"),
+    (0x089d, "J-J.A.D.E. Enterprise LLC"),
+    (0x089e, "i-SENS, inc."),
+    (0x089f, "Witschi Electronic Ltd"),
+    (0x08a0, "Aclara Technologies LLC"),
+    (0x08a1, "EXEO TECH CORPORATION"),
+    (0x08a2, "Epic Systems Co., Ltd."),
+    (0x08a3, "Hoffmann SE"),
+    (0x08a4, "Realme Chongqing Mobile Telecommunications Corp., Ltd."),
+    (0x08a5, "UMEHEAL Ltd"),
+    (0x08a6, "Intelligenceworks Inc."),
+    (0x08a7, "TGR 1.618 Limited"),
+    (0x08a8, "Shanghai Kfcube Inc"),
+    (0x08a9, "Fraunhofer IIS"),
+    (0x08aa, "SZ DJI TECHNOLOGY CO.,LTD"),
+    (0x08ab, "Coburn Technology, LLC"),
+    (0x08ac, "Topre Corporation"),
+    (0x08ad, "Kayamatics Limited"),
+    (0x08ae, "Moticon ReGo AG"),
+    (0x08af, "Polidea Sp. z o.o."),
+    (0x08b0, "Trivedi Advanced Technologies LLC"),
+    (0x08b1, "CORE|vision BV"),
+    (0x08b2, "PF SCHWEISSTECHNOLOGIE GMBH"),
+    (0x08b3, "IONIQ Skincare GmbH & Co. KG"),
+    (0x08b4, "Sengled Co., Ltd."),
+    (0x08b5, "TransferFi"),
+    (0x08b6, "Boehringer Ingelheim Vetmedica GmbH"),
+    (0x08b7, "ABB Inc"),
+    (0x08b8, "Check Technology Solutions LLC"),
+    (0x08b9, "U-Shin Ltd."),
+    (0x08ba, "HYPER ICE, INC."),
+    (0x08bb, "Tokai-rika co.,ltd."),
+    (0x08bc, "Prevayl Limited"),
+    (0x08bd, "bf1systems limited"),
+    (0x08be, "ubisys technologies GmbH"),
+    (0x08bf, "SIRC Co., Ltd."),
+    (0x08c0, "Accent Advanced Systems SLU"),
+    (0x08c1, "Rayden.Earth LTD"),
+    (0x08c2, "Lindinvent AB"),
+    (0x08c3, "CHIPOLO d.o.o."),
+    (0x08c4, "CellAssist, LLC"),
+    (0x08c5, "J. Wagner GmbH"),
+    (0x08c6, "Integra Optics Inc"),
+    (0x08c7, "Monadnock Systems Ltd."),
+    (0x08c8, "Liteboxer Technologies Inc."),
+    (0x08c9, "Noventa AG"),
+    (0x08ca, "Nubia Technology Co.,Ltd."),
+    (0x08cb, "JT INNOVATIONS LIMITED"),
+    (0x08cc, "TGM TECHNOLOGY CO., LTD."),
+    (0x08cd, "ifly"),
+    (0x08ce, "ZIMI CORPORATION"),
+    (0x08cf, "betternotstealmybike UG (with limited liability)"),
+    (0x08d0, "ESTOM Infotech Kft."),
+    (0x08d1, "Sensovium Inc."),
+    (0x08d2, "Virscient Limited"),
+    (0x08d3, "Novel Bits, LLC"),
+    (0x08d4, "ADATA Technology Co., LTD."),
+    (0x08d5, "KEYes"),
+    (0x08d6, "Nome Oy"),
+    (0x08d7, "Inovonics Corp"),
+    (0x08d8, "WARES"),
+    (0x08d9, "Pointr Labs Limited"),
+    (0x08da, "Miridia Technology Incorporated"),
+    (0x08db, "Tertium Technology"),
+    (0x08dc, "SHENZHEN AUKEY E BUSINESS CO., LTD"),
+    (0x08dd, "code-Q"),
+    (0x08de, "Tyco Electronics Corporation a TE Connectivity Ltd Company"),
+    (0x08df, "IRIS OHYAMA CO.,LTD."),
+    (0x08e0, "Philia Technology"),
+    (0x08e1, "KOZO KEIKAKU ENGINEERING Inc."),
+    (0x08e2, "Shenzhen Simo Technology co. LTD"),
+    (0x08e3, "Republic Wireless, Inc."),
+    (0x08e4, "Rashidov ltd"),
+    (0x08e5, "Crowd Connected Ltd"),
+    (0x08e6, "Eneso Tecnologia de Adaptacion S.L."),
+    (0x08e7, "Barrot Technology Limited"),
+    (0x08e8, "Naonext"),
+    (0x08e9, "Taiwan Intelligent Home Corp."),
+    (0x08ea, "COWBELL ENGINEERING CO.,LTD."),
+    (0x08eb, "Beijing Big Moment Technology Co., Ltd."),
+    (0x08ec, "Denso Corporation"),
+    (0x08ed, "IMI Hydronic Engineering International SA"),
+    (0x08ee, "ASKEY"),
+    (0x08ef, "Cumulus Digital Systems, Inc"),
+    (0x08f0, "Joovv, Inc."),
+    (0x08f1, "The L.S. Starrett Company"),
+    (0x08f2, "Microoled"),
+    (0x08f3, "PSP - Pauli Services & Products GmbH"),
+    (0x08f4, "Kodimo Technologies Company Limited"),
+    (0x08f5, "Tymtix Technologies Private Limited"),
+    (0x08f6, "Dermal Photonics Corporation"),
+    (0x08f7, "MTD Products Inc & Affiliates"),
+    (0x08f8, "instagrid GmbH"),
+    (0x08f9, "Spacelabs Medical Inc."),
+    (0x08fa, "Troo Corporation"),
+    (0x08fb, "Darkglass Electronics Oy"),
+    (0x08fc, "Hill-Rom"),
+    (0x08fd, "BioIntelliSense, Inc."),
+    (0x08fe, "Ketronixs Sdn Bhd"),
+    (0x08ff, "Plastimold Products, Inc"),
+    (0x0900, "Beijing Zizai Technology Co., LTD."),
+    (0x0901, "Lucimed"),
+    (0x0902, "TSC Auto-ID Technology Co., Ltd."),
+    (0x0903, "DATAMARS, Inc."),
+    (0x0904, "SUNCORPORATION"),
+    (0x0905, "Yandex Services AG"),
+    (0x0906, "Scope Logistical Solutions"),
+    (0x0907, "User Hello, LLC"),
+    (0x0908, "Pinpoint Innovations Limited"),
+    (0x0909, "70mai Co.,Ltd."),
+    (0x090a, "Zhuhai Hoksi Technology CO.,LTD"),
+    (0x090b, "EMBR labs, INC"),
+    (0x090c, "Radiawave Technologies Co.,Ltd."),
+    (0x090d, "IOT Invent GmbH"),
+    (0x090e, "OPTIMUSIOT TECH LLP"),
+    (0x090f, "VC Inc."),
+    (0x0910, "ASR Microelectronics (Shanghai) Co., Ltd."),
+    (0x0911, "Douglas Lighting Controls Inc."),
+    (0x0912, "Nerbio Medical Software Platforms Inc"),
+    (0x0913, "Braveheart Wireless, Inc."),
+    (0x0914, "INEO-SENSE"),
+    (0x0915, "Honda Motor Co., Ltd."),
+    (0x0916, "Ambient Sensors LLC"),
+    (0x0917, "ASR Microelectronics(ShenZhen)Co., Ltd."),
+    (0x0918, "Technosphere Labs Pvt. Ltd."),
+    (0x0919, "NO SMD LIMITED"),
+    (0x091a, "Albertronic BV"),
+    (0x091b, "Luminostics, Inc."),
+    (0x091c, "Oblamatik AG"),
+    (0x091d, "Innokind, Inc."),
+    (0x091e, "Melbot Studios, Sociedad Limitada"),
+    (0x091f, "Myzee Technology"),
+    (0x0920, "Omnisense Limited"),
+    (0x0921, "KAHA PTE. LTD."),
+    (0x0922, "Shanghai MXCHIP Information Technology Co., Ltd."),
+    (0x0923, "JSB TECH PTE LTD"),
+    (0x0924, "Fundacion Tecnalia Research and Innovation"),
+    (0x0925, "Yukai Engineering Inc."),
+    (0x0926, "Gooligum Technologies Pty Ltd"),
+    (0x0927, "ROOQ GmbH"),
+    (0x0928, "AiRISTA"),
+    (0x0929, "Qingdao Haier Technology Co., Ltd."),
+    (0x092a, "Sappl Verwaltungs- und Betriebs GmbH"),
+    (0x092b, "TekHome"),
+    (0x092c, "PCI Private Limited"),
+    (0x092d, "Leggett & Platt, Incorporated"),
+    (0x092e, "PS GmbH"),
+    (0x092f, "C.O.B.O. SpA"),
+    (0x0930, "James Walker RotaBolt Limited"),
+    (0x0931, "BREATHINGS Co., Ltd."),
+    (0x0932, "BarVision, LLC"),
+    (0x0933, "SRAM"),
+    (0x0934, "KiteSpring Inc."),
+    (0x0935, "Reconnect, Inc."),
+    (0x0936, "Elekon AG"),
+    (0x0937, "RealThingks GmbH"),
+    (0x0938, "Henway Technologies, LTD."),
+    (0x0939, "ASTEM Co.,Ltd."),
+    (0x093a, "LinkedSemi Microelectronics (Xiamen) Co., Ltd"),
+    (0x093b, "ENSESO LLC"),
+    (0x093c, "Xenoma Inc."),
+    (0x093d, "Adolf Wuerth GmbH & Co KG"),
+    (0x093e, "Catalyft Labs, Inc."),
+    (0x093f, "JEPICO Corporation"),
+    (0x0940, "Hero Workout GmbH"),
+    (0x0941, "Rivian Automotive, LLC"),
+    (0x0942, "TRANSSION HOLDINGS LIMITED"),
+    (0x0943, "Inovonics Corp."),
+    (0x0944, "Agitron d.o.o."),
+    (0x0945, "Globe (Jiangsu) Co., Ltd"),
+    (0x0946, "AMC International Alfa Metalcraft Corporation AG"),
+    (0x0947, "First Light Technologies Ltd."),
+    (0x0948, "Wearable Link Limited"),
+    (0x0949, "Metronom Health Europe"),
+    (0x094a, "Zwift, Inc."),
+    (0x094b, "Kindeva Drug Delivery L.P."),
+    (0x094c, "GimmiSys GmbH"),
+    (0x094d, "tkLABS INC."),
+    (0x094e, "PassiveBolt, Inc."),
+    (0x094f, "Limited Liability Company \"Mikrotikls\""),
+    (0x0950, "Capetech"),
+    (0x0951, "PPRS"),
+    (0x0952, "Apptricity Corporation"),
+    (0x0953, "LogiLube, LLC"),
+    (0x0954, "Julbo"),
+    (0x0955, "Breville Group"),
+    (0x0956, "Kerlink"),
+    (0x0957, "Ohsung Electronics"),
+    (0x0958, "ZTE Corporation"),
+    (0x0959, "HerdDogg, Inc"),
+    (0x095a, "Selekt Bilgisayar, lletisim Urunleri lnsaat Sanayi ve Ticaret Limited Sirketi"),
+    (0x095b, "Lismore Instruments Limited"),
+    (0x095c, "LogiLube, LLC"),
+    (0x095d, "ETC"),
+    (0x095e, "BioEchoNet inc."),
+    (0x095f, "NUANCE HEARING LTD"),
+    (0x0960, "Sena Technologies Inc."),
+    (0x0961, "Linkura AB"),
+    (0x0962, "GL Solutions K.K."),
+    (0x0963, "Moonbird BV"),
+    (0x0964, "Countrymate Technology Limited"),
+    (0x0965, "Asahi Kasei Corporation"),
+    (0x0966, "PointGuard, LLC"),
+    (0x0967, "Neo Materials and Consulting Inc."),
+    (0x0968, "Actev Motors, Inc."),
+    (0x0969, "Woan Technology (Shenzhen) Co., Ltd."),
+    (0x096a, "dricos, Inc."),
+    (0x096b, "Guide ID B.V."),
+    (0x096c, "9374-7319 Quebec inc"),
+    (0x096d, "Gunwerks, LLC"),
+    (0x096e, "Band Industries, inc."),
+    (0x096f, "Lund Motion Products, Inc."),
+    (0x0970, "IBA Dosimetry GmbH"),
+    (0x0971, "GA"),
+    (0x0972, "Closed Joint Stock Company \"Zavod Flometr\" (\"Zavod Flometr\" CJSC)"),
+    (0x0973, "Popit Oy"),
+    (0x0974, "ABEYE"),
+    (0x0975, "BlueIOT(Beijing) Technology Co.,Ltd"),
+    (0x0976, "Fauna Audio GmbH"),
+    (0x0977, "TOYOTA motor corporation"),
+    (0x0978, "ZifferEins GmbH & Co. KG"),
+    (0x0979, "BIOTRONIK SE & Co. KG"),
+    (0x097a, "CORE CORPORATION"),
+    (0x097b, "CTEK Sweden AB"),
+    (0x097c, "Thorley Industries, LLC"),
+    (0x097d, "CLB B.V."),
+    (0x097e, "SonicSensory Inc"),
+    (0x097f, "ISEMAR S.R.L."),
+    (0x0980, "DEKRA TESTING AND CERTIFICATION, S.A.U."),
+    (0x0981, "Bernard Krone Holding SE & Co.KG"),
+    (0x0982, "ELPRO-BUCHS AG"),
+    (0x0983, "Feedback Sports LLC"),
+    (0x0984, "TeraTron GmbH"),
+    (0x0985, "Lumos Health Inc."),
+    (0x0986, "Cello Hill, LLC"),
+    (0x0987, "TSE BRAKES, INC."),
+    (0x0988, "BHM-Tech Produktionsgesellschaft m.b.H"),
+    (0x0989, "WIKA Alexander Wiegand SE & Co.KG"),
+    (0x098a, "Biovigil"),
+    (0x098b, "Mequonic Engineering, S.L."),
+    (0x098c, "bGrid B.V."),
+    (0x098d, "C3-WIRELESS, LLC"),
+    (0x098e, "ADVEEZ"),
+    (0x098f, "Aktiebolaget Regin"),
+    (0x0990, "Anton Paar GmbH"),
+    (0x0991, "Telenor ASA"),
+    (0x0992, "Big Kaiser Precision Tooling Ltd"),
+    (0x0993, "Absolute Audio Labs B.V."),
+    (0x0994, "VT42 Pty Ltd"),
+    (0x0995, "Bronkhorst High-Tech B.V."),
+    (0x0996, "C. & E. Fein GmbH"),
+    (0x0997, "NextMind"),
+    (0x0998, "Pixie Dust Technologies, Inc."),
+    (0x0999, "eTactica ehf"),
+    (0x099a, "New Audio LLC"),
+    (0x099b, "Sendum Wireless Corporation"),
+    (0x099c, "deister electronic GmbH"),
+    (0x099d, "YKK AP Inc."),
+    (0x099e, "Step One Limited"),
+    (0x099f, "Koya Medical, Inc."),
+    (0x09a0, "Proof Diagnostics, Inc."),
+    (0x09a1, "VOS Systems, LLC"),
+    (0x09a2, "ENGAGENOW DATA SCIENCES PRIVATE LIMITED"),
+    (0x09a3, "ARDUINO SA"),
+    (0x09a4, "KUMHO ELECTRICS, INC"),
+    (0x09a5, "Security Enhancement Systems, LLC"),
+    (0x09a6, "BEIJING ELECTRIC VEHICLE CO.,LTD"),
+    (0x09a7, "Paybuddy ApS"),
+    (0x09a8, "KHN Solutions Inc"),
+    (0x09a9, "Nippon Ceramic Co.,Ltd."),
+    (0x09aa, "PHOTODYNAMIC INCORPORATED"),
+    (0x09ab, "DashLogic, Inc."),
+    (0x09ac, "Ambiq"),
+    (0x09ad, "Narhwall Inc."),
+    (0x09ae, "Pozyx NV"),
+    (0x09af, "ifLink Open Community"),
+    (0x09b0, "Deublin Company, LLC"),
+    (0x09b1, "BLINQY"),
+    (0x09b2, "DYPHI"),
+    (0x09b3, "BlueX Microelectronics Corp Ltd."),
+    (0x09b4, "PentaLock Aps."),
+    (0x09b5, "AUTEC Gesellschaft fuer Automationstechnik mbH"),
+    (0x09b6, "Pegasus Technologies, Inc."),
+    (0x09b7, "Bout Labs, LLC"),
+    (0x09b8, "PlayerData Limited"),
+    (0x09b9, "SAVOY ELECTRONIC LIGHTING"),
+    (0x09ba, "Elimo Engineering Ltd"),
+    (0x09bb, "SkyStream Corporation"),
+    (0x09bc, "Aerosens LLC"),
+    (0x09bd, "Centre Suisse d'Electronique et de Microtechnique SA"),
+    (0x09be, "Vessel Ltd."),
+    (0x09bf, "Span.IO, Inc."),
+    (0x09c0, "AnotherBrain inc."),
+    (0x09c1, "Rosewill"),
+    (0x09c2, "Universal Audio, Inc."),
+    (0x09c3, "JAPAN TOBACCO INC."),
+    (0x09c4, "UVISIO"),
+    (0x09c5, "HungYi Microelectronics Co.,Ltd."),
+    (0x09c6, "Honor Device Co., Ltd."),
+    (0x09c7, "Combustion, LLC"),
+    (0x09c8, "XUNTONG"),
+    (0x09c9, "CrowdGlow Ltd"),
+    (0x09ca, "Mobitrace"),
+    (0x09cb, "Hx Engineering, LLC"),
+    (0x09cc, "Senso4s d.o.o."),
+    (0x09cd, "Blyott"),
+    (0x09ce, "Julius Blum GmbH"),
+    (0x09cf, "BlueStreak IoT, LLC"),
+    (0x09d0, "Chess Wise B.V."),
+    (0x09d1, "ABLEPAY TECHNOLOGIES AS"),
+    (0x09d2, "Temperature Sensitive Solutions Systems Sweden AB"),
+    (0x09d3, "HeartHero, inc."),
+    (0x09d4, "ORBIS Inc."),
+    (0x09d5, "GEAR RADIO ELECTRONICS CORP."),
+    (0x09d6, "EAR TEKNIK ISITME VE ODIOMETRI CIHAZLARI SANAYI VE TICARET ANONIM SIRKETI"),
+    (0x09d7, "Coyotta"),
+    (0x09d8, "Synergy Tecnologia em Sistemas Ltda"),
+    (0x09d9, "VivoSensMedical GmbH"),
+    (0x09da, "Nagravision SA"),
+    (0x09db, "Bionic Avionics Inc."),
+    (0x09dc, "AON2 Ltd."),
+    (0x09dd, "Innoware Development AB"),
+    (0x09de, "JLD Technology Solutions, LLC"),
+    (0x09df, "Magnus Technology Sdn Bhd"),
+    (0x09e0, "Preddio Technologies Inc."),
+    (0x09e1, "Tag-N-Trac Inc"),
+    (0x09e2, "Wuhan Linptech Co.,Ltd."),
+    (0x09e3, "Friday Home Aps"),
+    (0x09e4, "CPS AS"),
+    (0x09e5, "Mobilogix"),
+    (0x09e6, "Masonite Corporation"),
+    (0x09e7, "Kabushikigaisha HANERON"),
+    (0x09e8, "Melange Systems Pvt. Ltd."),
+    (0x09e9, "LumenRadio AB"),
+    (0x09ea, "Athlos Oy"),
+    (0x09eb, "KEAN ELECTRONICS PTY LTD"),
+    (0x09ec, "Yukon advanced optics worldwide, UAB"),
+    (0x09ed, "Sibel Inc."),
+    (0x09ee, "OJMAR SA"),
+    (0x09ef, "Steinel Solutions AG"),
+    (0x09f0, "WatchGas B.V."),
+    (0x09f1, "OM Digital Solutions Corporation"),
+    (0x09f2, "Audeara Pty Ltd"),
+    (0x09f3, "Beijing Zero Zero Infinity Technology Co.,Ltd."),
+    (0x09f4, "Spectrum Technologies, Inc."),
+    (0x09f5, "OKI Electric Industry Co., Ltd"),
+    (0x09f6, "Mobile Action Technology Inc."),
+    (0x09f7, "SENSATEC Co., Ltd."),
+    (0x09f8, "R.O. S.R.L."),
+    (0x09f9, "Hangzhou Yaguan Technology Co. LTD"),
+    (0x09fa, "Listen Technologies Corporation"),
+    (0x09fb, "TOITU CO., LTD."),
+    (0x09fc, "Confidex"),
+    (0x09fd, "Keep Technologies, Inc."),
+    (0x09fe, "Lichtvision Engineering GmbH"),
+    (0x09ff, "AIRSTAR"),
+    (0x0a00, "Ampler Bikes OU"),
+    (0x0a01, "Cleveron AS"),
+    (0x0a02, "Ayxon-Dynamics GmbH"),
+    (0x0a03, "donutrobotics Co., Ltd."),
+    (0x0a04, "Flosonics Medical"),
+    (0x0a05, "Southwire Company, LLC"),
+    (0x0a06, "Shanghai wuqi microelectronics Co.,Ltd"),
+    (0x0a07, "Reflow Pty Ltd"),
+    (0x0a08, "Oras Oy"),
+    (0x0a09, "ECCT"),
+    (0x0a0a, "Volan Technology Inc."),
+    (0x0a0b, "SIANA Systems"),
+    (0x0a0c, "Shanghai Yidian Intelligent Technology Co., Ltd."),
+    (0x0a0d, "Blue Peacock GmbH"),
+    (0x0a0e, "Roland Corporation"),
+    (0x0a0f, "LIXIL Corporation"),
+    (0x0a10, "SUBARU Corporation"),
+    (0x0a11, "Sensolus"),
+    (0x0a12, "Dyson Technology Limited"),
+    (0x0a13, "Tec4med LifeScience GmbH"),
+    (0x0a14, "CROXEL, INC."),
+    (0x0a15, "Syng Inc"),
+    (0x0a16, "RIDE VISION LTD"),
+    (0x0a17, "Plume Design Inc"),
+    (0x0a18, "Cambridge Animal Technologies Ltd"),
+    (0x0a19, "Maxell, Ltd."),
+    (0x0a1a, "Link Labs, Inc."),
+    (0x0a1b, "Embrava Pty Ltd"),
+    (0x0a1c, "INPEAK S.C."),
+    (0x0a1d, "API-K"),
+    (0x0a1e, "CombiQ AB"),
+    (0x0a1f, "DeVilbiss Healthcare LLC"),
+    (0x0a20, "Jiangxi Innotech Technology Co., Ltd"),
+    (0x0a21, "Apollogic Sp. z o.o."),
+    (0x0a22, "DAIICHIKOSHO CO., LTD."),
+    (0x0a23, "BIXOLON CO.,LTD"),
+    (0x0a24, "Atmosic Technologies, Inc."),
+    (0x0a25, "Eran Financial Services LLC"),
+    (0x0a26, "Louis Vuitton"),
+    (0x0a27, "AYU DEVICES PRIVATE LIMITED"),
+    (0x0a28, "NanoFlex"),
+    (0x0a29, "Worthcloud Technology Co.,Ltd"),
+    (0x0a2a, "Yamaha Corporation"),
+    (0x0a2b, "PaceBait IVS"),
+    (0x0a2c, "Shenzhen H&T Intelligent Control Co., Ltd"),
+    (0x0a2d, "Shenzhen Feasycom Technology Co., Ltd."),
+    (0x0a2e, "Zuma Array Limited"),
+    (0x0a2f, "Instamic, Inc."),
+    (0x0a30, "Air-Weigh"),
+    (0x0a31, "Nevro Corp."),
+    (0x0a32, "Pinnacle Technology, Inc."),
+    (0x0a33, "WMF AG"),
+    (0x0a34, "Luxer Corporation"),
+    (0x0a35, "safectory GmbH"),
+    (0x0a36, "NGK SPARK PLUG CO., LTD."),
+    (0x0a37, "2587702 Ontario Inc."),
+    (0x0a38, "Bouffalo Lab (Nanjing)., Ltd."),
+    (0x0a39, "BLUETICKETING SRL"),
+    (0x0a3a, "Incotex Co. Ltd."),
+    (0x0a3b, "Galileo Technology Limited"),
+    (0x0a3c, "Siteco GmbH"),
+    (0x0a3d, "DELABIE"),
+    (0x0a3e, "Hefei Yunlian Semiconductor Co., Ltd"),
+    (0x0a3f, "Shenzhen Yopeak Optoelectronics Technology Co., Ltd."),
+    (0x0a40, "GEWISS S.p.A."),
+    (0x0a41, "OPEX Corporation"),
+    (0x0a42, "Motionalysis, Inc."),
+    (0x0a43, "Busch Systems International Inc."),
+    (0x0a44, "Novidan, Inc."),
+    (0x0a45, "3SI Security Systems, Inc"),
+    (0x0a46, "Beijing HC-Infinite Technology Limited"),
+    (0x0a47, "The Wand Company Ltd"),
+    (0x0a48, "JRC Mobility Inc."),
+    (0x0a49, "Venture Research Inc."),
+    (0x0a4a, "Map Large, Inc."),
+    (0x0a4b, "MistyWest Energy and Transport Ltd."),
+    (0x0a4c, "SiFli Technologies (shanghai) Inc."),
+    (0x0a4d, "Lockn Technologies Private Limited"),
+    (0x0a4e, "Toytec Corporation"),
+    (0x0a4f, "VANMOOF Global Holding B.V."),
+    (0x0a50, "Nextscape Inc."),
+    (0x0a51, "CSIRO"),
+    (0x0a52, "Follow Sense Europe B.V."),
+    (0x0a53, "KKM COMPANY LIMITED"),
+    (0x0a54, "SQL Technologies Corp."),
+    (0x0a55, "Inugo Systems Limited"),
+    (0x0a56, "ambie"),
+    (0x0a57, "Meizhou Guo Wei Electronics Co., Ltd"),
+    (0x0a58, "Indigo Diabetes"),
+    (0x0a59, "TourBuilt, LLC"),
+    (0x0a5a, "Sontheim Industrie Elektronik GmbH"),
+    (0x0a5b, "LEGIC Identsystems AG"),
+    (0x0a5c, "Innovative Design Labs Inc."),
+    (0x0a5d, "MG Energy Systems B.V."),
+    (0x0a5e, "LaceClips llc"),
+    (0x0a5f, "stryker"),
+    (0x0a60, "DATANG SEMICONDUCTOR TECHNOLOGY CO.,LTD"),
+    (0x0a61, "Smart Parks B.V."),
+    (0x0a62, "MOKO TECHNOLOGY Ltd"),
+    (0x0a63, "Gremsy JSC"),
+    (0x0a64, "Geopal system A/S"),
+    (0x0a65, "Lytx, INC."),
+    (0x0a66, "JUSTMORPH PTE. LTD."),
+    (0x0a67, "Beijing SuperHexa Century Technology CO. Ltd"),
+    (0x0a68, "Focus Ingenieria SRL"),
+    (0x0a69, "HAPPIEST BABY, INC."),
+    (0x0a6a, "Scribble Design Inc."),
+    (0x0a6b, "Olympic Ophthalmics, Inc."),
+    (0x0a6c, "Pokkels"),
+    (0x0a6d, "KUUKANJYOKIN Co.,Ltd."),
+    (0x0a6e, "Pac Sane Limited"),
+    (0x0a6f, "Warner Bros."),
+    (0x0a70, "Ooma"),
+    (0x0a71, "Senquip Pty Ltd"),
+    (0x0a72, "Jumo GmbH & Co. KG"),
+    (0x0a73, "Innohome Oy"),
+    (0x0a74, "MICROSON S.A."),
+    (0x0a75, "Delta Cycle Corporation"),
+    (0x0a76, "Synaptics Incorporated"),
+    (0x0a77, "JMD PACIFIC PTE. LTD."),
+    (0x0a78, "Shenzhen Sunricher Technology Limited"),
+    (0x0a79, "Webasto SE"),
+    (0x0a7a, "Emlid Limited"),
+    (0x0a7b, "UniqAir Oy"),
+    (0x0a7c, "WAFERLOCK"),
+    (0x0a7d, "Freedman Electronics Pty Ltd"),
+    (0x0a7e, "Keba AG"),
+    (0x0a7f, "Intuity Medical"),
+    (0x0a80, "Cleer Limited"),
+    (0x0a81, "Universal Biosensors Pty Ltd"),
+    (0x0a82, "Corsair"),
+    (0x0a83, "Rivata, Inc."),
+    (0x0a84, "Greennote Inc,"),
+    (0x0a85, "Snowball Technology Co., Ltd."),
+    (0x0a86, "ALIZENT International"),
+    (0x0a87, "Shanghai Smart System Technology Co., Ltd"),
+    (0x0a88, "PSA Peugeot Citroen"),
+    (0x0a89, "SES-Imagotag"),
+    (0x0a8a, "HAINBUCH SPANNENDE TECHNIK"),
+    (0x0a8b, "SANlight GmbH"),
+    (0x0a8c, "DelpSys, s.r.o."),
+    (0x0a8d, "JCM TECHNOLOGIES S.A."),
+    (0x0a8e, "Perfect Company"),
+    (0x0a8f, "TOTO LTD."),
+    (0x0a90, "Shenzhen Grandsun Electronic Co.,Ltd."),
+    (0x0a91, "Monarch International Inc."),
+    (0x0a92, "Carestream Dental LLC"),
+    (0x0a93, "GiPStech S.r.l."),
+    (0x0a94, "OOBIK Inc."),
+    (0x0a95, "Pamex Inc."),
+    (0x0a96, "Lightricity Ltd"),
+    (0x0a97, "SensTek"),
+    (0x0a98, "Foil, Inc."),
+    (0x0a99, "Shanghai high-flying electronics technology Co.,Ltd"),
+    (0x0a9a, "TEMKIN ASSOCIATES, LLC"),
+    (0x0a9b, "Eello LLC"),
+    (0x0a9c, "Xi'an Fengyu Information Technology Co., Ltd."),
+    (0x0a9d, "Canon Finetech Nisca Inc."),
+    (0x0a9e, "LifePlus, Inc."),
+    (0x0a9f, "ista International GmbH"),
+    (0x0aa0, "Loy Tec electronics GmbH"),
+    (0x0aa1, "LINCOGN TECHNOLOGY CO. LIMITED"),
+    (0x0aa2, "Care Bloom, LLC"),
+    (0x0aa3, "DIC Corporation"),
+    (0x0aa4, "FAZEPRO LLC"),
+    (0x0aa5, "Shenzhen Uascent Technology Co., Ltd"),
+    (0x0aa6, "Realityworks, inc."),
+    (0x0aa7, "Urbanista AB"),
+    (0x0aa8, "Zencontrol Pty Ltd"),
+    (0x0aa9, "Mrinq Technologies LLC"),
+    (0x0aaa, "Computime International Ltd"),
+    (0x0aab, "Anhui Listenai Co"),
+    (0x0aac, "OSM HK Limited"),
+    (0x0aad, "Adevo Consulting AB"),
+    (0x0aae, "PS Engineering, Inc."),
+    (0x0aaf, "AIAIAI ApS"),
+    (0x0ab0, "Visiontronic s.r.o."),
+    (0x0ab1, "InVue Security Products Inc"),
+    (0x0ab2, "TouchTronics, Inc."),
+    (0x0ab3, "INNER RANGE PTY. LTD."),
+    (0x0ab4, "Ellenby Technologies, Inc."),
+    (0x0ab5, "Elstat Ltd [ Formerly Elstat Electronics Ltd.]"),
+    (0x0ab6, "Xenter, Inc."),
+    (0x0ab7, "LogTag North America Inc."),
+    (0x0ab8, "Sens.ai Incorporated"),
+    (0x0ab9, "STL"),
+    (0x0aba, "Open Bionics Ltd."),
+    (0x0abb, "R-DAS, s.r.o."),
+    (0x0abc, "KCCS Mobile Engineering Co., Ltd."),
+    (0x0abd, "Inventas AS"),
+    (0x0abe, "Robkoo Information & Technologies Co., Ltd."),
+    (0x0abf, "PAUL HARTMANN AG"),
+    (0x0ac0, "Omni-ID USA, INC."),
+    (0x0ac1, "Shenzhen Jingxun Technology Co., Ltd."),
+    (0x0ac2, "RealMega Microelectronics technology (Shanghai) Co. Ltd."),
+    (0x0ac3, "Kenzen, Inc."),
+    (0x0ac4, "CODIUM"),
+    (0x0ac5, "Flexoptix GmbH"),
+    (0x0ac6, "Barnes Group Inc."),
+    (0x0ac7, "Chengdu Aich Technology Co.,Ltd"),
+    (0x0ac8, "Keepin Co., Ltd."),
+    (0x0ac9, "Swedlock AB"),
+    (0x0aca, "Shenzhen CoolKit Technology Co., Ltd"),
+    (0x0acb, "ise Individuelle Software und Elektronik GmbH"),
+    (0x0acc, "Nuvoton"),
+    (0x0acd, "Visuallex Sport International Limited"),
+    (0x0ace, "KOBATA GAUGE MFG. CO., LTD."),
+    (0x0acf, "CACI Technologies"),
+    (0x0ad0, "Nordic Strong ApS"),
+    (0x0ad1, "EAGLE KINGDOM TECHNOLOGIES LIMITED"),
+    (0x0ad2, "Lautsprecher Teufel GmbH"),
+    (0x0ad3, "SSV Software Systems GmbH"),
+    (0x0ad4, "Zhuhai Pantum Electronisc Co., Ltd"),
+    (0x0ad5, "Streamit B.V."),
+    (0x0ad6, "nymea GmbH"),
+    (0x0ad7, "AL-KO Geraete GmbH"),
+    (0x0ad8, "Franz Kaldewei GmbH&Co KG"),
+    (0x0ad9, "Shenzhen Aimore. Co.,Ltd"),
+    (0x0ada, "Codefabrik GmbH"),
+    (0x0adb, "Reelables, Inc."),
+    (0x0adc, "Duravit AG"),
+    (0x0add, "Boss Audio"),
+    (0x0ade, "Vocera Communications, Inc."),
+    (0x0adf, "Douglas Dynamics L.L.C."),
+    (0x0ae0, "Viceroy Devices Corporation"),
+    (0x0ae1, "ChengDu ForThink Technology Co., Ltd."),
+    (0x0ae2, "IMATRIX SYSTEMS, INC."),
+    (0x0ae3, "GlobalMed"),
+    (0x0ae4, "DALI Alliance"),
+    (0x0ae5, "unu GmbH"),
+    (0x0ae6, "Hexology"),
+    (0x0ae7, "Sunplus Technology Co., Ltd."),
+    (0x0ae8, "LEVEL, s.r.o."),
+    (0x0ae9, "FLIR Systems AB"),
+    (0x0aea, "Borda Technology"),
+    (0x0aeb, "Square, Inc."),
+    (0x0aec, "FUTEK ADVANCED SENSOR TECHNOLOGY, INC"),
+    (0x0aed, "Saxonar GmbH"),
+    (0x0aee, "Velentium, LLC"),
+    (0x0aef, "GLP German Light Products GmbH"),
+    (0x0af0, "Leupold & Stevens, Inc."),
+    (0x0af1, "CRADERS,CO.,LTD"),
+    (0x0af2, "Shanghai All Link Microelectronics Co.,Ltd"),
+    (0x0af3, "701x Inc."),
+    (0x0af4, "Radioworks Microelectronics PTY LTD"),
+    (0x0af5, "Unitech Electronic Inc."),
+    (0x0af6, "AMETEK, Inc."),
+    (0x0af7, "Irdeto"),
+    (0x0af8, "First Design System Inc."),
+    (0x0af9, "Unisto AG"),
+    (0x0afa, "Chengdu Ambit Technology Co., Ltd."),
+    (0x0afb, "SMT ELEKTRONIK GmbH"),
+    (0x0afc, "Cerebrum Sensor Technologies Inc."),
+    (0x0afd, "Weber Sensors, LLC"),
+    (0x0afe, "Earda Technologies Co.,Ltd"),
+    (0x0aff, "FUSEAWARE LIMITED"),
+    (0x0b00, "Flaircomm Microelectronics Inc."),
+    (0x0b01, "RESIDEO TECHNOLOGIES, INC."),
+    (0x0b02, "IORA Technology Development Ltd. Sti."),
+    (0x0b03, "Precision Triathlon Systems Limited"),
+    (0x0b04, "I-PERCUT"),
+    (0x0b05, "Marquardt GmbH"),
+    (0x0b06, "FAZUA GmbH"),
+    (0x0b07, "Workaround Gmbh"),
+    (0x0b08, "Shenzhen Qianfenyi Intelligent Technology Co., LTD"),
+    (0x0b09, "soonisys"),
+    (0x0b0a, "Belun Technology Company Limited"),
+    (0x0b0b, "Sanistaal A/S"),
+    (0x0b0c, "BluPeak"),
+    (0x0b0d, "SANYO DENKO Co.,Ltd."),
+    (0x0b0e, "Honda Lock Mfg. Co.,Ltd."),
+    (0x0b0f, "B.E.A. S.A."),
+    (0x0b10, "Alfa Laval Corporate AB"),
+    (0x0b11, "ThermoWorks, Inc."),
+    (0x0b12, "ToughBuilt Industries LLC"),
+    (0x0b13, "IOTOOLS"),
+    (0x0b14, "Olumee"),
+    (0x0b15, "NAOS JAPAN K.K."),
+    (0x0b16, "Guard RFID Solutions Inc."),
+    (0x0b17, "SIG SAUER, INC."),
+    (0x0b18, "DECATHLON SE"),
+    (0x0b19, "WBS PROJECT H PTY LTD"),
+    (0x0b1a, "Roca Sanitario, S.A."),
+    (0x0b1b, "Enerpac Tool Group Corp."),
+    (0x0b1c, "Nanoleq AG"),
+    (0x0b1d, "Accelerated Systems"),
+    (0x0b1e, "PB INC."),
+    (0x0b1f, "Beijing ESWIN Computing Technology Co., Ltd."),
+    (0x0b20, "TKH Security B.V."),
+    (0x0b21, "ams AG"),
+    (0xffff, "Bluetooth SIG Specification Reserved Default Vendor ID for Remote Devices Without Device ID Service Record."),
+];
+
+/// GAP appearance values.
+pub(super) const APPEARANCES: &[(u16, &str)] = &[
+    (0x0000, "Unknown"),
+    (0x0040, "Phone"),
+    (0x0080, "Computer"),
+    (0x0081, "Computer: Desktop Workstation"),
+    (0x0082, "Computer: Server-class Computer"),
+    (0x0083, "Computer: Laptop"),
+    (0x0084, "Computer: Handheld PC/PDA (clamshell)"),
+    (0x0085, "Computer: Palm-size PC/PDA"),
+    (0x0086, "Computer: Wearable computer (watch size)"),
+    (0x0087, "Computer: Tablet"),
+    (0x0088, "Computer: Docking Station"),
+    (0x0089, "Computer: All in One"),
+    (0x008a, "Computer: Blade Server"),
+    (0x008b, "Computer: Convertible"),
+    (0x008c, "Computer: Detachable"),
+    (0x008d, "Computer: IoT Gateway"),
+    (0x008e, "Computer: Mini PC"),
+    (0x008f, "Computer: Stick PC"),
+    (0x00c0, "Watch"),
+    (0x00c1, "Watch: Sports Watch"),
+    (0x00c2, "Watch: Smartwatch"),
+    (0x0100, "Clock"),
+    (0x0140, "Display"),
+    (0x0180, "Remote Control"),
+    (0x01c0, "Eye-glasses"),
+    (0x0200, "Tag"),
+    (0x0240, "Keyring"),
+    (0x0280, "Media Player"),
+    (0x02c0, "Barcode Scanner"),
+    (0x0300, "Thermometer"),
+    (0x0301, "Thermometer: Ear Thermometer"),
+    (0x0340, "Heart Rate Sensor"),
+    (0x0341, "Heart Rate Sensor: Heart Rate Belt"),
+    (0x0380, "Blood Pressure"),
+    (0x0381, "Blood Pressure: Arm Blood Pressure"),
+    (0x0382, "Blood Pressure: Wrist Blood Pressure"),
+    (0x03c0, "Human Interface Device"),
+    (0x03c1, "Human Interface Device: Keyboard"),
+    (0x03c2, "Human Interface Device: Mouse"),
+    (0x03c3, "Human Interface Device: Joystick"),
+    (0x03c4, "Human Interface Device: Gamepad"),
+    (0x03c5, "Human Interface Device: Digitizer Tablet"),
+    (0x03c6, "Human Interface Device: Card Reader"),
+    (0x03c7, "Human Interface Device: Digital Pen"),
+    (0x03c8, "Human Interface Device: Barcode Scanner"),
+    (0x03c9, "Human Interface Device: Touchpad"),
+    (0x03ca, "Human Interface Device: Presentation Remote"),
+    (0x0400, "Glucose Meter"),
+    (0x0440, "Running Walking Sensor"),
+    (0x0441, "Running Walking Sensor: In-Shoe Running Walking Sensor"),
+    (0x0442, "Running Walking Sensor: On-Shoe Running Walking Sensor"),
+    (0x0443, "Running Walking Sensor: On-Hip Running Walking Sensor"),
+    (0x0480, "Cycling"),
+    (0x0481, "Cycling: Cycling Computer"),
+    (0x0482, "Cycling: Speed Sensor"),
+    (0x0483, "Cycling: Cadence Sensor"),
+    (0x0484, "Cycling: Power Sensor"),
+    (0x0485, "Cycling: Speed and Cadence Sensor"),
+    (0x04c0, "Control Device"),
+    (0x04c1, "Control Device: Switch"),
+    (0x04c2, "Control Device: Multi-switch"),
+    (0x04c3, "Control Device: Button"),
+    (0x04c4, "Control Device: Slider"),
+    (0x04c5, "Control Device: Rotary Switch"),
+    (0x04c6, "Control Device: Touch Panel"),
+    (0x04c7, "Control Device: Single Switch"),
+    (0x04c8, "Control Device: Double Switch"),
+    (0x04c9, "Control Device: Triple Switch"),
+    (0x04ca, "Control Device: Battery Switch"),
+    (0x04cb, "Control Device: Energy Harvesting Switch"),
+    (0x04cc, "Control Device: Push Button"),
+    (0x04cd, "Control Device: Dial"),
+    (0x0500, "Network Device"),
+    (0x0501, "Network Device: Access Point"),
+    (0x0502, "Network Device: Mesh Device"),
+    (0x0503, "Network Device: Mesh Network Proxy"),
+    (0x0540, "Sensor"),
+    (0x0541, "Sensor: Motion Sensor"),
+    (0x0542, "Sensor: Air quality Sensor"),
+    (0x0543, "Sensor: Temperature Sensor"),
+    (0x0544, "Sensor: Humidity Sensor"),
+    (0x0545, "Sensor: Leak Sensor"),
+    (0x0546, "Sensor: Smoke Sensor"),
+    (0x0547, "Sensor: Occupancy Sensor"),
+    (0x0548, "Sensor: Contact Sensor"),
+    (0x0549, "Sensor: Carbon Monoxide Sensor"),
+    (0x054a, "Sensor: Carbon Dioxide Sensor"),
+    (0x054b, "Sensor: Ambient Light Sensor"),
+    (0x054c, "Sensor: Energy Sensor"),
+    (0x054d, "Sensor: Color Light Sensor"),
+    (0x054e, "Sensor: Rain Sensor"),
+    (0x054f, "Sensor: Fire Sensor"),
+    (0x0550, "Sensor: Wind Sensor"),
+    (0x0551, "Sensor: Proximity Sensor"),
+    (0x0552, "Sensor: Multi-Sensor"),
+    (0x0553, "Sensor: Flush Mounted Sensor"),
+    (0x0554, "Sensor: Ceiling Mounted Sensor"),
+    (0x0555, "Sensor: Wall Mounted Sensor"),
+    (0x0556, "Sensor: Multisensor"),
+    (0x0557, "Sensor: Energy Meter"),
+    (0x0558, "Sensor: Flame Detector"),
+    (0x0559, "Sensor: Vehicle Tire Pressure Sensor"),
+    (0x0580, "Light Fixtures"),
+    (0x0581, "Light Fixtures: Wall Light"),
+    (0x0582, "Light Fixtures: Ceiling Light"),
+    (0x0583, "Light Fixtures: Floor Light"),
+    (0x0584, "Light Fixtures: Cabinet Light"),
+    (0x0585, "Light Fixtures: Desk Light"),
+    (0x0586, "Light Fixtures: Troffer Light"),
+    (0x0587, "Light Fixtures: Pendant Light"),
+    (0x0588, "Light Fixtures: In-ground Light"),
+    (0x0589, "Light Fixtures: Flood Light"),
+    (0x058a, "Light Fixtures: Underwater Light"),
+    (0x058b, "Light Fixtures: Bollard with Light"),
+    (0x058c, "Light Fixtures: Pathway Light"),
+    (0x058d, "Light Fixtures: Garden Light"),
+    (0x058e, "Light Fixtures: Pole-top Light"),
+    (0x058f, "Light Fixtures: Spotlight"),
+    (0x0590, "Light Fixtures: Linear Light"),
+    (0x0591, "Light Fixtures: Street Light"),
+    (0x0592, "Light Fixtures: Shelves Light"),
+    (0x0593, "Light Fixtures: Bay Light"),
+    (0x0594, "Light Fixtures: Emergency Exit Light"),
+    (0x0595, "Light Fixtures: Light Controller"),
+    (0x0596, "Light Fixtures: Light Driver"),
+    (0x0597, "Light Fixtures: Bulb"),
+    (0x0598, "Light Fixtures: Low-bay Light"),
+    (0x0599, "Light Fixtures: High-bay Light"),
+    (0x05c0, "Fan"),
+    (0x05c1, "Fan: Ceiling Fan"),
+    (0x05c2, "Fan: Axial Fan"),
+    (0x05c3, "Fan: Exhaust Fan"),
+    (0x05c4, "Fan: Pedestal Fan"),
+    (0x05c5, "Fan: Desk Fan"),
+    (0x05c6, "Fan: Wall Fan"),
+    (0x0600, "HVAC"),
+    (0x0601, "HVAC: Thermostat"),
+    (0x0602, "HVAC: Humidifier"),
+    (0x0603, "HVAC: De-humidifier"),
+    (0x0604, "HVAC: Heater"),
+    (0x0605, "HVAC: Radiator"),
+    (0x0606, "HVAC: Boiler"),
+    (0x0607, "HVAC: Heat Pump"),
+    (0x0608, "HVAC: Infrared Heater"),
+    (0x0609, "HVAC: Radiant Panel Heater"),
+    (0x060a, "HVAC: Fan Heater"),
+    (0x060b, "HVAC: Air Curtain"),
+    (0x0640, "Air Conditioning"),
+    (0x0680, "Humidifier"),
+    (0x06c0, "Heating"),
+    (0x06c1, "Heating: Radiator"),
+    (0x06c2, "Heating: Boiler"),
+    (0x06c3, "Heating: Heat Pump"),
+    (0x06c4, "Heating: Infrared Heater"),
+    (0x06c5, "Heating: Radiant Panel Heater"),
+    (0x06c6, "Heating: Fan Heater"),
+    (0x06c7, "Heating: Air Curtain"),
+    (0x0700, "Access Control"),
+    (0x0701, "Access Control: Access Door"),
+    (0x0702, "Access Control: Garage Door"),
+    (0x0703, "Access Control: Emergency Exit Door"),
+    (0x0704, "Access Control: Access Lock"),
+    (0x0705, "Access Control: Elevator"),
+    (0x0706, "Access Control: Window"),
+    (0x0707, "Access Control: Entrance Gate"),
+    (0x0708, "Access Control: Door Lock"),
+    (0x0709, "Access Control: Locker"),
+    (0x0740, "Motorized Device"),
+    (0x0741, "Motorized Device: Motorized Gate"),
+    (0x0742, "Motorized Device: Awning"),
+    (0x0743, "Motorized Device: Blinds or Shades"),
+    (0x0744, "Motorized Device: Curtains"),
+    (0x0745, "Motorized Device: Screen"),
+    (0x0780, "Power Device"),
+    (0x0781, "Power Device: Power Outlet"),
+    (0x0782, "Power Device: Power Strip"),
+    (0x0783, "Power Device: Plug"),
+    (0x0784, "Power Device: Power Supply"),
+    (0x0785, "Power Device: LED Driver"),
+    (0x0786, "Power Device: Fluorescent Lamp Gear"),
+    (0x0787, "Power Device: HID Lamp Gear"),
+    (0x0788, "Power Device: Charge Case"),
+    (0x0789, "Power Device: Power Bank"),
+    (0x07c0, "Light Source"),
+    (0x07c1, "Light Source: Incandescent Light Bulb"),
+    (0x07c2, "Light Source: LED Lamp"),
+    (0x07c3, "Light Source: HID Lamp"),
+    (0x07c4, "Light Source: Fluorescent Lamp"),
+    (0x07c5, "Light Source: LED Array"),
+    (0x07c6, "Light Source: Multi-Color LED Array"),
+    (0x07c7, "Light Source: Low voltage halogen"),
+    (0x07c8, "Light Source: Organic light emitting diode (OLED)"),
+    (0x0800, "Window Covering"),
+    (0x0801, "Window Covering: Window Shades"),
+    (0x0802, "Window Covering: Window Blinds"),
+    (0x0803, "Window Covering: Window Awning"),
+    (0x0804, "Window Covering: Window Curtain"),
+    (0x0805, "Window Covering: Exterior Shutter"),
+    (0x0806, "Window Covering: Exterior Screen"),
+    (0x0840, "Audio Sink"),
+    (0x0841, "Audio Sink: Standalone Speaker"),
+    (0x0842, "Audio Sink: Soundbar"),
+    (0x0843, "Audio Sink: Bookshelf Speaker"),
+    (0x0844, "Audio Sink: Standmounted Speaker"),
+    (0x0845, "Audio Sink: Speakerphone"),
+    (0x0880, "Audio Source"),
+    (0x0881, "Audio Source: Microphone"),
+    (0x0882, "Audio Source: Alarm"),
+    (0x0883, "Audio Source: Bell"),
+    (0x0884, "Audio Source: Horn"),
+    (0x0885, "Audio Source: Broadcasting Device"),
+    (0x0886, "Audio Source: Service Desk"),
+    (0x0887, "Audio Source: Kiosk"),
+    (0x0888, "Audio Source: Broadcasting Room"),
+    (0x0889, "Audio Source: Auditorium"),
+    (0x08c0, "Motorized Vehicle"),
+    (0x08c1, "Motorized Vehicle: Car"),
+    (0x08c2, "Motorized Vehicle: Large Goods Vehicle"),
+    (0x08c3, "Motorized Vehicle: 2-Wheeled Vehicle"),
+    (0x08c4, "Motorized Vehicle: Motorbike"),
+    (0x08c5, "Motorized Vehicle: Scooter"),
+    (0x08c6, "Motorized Vehicle: Moped"),
+    (0x08c7, "Motorized Vehicle: 3-Wheeled Vehicle"),
+    (0x08c8, "Motorized Vehicle: Light Vehicle"),
+    (0x08c9, "Motorized Vehicle: Quad Bike"),
+    (0x08ca, "Motorized Vehicle: Minibus"),
+    (0x08cb, "Motorized Vehicle: Bus"),
+    (0x08cc, "Motorized Vehicle: Trolley"),
+    (0x08cd, "Motorized Vehicle: Agricultural Vehicle"),
+    (0x08ce, "Motorized Vehicle: Camper / Caravan"),
+    (0x08cf, "Motorized Vehicle: Recreational Vehicle / Motor Home"),
+    (0x0900, "Domestic Appliance"),
+    (0x0901, "Domestic Appliance: Refrigerator"),
+    (0x0902, "Domestic Appliance: Freezer"),
+    (0x0903, "Domestic Appliance: Oven"),
+    (0x0904, "Domestic Appliance: Microwave"),
+    (0x0905, "Domestic Appliance: Toaster"),
+    (0x0906, "Domestic Appliance: Washing Machine"),
+    (0x0907, "Domestic Appliance: Dryer"),
+    (0x0908, "Domestic Appliance: Coffee maker"),
+    (0x0909, "Domestic Appliance: Clothes iron"),
+    (0x090a, "Domestic Appliance: Curling iron"),
+    (0x090b, "Domestic Appliance: Hair dryer"),
+    (0x090c, "Domestic Appliance: Vacuum cleaner"),
+    (0x090d, "Domestic Appliance: Robotic vacuum cleaner"),
+    (0x090e, "Domestic Appliance: Rice cooker"),
+    (0x090f, "Domestic Appliance: Clothes steamer"),
+    (0x0940, "Wearable Audio Device"),
+    (0x0941, "Wearable Audio Device: Earbud"),
+    (0x0942, "Wearable Audio Device: Headset"),
+    (0x0943, "Wearable Audio Device: Headphones"),
+    (0x0944, "Wearable Audio Device: Neck Band"),
+    (0x0945, "Wearable Audio Device: Left Earbud"),
+    (0x0946, "Wearable Audio Device: Right Earbud"),
+    (0x0980, "Aircraft"),
+    (0x0981, "Aircraft: Light Aircraft"),
+    (0x0982, "Aircraft: Microlight"),
+    (0x0983, "Aircraft: Paraglider"),
+    (0x0984, "Aircraft: Large Passenger Aircraft"),
+    (0x09c0, "AV Equipment"),
+    (0x09c1, "AV Equipment: Amplifier"),
+    (0x09c2, "AV Equipment: Receiver"),
+    (0x09c3, "AV Equipment: Radio"),
+    (0x09c4, "AV Equipment: Tuner"),
+    (0x09c5, "AV Equipment: Turntable"),
+    (0x09c6, "AV Equipment: CD Player"),
+    (0x09c7, "AV Equipment: DVD Player"),
+    (0x09c8, "AV Equipment: Bluray Player"),
+    (0x09c9, "AV Equipment: Optical Disc Player"),
+    (0x09ca, "AV Equipment: Set-Top Box"),
+    (0x0a00, "Display Equipment"),
+    (0x0a01, "Display Equipment: Television"),
+    (0x0a02, "Display Equipment: Monitor"),
+    (0x0a03, "Display Equipment: Projector"),
+    (0x0a40, "Hearing aid"),
+    (0x0a41, "Hearing aid: In-ear hearing aid"),
+    (0x0a42, "Hearing aid: Behind-ear hearing aid"),
+    (0x0a43, "Hearing aid: Cochlear Implant"),
+    (0x0a80, "Gaming"),
+    (0x0a81, "Gaming: Home Video Game Console"),
+    (0x0a82, "Gaming: Portable handheld console"),
+    (0x0ac0, "Signage"),
+    (0x0ac1, "Signage: Digital Signage"),
+    (0x0ac2, "Signage: Electronic Label"),
+    (0x0c40, "Pulse Oximeter"),
+    (0x0c41, "Pulse Oximeter: Fingertip Pulse Oximeter"),
+    (0x0c42, "Pulse Oximeter: Wrist Worn Pulse Oximeter"),
+    (0x0c80, "Weight Scale"),
+    (0x0cc0, "Personal Mobility Device"),
+    (0x0cc1, "Personal Mobility Device: Powered Wheelchair"),
+    (0x0cc2, "Personal Mobility Device: Mobility Scooter"),
+    (0x0d00, "Continuous Glucose Monitor"),
+    (0x0d40, "Insulin Pump"),
+    (0x0d41, "Insulin Pump: Insulin Pump, durable pump"),
+    (0x0d44, "Insulin Pump: Insulin Pump, patch pump"),
+    (0x0d48, "Insulin Pump: Insulin Pen"),
+    (0x0d80, "Medication Delivery"),
+    (0x0dc0, "Spirometer"),
+    (0x0dc1, "Spirometer: Handheld Spirometer"),
+    (0x1440, "Outdoor Sports Activity"),
+    (0x1441, "Outdoor Sports Activity: Location Display"),
+    (0x1442, "Outdoor Sports Activity: Location and Navigation Display"),
+    (0x1443, "Outdoor Sports Activity: Location Pod"),
+    (0x1444, "Outdoor Sports Activity: Location and Navigation Pod"),
+    (0x1480, "Industrial Measurement Device"),
+    (0x1481, "Industrial Measurement Device: Torque Testing Device"),
+    (0x1482, "Industrial Measurement Device: Caliper"),
+    (0x1483, "Industrial Measurement Device: Dial Indicator"),
+    (0x1484, "Industrial Measurement Device: Micrometer"),
+    (0x1485, "Industrial Measurement Device: Height Gauge"),
+    (0x1486, "Industrial Measurement Device: Force Gauge"),
+    (0x14c0, "Industrial Tools"),
+    (0x14c1, "Industrial Tools: Machine Tool Holder"),
+    (0x14c2, "Industrial Tools: Generic Clamping Device"),
+    (0x14c3, "Industrial Tools: Clamping Jaws/Jaw Chuck"),
+    (0x14c4, "Industrial Tools: Clamping (Collet) Chuck"),
+    (0x14c5, "Industrial Tools: Clamping Mandrel"),
+    (0x14c6, "Industrial Tools: Vise"),
+    (0x14c7, "Industrial Tools: Zero-Point Clamping System"),
+    (0x14c8, "Industrial Tools: Torque Wrench"),
+    (0x14c9, "Industrial Tools: Torque Screwdriver"),
+];
@@ -1,5 +1,8 @@
 use uuid::Uuid;
 
+#[cfg(feature = "assigned-numbers")]
+use crate::assigned_numbers::NamedUuid;
+
 const BLUETOOTH_BASE_UUID: u128 = 0x00000000_0000_1000_8000_00805f9b34fb;
 const BLUETOOTH_BASE_MASK: u128 = 0x00000000_ffff_ffff_ffff_ffffffffffff;
 const BLUETOOTH_BASE_MASK_16: u128 = 0xffff0000_ffff_ffff_ffff_ffffffffffff;
@@ -27,6 +30,16 @@ pub trait BleUuid {
 
     /// Convert the UUID to a string, using short format if applicable.
     fn succinctly(&self) -> String;
+
+    /// If the UUID is a 16-bit UUID assigned by the Bluetooth SIG for a service, characteristic,
+    /// descriptor or member then return its name, e.g. `"Battery Level"`.
+    #[cfg(feature = "assigned-numbers")]
+    fn name(&self) -> Option<&'static str>;
+
+    /// Return a wrapper which displays the UUID in short format along with its assigned name, if
+    /// it has one.
+    #[cfg(feature = "assigned-numbers")]
+    fn named(&self) -> NamedUuid;
 }
 
 impl BleUuid for Uuid {
@@ -57,6 +70,16 @@ impl BleUuid for Uuid {
             self.to_string()
         }
     }
+
+    #[cfg(feature = "assigned-numbers")]
+    fn name(&self) -> Option<&'static str> {
+        crate::assigned_numbers::uuid_name(self)
+    }
+
+    #[cfg(feature = "assigned-numbers")]
+    fn named(&self) -> NamedUuid {
+        NamedUuid(*self)
+    }
 }
 
 #[cfg(test)]
@@ -116,31 +116,65 @@ bitflags! {
     }
 }
 
+/// The BlueZ names of each characteristic flag.
+const FLAG_NAMES: &[(CharacteristicFlags, &str)] = &[
+    (CharacteristicFlags::BROADCAST, "broadcast"),
+    (CharacteristicFlags::READ, "read"),
+    (
+        CharacteristicFlags::WRITE_WITHOUT_RESPONSE,
+        "write-without-response",
+    ),
+    (CharacteristicFlags::WRITE, "write"),
+    (CharacteristicFlags::NOTIFY, "notify"),
+    (CharacteristicFlags::INDICATE, "indicate"),
+    (
+        CharacteristicFlags::SIGNED_WRITE,
+        "authenticated-signed-writes",
+    ),
+    (
+        CharacteristicFlags::EXTENDED_PROPERTIES,
+        "extended-properties",
+    ),
+    (CharacteristicFlags::RELIABLE_WRITE, "reliable-write"),
+    (
+        CharacteristicFlags::WRITABLE_AUXILIARIES,
+        "writable-auxiliaries",
+    ),
+    (CharacteristicFlags::ENCRYPT_READ, "encrypt-read"),
+    (CharacteristicFlags::ENCRYPT_WRITE, "encrypt-write"),
+    (
+        CharacteristicFlags::ENCRYPT_AUTHENTICATED_READ,
+        "encrypt-authenticated-read",
+    ),
+    (
+        CharacteristicFlags::ENCRYPT_AUTHENTICATED_WRITE,
+        "encrypt-authenticated-write",
+    ),
+    (CharacteristicFlags::AUTHORIZE, "authorize"),
+];
+
+impl CharacteristicFlags {
+    /// Get the BlueZ names of the flags which are set, e.g. to export a local characteristic.
+    pub(crate) fn to_strings(self) -> Vec<String> {
+        FLAG_NAMES
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| (*name).to_owned())
+            .collect()
+    }
+}
+
 impl TryFrom<&[String]> for CharacteristicFlags {
     type Error = BluetoothError;
 
     fn try_from(value: &[String]) -> Result<Self, BluetoothError> {
         let mut flags = Self::empty();
         for flag_string in value {
-            let flag = match flag_string.as_ref() {
-                "broadcast" => Self::BROADCAST,
-                "read" => Self::READ,
-                "write-without-response" => Self::WRITE_WITHOUT_RESPONSE,
-                "write" => Self::WRITE,
-                "notify" => Self::NOTIFY,
-                "indicate" => Self::INDICATE,
-                "authenticated-signed-writes" => Self::SIGNED_WRITE,
-                "extended-properties" => Self::EXTENDED_PROPERTIES,
-                "reliable-write" => Self::RELIABLE_WRITE,
-                "writable-auxiliaries" => Self::WRITABLE_AUXILIARIES,
-                "encrypt-read" => Self::ENCRYPT_READ,
-                "encrypt-write" => Self::ENCRYPT_WRITE,
-                "encrypt-authenticated-read" => Self::ENCRYPT_AUTHENTICATED_READ,
-                "encrypt-authenticated-write" => Self::ENCRYPT_AUTHENTICATED_WRITE,
-                "authorize" => Self::AUTHORIZE,
-                _ => return Err(BluetoothError::FlagParseError(flag_string.to_owned())),
-            };
-            flags.insert(flag);
+            let (flag, _) = FLAG_NAMES
+                .iter()
+                .find(|(_, name)| name == flag_string)
+                .ok_or_else(|| BluetoothError::FlagParseError(flag_string.to_owned()))?;
+            flags.insert(*flag);
         }
         Ok(flags)
     }
@@ -188,6 +222,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn flags_to_strings() {
+        let flags = CharacteristicFlags::READ | CharacteristicFlags::WRITE_WITHOUT_RESPONSE;
+        assert_eq!(flags.to_strings(), vec!["read", "write-without-response"]);
+        assert_eq!(
+            CharacteristicFlags::try_from(flags.to_strings()).unwrap(),
+            flags
+        );
+    }
+
     #[test]
     fn to_string() {
         let characteristic_id =
@@ -0,0 +1,372 @@
+use bitflags::bitflags;
+use std::fmt::{self, Display, Formatter};
+
+/// A Bluetooth Classic Class of Device, as defined in the Bluetooth SIG assigned numbers.
+///
+/// This is a 24-bit value made up of a set of major service classes, a major device class and a
+/// minor device class whose meaning depends on the major device class.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ClassOfDevice(pub u32);
+
+impl ClassOfDevice {
+    /// The major service classes which the device supports.
+    pub fn service_classes(&self) -> ServiceClasses {
+        ServiceClasses::from_bits_retain((self.0 >> 13) as u16 & 0x7ff)
+    }
+
+    /// The major device class.
+    pub fn major_class(&self) -> MajorDeviceClass {
+        MajorDeviceClass::from(((self.0 >> 8) & 0x1f) as u8)
+    }
+
+    /// The raw 6-bit minor device class. Its meaning depends on the major device class.
+    pub fn minor_class(&self) -> u8 {
+        ((self.0 >> 2) & 0x3f) as u8
+    }
+
+    /// The name of the minor device class, as assigned by the Bluetooth SIG, if it is known. For
+    /// imaging devices this is only available if the minor class names a single kind of device.
+    pub fn minor_class_name(&self) -> Option<&'static str> {
+        // These follow the Class of Device section of the Assigned Numbers document. Unlike the
+        // appearance subcategories they are not a flat table of values, as the minor class is
+        // split into different bitfields depending on the major class.
+        let minor = self.minor_class();
+        let names: &[&str] = match self.major_class() {
+            MajorDeviceClass::Computer => &[
+                "Uncategorized",
+                "Desktop workstation",
+                "Server-class computer",
+                "Laptop",
+                "Handheld PC/PDA",
+                "Palm-size PC/PDA",
+                "Wearable computer",
+                "Tablet",
+            ],
+            MajorDeviceClass::Phone => &[
+                "Uncategorized",
+                "Cellular",
+                "Cordless",
+                "Smartphone",
+                "Wired modem or voice gateway",
+                "Common ISDN access",
+            ],
+            MajorDeviceClass::LanNetworkAccessPoint => {
+                // The upper three bits give the load factor, and the lower three bits are reserved.
+                return [
+                    "Fully available",
+                    "1% to 17% utilized",
+                    "17% to 33% utilized",
+                    "33% to 50% utilized",
+                    "50% to 67% utilized",
+                    "67% to 83% utilized",
+                    "83% to 99% utilized",
+                    "No service available",
+                ]
+                .get(usize::from(minor >> 3))
+                .copied();
+            }
+            MajorDeviceClass::AudioVideo => &[
+                "Uncategorized",
+                "Wearable Headset Device",
+                "Hands-free Device",
+                "",
+                "Microphone",
+                "Loudspeaker",
+                "Headphones",
+                "Portable Audio",
+                "Car audio",
+                "Set-top box",
+                "HiFi Audio Device",
+                "VCR",
+                "Video Camera",
+                "Camcorder",
+                "Video Monitor",
+                "Video Display and Loudspeaker",
+                "Video Conferencing",
+                "",
+                "Gaming/Toy",
+            ],
+            MajorDeviceClass::Peripheral => {
+                // The upper two bits say whether it is a keyboard and/or pointing device, and the
+                // lower four bits give the type of device.
+                return match (minor >> 4, minor & 0x0f) {
+                    (0, 0) => Some("Uncategorized"),
+                    (1, 0) => Some("Keyboard"),
+                    (2, 0) => Some("Pointing device"),
+                    (3, 0) => Some("Combo keyboard/pointing device"),
+                    (_, 1) => Some("Joystick"),
+                    (_, 2) => Some("Gamepad"),
+                    (_, 3) => Some("Remote control"),
+                    (_, 4) => Some("Sensing device"),
+                    (_, 5) => Some("Digitizer tablet"),
+                    (_, 6) => Some("Card Reader"),
+                    (_, 7) => Some("Digital Pen"),
+                    (_, 8) => Some("Handheld scanner"),
+                    (_, 9) => Some("Handheld gestural input device"),
+                    _ => None,
+                };
+            }
+            MajorDeviceClass::Imaging => {
+                // The upper four bits are flags for the kinds of imaging device, of which more than
+                // one may be set, and the lower two bits are reserved. Only single kinds are named.
+                return match minor >> 2 {
+                    0x1 => Some("Display"),
+                    0x2 => Some("Camera"),
+                    0x4 => Some("Scanner"),
+                    0x8 => Some("Printer"),
+                    _ => None,
+                };
+            }
+            MajorDeviceClass::Wearable => &[
+                "",
+                "Wristwatch",
+                "Pager",
+                "Jacket",
+                "Helmet",
+                "Glasses",
+                "Pin",
+            ],
+            MajorDeviceClass::Toy => &[
+                "",
+                "Robot",
+                "Vehicle",
+                "Doll / Action figure",
+                "Controller",
+                "Game",
+            ],
+            MajorDeviceClass::Health => &[
+                "Undefined",
+                "Blood Pressure Monitor",
+                "Thermometer",
+                "Weighing Scale",
+                "Glucose Meter",
+                "Pulse Oximeter",
+                "Heart/Pulse Rate Monitor",
+                "Health Data Display",
+                "Step Counter",
+                "Body Composition Analyzer",
+                "Peak Flow Monitor",
+                "Medication Monitor",
+                "Knee Prosthesis",
+                "Ankle Prosthesis",
+                "Generic Health Manager",
+                "Personal Mobility Device",
+            ],
+            _ => &[],
+        };
+        names
+            .get(usize::from(minor))
+            .copied()
+            .filter(|name| !name.is_empty())
+    }
+}
+
+impl From<u32> for ClassOfDevice {
+    fn from(value: u32) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ClassOfDevice> for u32 {
+    fn from(class: ClassOfDevice) -> Self {
+        class.0
+    }
+}
+
+impl Display for ClassOfDevice {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.major_class())?;
+        match self.minor_class_name() {
+            Some(name) => write!(f, ": {}", name)?,
+            None if self.minor_class() != 0 => write!(f, " (minor class {})", self.minor_class())?,
+            None => {}
+        }
+        let service_classes = self.service_classes();
+        if !service_classes.is_empty() {
+            write!(f, " [{}]", service_classes)?;
+        }
+        Ok(())
+    }
+}
+
+/// The major device class of a [`ClassOfDevice`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum MajorDeviceClass {
+    Miscellaneous,
+    /// Desktop, notebook, PDA, organizer, etc.
+    Computer,
+    /// Cellular, cordless, pay phone, modem, etc.
+    Phone,
+    /// LAN or network access point.
+    LanNetworkAccessPoint,
+    /// Headset, speaker, stereo, video display, VCR, etc.
+    AudioVideo,
+    /// Mouse, joystick, keyboard, etc.
+    Peripheral,
+    /// Printer, scanner, camera, display, etc.
+    Imaging,
+    Wearable,
+    Toy,
+    Health,
+    /// The device class is not specified.
+    Uncategorized,
+    /// Some other, reserved, value.
+    Other(u8),
+}
+
+impl MajorDeviceClass {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Miscellaneous => "Miscellaneous",
+            Self::Computer => "Computer",
+            Self::Phone => "Phone",
+            Self::LanNetworkAccessPoint => "LAN/Network Access Point",
+            Self::AudioVideo => "Audio/Video",
+            Self::Peripheral => "Peripheral",
+            Self::Imaging => "Imaging",
+            Self::Wearable => "Wearable",
+            Self::Toy => "Toy",
+            Self::Health => "Health",
+            Self::Uncategorized => "Uncategorized",
+            Self::Other(_) => "Reserved",
+        }
+    }
+}
+
+impl From<u8> for MajorDeviceClass {
+    fn from(value: u8) -> Self {
+        match value {
+            0x00 => Self::Miscellaneous,
+            0x01 => Self::Computer,
+            0x02 => Self::Phone,
+            0x03 => Self::LanNetworkAccessPoint,
+            0x04 => Self::AudioVideo,
+            0x05 => Self::Peripheral,
+            0x06 => Self::Imaging,
+            0x07 => Self::Wearable,
+            0x08 => Self::Toy,
+            0x09 => Self::Health,
+            0x1f => Self::Uncategorized,
+            _ => Self::Other(value),
+        }
+    }
+}
+
+impl Display for MajorDeviceClass {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Other(value) => write!(f, "Reserved ({:#04x})", value),
+            _ => f.write_str(self.as_str()),
+        }
+    }
+}
+
+bitflags! {
+    /// The major service classes of a [`ClassOfDevice`], i.e. bits 13 to 23 shifted down by 13.
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    pub struct ServiceClasses: u16 {
+        const LIMITED_DISCOVERABLE_MODE = 0x001;
+        const LE_AUDIO = 0x002;
+        const POSITIONING = 0x008;
+        const NETWORKING = 0x010;
+        const RENDERING = 0x020;
+        const CAPTURING = 0x040;
+        const OBJECT_TRANSFER = 0x080;
+        const AUDIO = 0x100;
+        const TELEPHONY = 0x200;
+        const INFORMATION = 0x400;
+    }
+}
+
+impl Display for ServiceClasses {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let names = [
+            (Self::LIMITED_DISCOVERABLE_MODE, "Limited Discoverable Mode"),
+            (Self::LE_AUDIO, "LE Audio"),
+            (Self::POSITIONING, "Positioning"),
+            (Self::NETWORKING, "Networking"),
+            (Self::RENDERING, "Rendering"),
+            (Self::CAPTURING, "Capturing"),
+            (Self::OBJECT_TRANSFER, "Object Transfer"),
+            (Self::AUDIO, "Audio"),
+            (Self::TELEPHONY, "Telephony"),
+            (Self::INFORMATION, "Information"),
+        ];
+        let mut first = true;
+        for (flag, name) in names {
+            if self.contains(flag) {
+                if !first {
+                    f.write_str(", ")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn headset() {
+        let class = ClassOfDevice(0x240404);
+        assert_eq!(class.major_class(), MajorDeviceClass::AudioVideo);
+        assert_eq!(class.minor_class(), 1);
+        assert_eq!(class.minor_class_name(), Some("Wearable Headset Device"));
+        assert_eq!(
+            class.service_classes(),
+            ServiceClasses::AUDIO | ServiceClasses::RENDERING
+        );
+        assert_eq!(
+            class.to_string(),
+            "Audio/Video: Wearable Headset Device [Rendering, Audio]"
+        );
+    }
+
+    #[test]
+    fn peripheral() {
+        // Combo keyboard/pointing device.
+        assert_eq!(
+            ClassOfDevice(0x0005c0).minor_class_name(),
+            Some("Combo keyboard/pointing device")
+        );
+        // Gamepad.
+        assert_eq!(
+            ClassOfDevice(0x002508).to_string(),
+            "Peripheral: Gamepad [Limited Discoverable Mode]"
+        );
+    }
+
+    #[test]
+    fn lan_and_imaging() {
+        assert_eq!(
+            ClassOfDevice(0x020340).to_string(),
+            "LAN/Network Access Point: 17% to 33% utilized [Networking]"
+        );
+        assert_eq!(
+            ClassOfDevice(0x040680).to_string(),
+            "Imaging: Printer [Rendering]"
+        );
+        // Scanner and printer.
+        assert_eq!(
+            ClassOfDevice(0x0006c0).to_string(),
+            "Imaging (minor class 48)"
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(ClassOfDevice(0x000000).to_string(), "Miscellaneous");
+        assert_eq!(
+            ClassOfDevice(0x000a7c).to_string(),
+            "Reserved (0x0a) (minor class 31)"
+        );
+        assert_eq!(
+            ClassOfDevice(0x001f00).major_class(),
+            MajorDeviceClass::Uncategorized
+        );
+    }
+}
@@ -1,9 +1,11 @@
+use bitflags::bitflags;
 use dbus::Path;
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 use uuid::Uuid;
 
-use crate::CharacteristicId;
+use crate::{BluetoothError, CharacteristicId};
 
 /// Opaque identifier for a GATT characteristic descriptor on a Bluetooth device.
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
@@ -59,6 +61,68 @@ pub struct DescriptorInfo {
     pub uuid: Uuid,
 }
 
+bitflags! {
+    /// The set of flags of a descriptor, defining how the descriptor can be used.
+    #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+    pub struct DescriptorFlags: u16 {
+        const READ = 0x01;
+        const WRITE = 0x02;
+        const ENCRYPT_READ = 0x04;
+        const ENCRYPT_WRITE = 0x08;
+        const ENCRYPT_AUTHENTICATED_READ = 0x10;
+        const ENCRYPT_AUTHENTICATED_WRITE = 0x20;
+        const SECURE_READ = 0x40;
+        const SECURE_WRITE = 0x80;
+        const AUTHORIZE = 0x100;
+    }
+}
+
+/// The BlueZ names of each descriptor flag.
+const FLAG_NAMES: &[(DescriptorFlags, &str)] = &[
+    (DescriptorFlags::READ, "read"),
+    (DescriptorFlags::WRITE, "write"),
+    (DescriptorFlags::ENCRYPT_READ, "encrypt-read"),
+    (DescriptorFlags::ENCRYPT_WRITE, "encrypt-write"),
+    (
+        DescriptorFlags::ENCRYPT_AUTHENTICATED_READ,
+        "encrypt-authenticated-read",
+    ),
+    (
+        DescriptorFlags::ENCRYPT_AUTHENTICATED_WRITE,
+        "encrypt-authenticated-write",
+    ),
+    (DescriptorFlags::SECURE_READ, "secure-read"),
+    (DescriptorFlags::SECURE_WRITE, "secure-write"),
+    (DescriptorFlags::AUTHORIZE, "authorize"),
+];
+
+impl DescriptorFlags {
+    /// Get the BlueZ names of the flags which are set, e.g. to export a local descriptor.
+    pub(crate) fn to_strings(self) -> Vec<String> {
+        FLAG_NAMES
+            .iter()
+            .filter(|(flag, _)| self.contains(*flag))
+            .map(|(_, name)| (*name).to_owned())
+            .collect()
+    }
+}
+
+impl TryFrom<&[String]> for DescriptorFlags {
+    type Error = BluetoothError;
+
+    fn try_from(value: &[String]) -> Result<Self, BluetoothError> {
+        let mut flags = Self::empty();
+        for flag_string in value {
+            let (flag, _) = FLAG_NAMES
+                .iter()
+                .find(|(_, name)| name == flag_string)
+                .ok_or_else(|| BluetoothError::FlagParseError(flag_string.to_owned()))?;
+            flags.insert(*flag);
+        }
+        Ok(flags)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -73,6 +137,17 @@ mod tests {
         assert_eq!(descriptor_id.characteristic(), characteristic_id);
     }
 
+    #[test]
+    fn flags() {
+        let flags = DescriptorFlags::READ | DescriptorFlags::SECURE_WRITE;
+        assert_eq!(flags.to_strings(), vec!["read", "secure-write"]);
+        assert_eq!(
+            DescriptorFlags::try_from(flags.to_strings().as_slice()).unwrap(),
+            flags
+        );
+        assert!(DescriptorFlags::try_from(&["notify".to_owned()][..]).is_err());
+    }
+
     #[test]
     fn to_string() {
         let descriptor_id = DescriptorId::new(
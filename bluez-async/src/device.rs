@@ -1,4 +1,4 @@
-use bluez_generated::OrgBluezDevice1Properties;
+use bluez_generated::{OrgBluezBattery1Properties, OrgBluezDevice1Properties};
 use dbus::arg::{cast, PropMap, RefArg, Variant};
 use dbus::Path;
 use serde::{Deserialize, Serialize};
@@ -7,7 +7,7 @@ use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 use uuid::Uuid;
 
-use crate::{AdapterId, BluetoothError, MacAddress};
+use crate::{AdapterId, Appearance, BluetoothError, ClassOfDevice, MacAddress, Modalias};
 
 /// Opaque identifier for a Bluetooth device which the system knows about. This includes a reference
 /// to which Bluetooth adapter it was discovered on, which means that any attempt to connect to it
@@ -110,15 +110,19 @@ pub struct DeviceInfo {
     // simple pairing will occur if pairing is initiated.
     pub legacy_pairing: bool,
     // Remote Device ID information in modalias format used by the kernel and udev.
-    pub modalias: Option<String>,
+    pub modalias: Option<Modalias>,
     // If set to true this device will be allowed to wake the host from system suspend.
     pub wake_allowed: bool,
+    /// The battery level of the device as a percentage, if BlueZ provides it via the
+    /// `org.bluez.Battery1` interface.
+    pub battery_percentage: Option<u8>,
 }
 
 impl DeviceInfo {
     pub(crate) fn from_properties(
         id: DeviceId,
         device_properties: OrgBluezDevice1Properties,
+        battery_properties: Option<OrgBluezBattery1Properties>,
     ) -> Result<DeviceInfo, BluetoothError> {
         let mac_address = device_properties
             .address()
@@ -165,10 +169,30 @@ impl DeviceInfo {
             legacy_pairing: device_properties
                 .legacy_pairing()
                 .ok_or(BluetoothError::RequiredPropertyMissing("LegacyPairing"))?,
-            modalias: device_properties.modalias().cloned(),
+            modalias: device_properties
+                .modalias()
+                .and_then(|modalias| match modalias.parse() {
+                    Ok(modalias) => Some(modalias),
+                    Err(e) => {
+                        log::warn!("{}", e);
+                        None
+                    }
+                }),
             wake_allowed: device_properties.wake_allowed().unwrap_or(false),
+            battery_percentage: battery_properties.and_then(|battery| battery.percentage()),
         })
     }
+
+    /// The appearance of the device decoded into its category and subcategory, if it is available.
+    pub fn decoded_appearance(&self) -> Option<Appearance> {
+        self.appearance.map(Appearance::from)
+    }
+
+    /// The Bluetooth Classic class of device decoded into its service classes and major and minor
+    /// device classes, if it is available.
+    pub fn class_of_device(&self) -> Option<ClassOfDevice> {
+        self.class.map(ClassOfDevice)
+    }
 }
 
 /// MAC address type of a Bluetooth device.
@@ -279,7 +303,7 @@ pub(crate) fn convert_services(uuids: &[String]) -> Vec<Uuid> {
 
 #[cfg(test)]
 mod tests {
-    use crate::uuid_from_u32;
+    use crate::{uuid_from_u32, ModaliasSource};
 
     use super::*;
 
@@ -333,9 +357,8 @@ mod tests {
         );
     }
 
-    #[test]
-    fn device_info_minimal() {
-        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+    /// The properties which BlueZ always reports for a device.
+    fn device_properties() -> PropMap {
         let mut device_properties: PropMap = HashMap::new();
         device_properties.insert(
             "Address".to_string(),
@@ -352,10 +375,20 @@ mod tests {
         device_properties.insert("Trusted".to_string(), Variant(Box::new(false)));
         device_properties.insert("Blocked".to_string(), Variant(Box::new(false)));
         device_properties.insert("LegacyPairing".to_string(), Variant(Box::new(false)));
+        device_properties
+    }
 
-        let device =
-            DeviceInfo::from_properties(id.clone(), OrgBluezDevice1Properties(&device_properties))
-                .unwrap();
+    #[test]
+    fn device_info_minimal() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let device_properties = device_properties();
+
+        let device = DeviceInfo::from_properties(
+            id.clone(),
+            OrgBluezDevice1Properties(&device_properties),
+            None,
+        )
+        .unwrap();
         assert_eq!(
             device,
             DeviceInfo {
@@ -381,10 +414,50 @@ mod tests {
                 legacy_pairing: false,
                 modalias: None,
                 wake_allowed: false,
+                battery_percentage: None,
             }
         )
     }
 
+    #[test]
+    fn device_info_battery() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let device_properties = device_properties();
+        let mut battery_properties: PropMap = HashMap::new();
+        battery_properties.insert("Percentage".to_string(), Variant(Box::new(42u8)));
+
+        let device = DeviceInfo::from_properties(
+            id,
+            OrgBluezDevice1Properties(&device_properties),
+            Some(OrgBluezBattery1Properties(&battery_properties)),
+        )
+        .unwrap();
+        assert_eq!(device.battery_percentage, Some(42));
+    }
+
+    #[test]
+    fn device_info_modalias() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let mut device_properties = device_properties();
+        device_properties.insert(
+            "Modalias".to_string(),
+            Variant(Box::new("bluetooth:v004Cp0312d0110".to_string())),
+        );
+
+        let device =
+            DeviceInfo::from_properties(id, OrgBluezDevice1Properties(&device_properties), None)
+                .unwrap();
+        assert_eq!(
+            device.modalias,
+            Some(Modalias {
+                source: ModaliasSource::Bluetooth,
+                vendor_id: 0x004c,
+                product_id: 0x0312,
+                device_id: 0x0110,
+            })
+        );
+    }
+
     #[test]
     fn get_services_none() {
         let device_properties: PropMap = HashMap::new();
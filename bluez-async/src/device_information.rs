@@ -0,0 +1,319 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display, Formatter};
+use std::future::Future;
+use uuid::Uuid;
+
+use crate::{uuid_from_u16, BluetoothError};
+
+/// The UUID of the GATT Device Information Service.
+pub const DEVICE_INFORMATION_SERVICE_UUID: Uuid = uuid_from_u16(0x180a);
+
+const SYSTEM_ID_UUID: Uuid = uuid_from_u16(0x2a23);
+const MODEL_NUMBER_UUID: Uuid = uuid_from_u16(0x2a24);
+const SERIAL_NUMBER_UUID: Uuid = uuid_from_u16(0x2a25);
+const FIRMWARE_REVISION_UUID: Uuid = uuid_from_u16(0x2a26);
+const HARDWARE_REVISION_UUID: Uuid = uuid_from_u16(0x2a27);
+const SOFTWARE_REVISION_UUID: Uuid = uuid_from_u16(0x2a28);
+const MANUFACTURER_NAME_UUID: Uuid = uuid_from_u16(0x2a29);
+const IEEE_REGULATORY_CERTIFICATION_UUID: Uuid = uuid_from_u16(0x2a2a);
+const PNP_ID_UUID: Uuid = uuid_from_u16(0x2a50);
+
+/// Information about a device read from its GATT Device Information Service.
+///
+/// Any characteristics which the device doesn't provide, or whose values couldn't be decoded, are
+/// `None`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DeviceInformation {
+    /// The name of the manufacturer of the device.
+    pub manufacturer_name: Option<String>,
+    /// The model number assigned by the device vendor.
+    pub model_number: Option<String>,
+    /// The serial number of this particular instance of the device.
+    pub serial_number: Option<String>,
+    /// The hardware revision of the device.
+    pub hardware_revision: Option<String>,
+    /// The firmware revision of the device.
+    pub firmware_revision: Option<String>,
+    /// The software revision of the device.
+    pub software_revision: Option<String>,
+    /// The system ID of the device.
+    pub system_id: Option<SystemId>,
+    /// The raw IEEE 11073-20601 regulatory certification data list.
+    pub ieee_regulatory_certification: Option<Vec<u8>>,
+    /// The Plug and Play ID of the device.
+    pub pnp_id: Option<PnpId>,
+}
+
+impl DeviceInformation {
+    /// The UUIDs of all the characteristics of the Device Information Service which we know how to
+    /// decode.
+    pub(crate) const CHARACTERISTIC_UUIDS: [Uuid; 9] = [
+        MANUFACTURER_NAME_UUID,
+        MODEL_NUMBER_UUID,
+        SERIAL_NUMBER_UUID,
+        HARDWARE_REVISION_UUID,
+        FIRMWARE_REVISION_UUID,
+        SOFTWARE_REVISION_UUID,
+        SYSTEM_ID_UUID,
+        IEEE_REGULATORY_CERTIFICATION_UUID,
+        PNP_ID_UUID,
+    ];
+
+    /// Decode the value of the characteristic with the given UUID and fill in the corresponding
+    /// field. Values which can't be decoded and unknown UUIDs are logged and ignored.
+    pub(crate) fn set_characteristic(&mut self, uuid: Uuid, value: &[u8]) {
+        match uuid {
+            MANUFACTURER_NAME_UUID => self.manufacturer_name = Some(decode_string(value)),
+            MODEL_NUMBER_UUID => self.model_number = Some(decode_string(value)),
+            SERIAL_NUMBER_UUID => self.serial_number = Some(decode_string(value)),
+            HARDWARE_REVISION_UUID => self.hardware_revision = Some(decode_string(value)),
+            FIRMWARE_REVISION_UUID => self.firmware_revision = Some(decode_string(value)),
+            SOFTWARE_REVISION_UUID => self.software_revision = Some(decode_string(value)),
+            SYSTEM_ID_UUID => {
+                self.system_id = SystemId::from_bytes(value);
+                if self.system_id.is_none() {
+                    log::warn!("Invalid System ID {:?}", value);
+                }
+            }
+            IEEE_REGULATORY_CERTIFICATION_UUID => {
+                self.ieee_regulatory_certification = Some(value.to_owned())
+            }
+            PNP_ID_UUID => {
+                self.pnp_id = PnpId::from_bytes(value);
+                if self.pnp_id.is_none() {
+                    log::warn!("Invalid PnP ID {:?}", value);
+                }
+            }
+            _ => log::trace!("Ignoring Device Information characteristic {}", uuid),
+        }
+    }
+
+    /// Read the known characteristics from the given list of UUIDs and IDs with the given function,
+    /// and decode them.
+    ///
+    /// Characteristics which can't be read, e.g. because they require encryption, are logged and
+    /// left as `None`. Other errors, such as the device disconnecting, are returned.
+    pub(crate) async fn read<T, F: Future<Output = Result<Vec<u8>, BluetoothError>>>(
+        characteristics: impl IntoIterator<Item = (Uuid, T)>,
+        read: impl Fn(T) -> F,
+    ) -> Result<Self, BluetoothError> {
+        let mut information = Self::default();
+        for (uuid, id) in characteristics {
+            if !Self::CHARACTERISTIC_UUIDS.contains(&uuid) {
+                continue;
+            }
+            match read(id).await {
+                Ok(value) => information.set_characteristic(uuid, &value),
+                Err(e) if is_characteristic_error(&e) => {
+                    log::debug!(
+                        "Can't read Device Information characteristic {}: {}",
+                        uuid,
+                        e
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(information)
+    }
+}
+
+/// Returns whether the given error from reading a characteristic is specific to that
+/// characteristic, such as it requiring authentication, rather than a problem with the connection.
+fn is_characteristic_error(error: &BluetoothError) -> bool {
+    match error {
+        BluetoothError::DbusError(error) => match error.name() {
+            Some("org.bluez.Error.NotPermitted")
+            | Some("org.bluez.Error.NotAuthorized")
+            | Some("org.bluez.Error.NotSupported")
+            | Some("org.bluez.Error.InvalidOffset")
+            | Some("org.bluez.Error.InvalidValueLength") => true,
+            // Other ATT error responses from the device, such as insufficient encryption.
+            Some("org.bluez.Error.Failed") => error
+                .message()
+                .is_some_and(|message| message.contains("ATT error")),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Decode a UTF-8 string characteristic, replacing any invalid sequences and dropping any trailing
+/// NUL characters which some devices include.
+fn decode_string(value: &[u8]) -> String {
+    String::from_utf8_lossy(value)
+        .trim_end_matches('\0')
+        .to_owned()
+}
+
+/// The System ID characteristic of the Device Information Service.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct SystemId {
+    /// The 40-bit manufacturer-defined identifier.
+    pub manufacturer_identifier: u64,
+    /// The 24-bit Organizationally Unique Identifier of the manufacturer.
+    pub organizationally_unique_identifier: u32,
+}
+
+impl SystemId {
+    /// Decode a System ID from the 8 bytes of its characteristic value.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let value = u64::from_le_bytes(bytes.try_into().ok()?);
+        Some(Self {
+            manufacturer_identifier: value & 0xff_ffff_ffff,
+            organizationally_unique_identifier: (value >> 40) as u32,
+        })
+    }
+}
+
+/// The source of the vendor ID in a [`PnpId`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VendorIdSource {
+    /// A Bluetooth SIG assigned company identifier.
+    Bluetooth,
+    /// A USB Implementer's Forum assigned vendor ID.
+    Usb,
+    /// Some other, reserved, value.
+    Unknown(u8),
+}
+
+impl From<u8> for VendorIdSource {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::Bluetooth,
+            2 => Self::Usb,
+            _ => Self::Unknown(value),
+        }
+    }
+}
+
+impl Display for VendorIdSource {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Bluetooth => f.write_str("Bluetooth SIG"),
+            Self::Usb => f.write_str("USB-IF"),
+            Self::Unknown(value) => write!(f, "unknown ({})", value),
+        }
+    }
+}
+
+/// The PnP ID characteristic of the Device Information Service.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PnpId {
+    /// Which organisation assigned the vendor ID.
+    pub vendor_id_source: VendorIdSource,
+    /// The vendor ID, assigned by the organisation given by `vendor_id_source`.
+    pub vendor_id: u16,
+    /// The product ID, assigned by the vendor.
+    pub product_id: u16,
+    /// The product version, assigned by the vendor.
+    pub product_version: u16,
+}
+
+impl PnpId {
+    /// Decode a PnP ID from the 7 bytes of its characteristic value.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 7 {
+            return None;
+        }
+        Some(Self {
+            vendor_id_source: bytes[0].into(),
+            vendor_id: u16::from_le_bytes([bytes[1], bytes[2]]),
+            product_id: u16::from_le_bytes([bytes[3], bytes[4]]),
+            product_version: u16::from_le_bytes([bytes[5], bytes[6]]),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn read_skips_unreadable() {
+        let characteristics = vec![
+            (MANUFACTURER_NAME_UUID, "manufacturer"),
+            (SYSTEM_ID_UUID, "system id"),
+            (uuid_from_u16(0x1234), "unknown"),
+            (MODEL_NUMBER_UUID, "model"),
+        ];
+        let information = DeviceInformation::read(characteristics.clone(), |id| async move {
+            match id {
+                "manufacturer" => Ok(b"ACME".to_vec()),
+                "system id" => Err(dbus::Error::new_custom(
+                    "org.bluez.Error.NotPermitted",
+                    "Read not permitted",
+                )
+                .into()),
+                "model" => Ok(b"Widget".to_vec()),
+                _ => panic!("Unexpected read of {}", id),
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            information,
+            DeviceInformation {
+                manufacturer_name: Some("ACME".to_string()),
+                model_number: Some("Widget".to_string()),
+                ..Default::default()
+            }
+        );
+
+        // Connection errors are still returned.
+        let result = DeviceInformation::read(characteristics, |_| async {
+            Err(dbus::Error::new_custom("org.bluez.Error.Failed", "Not connected").into())
+        })
+        .await;
+        assert!(matches!(result, Err(BluetoothError::DbusError(_))));
+    }
+
+    #[test]
+    fn system_id() {
+        assert_eq!(
+            SystemId::from_bytes(&[0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08]),
+            Some(SystemId {
+                manufacturer_identifier: 0x05_0403_0201,
+                organizationally_unique_identifier: 0x08_0706,
+            })
+        );
+        assert_eq!(SystemId::from_bytes(&[0x01, 0x02]), None);
+    }
+
+    #[test]
+    fn pnp_id() {
+        assert_eq!(
+            PnpId::from_bytes(&[0x02, 0x6b, 0x1d, 0x46, 0x02, 0x37, 0x05]),
+            Some(PnpId {
+                vendor_id_source: VendorIdSource::Usb,
+                vendor_id: 0x1d6b,
+                product_id: 0x0246,
+                product_version: 0x0537,
+            })
+        );
+        assert_eq!(
+            PnpId::from_bytes(&[0x07, 0, 0, 0, 0, 0, 0])
+                .unwrap()
+                .vendor_id_source,
+            VendorIdSource::Unknown(7)
+        );
+        assert_eq!(PnpId::from_bytes(&[0x01, 0x02]), None);
+    }
+
+    #[test]
+    fn set_characteristics() {
+        let mut information = DeviceInformation::default();
+        information.set_characteristic(MANUFACTURER_NAME_UUID, b"Acme\0");
+        information.set_characteristic(FIRMWARE_REVISION_UUID, b"1.2.3");
+        information.set_characteristic(PNP_ID_UUID, &[0x01]);
+        information.set_characteristic(uuid_from_u16(0x1234), b"ignored");
+        assert_eq!(
+            information,
+            DeviceInformation {
+                manufacturer_name: Some("Acme".to_owned()),
+                firmware_revision: Some("1.2.3".to_owned()),
+                ..Default::default()
+            }
+        );
+    }
+}
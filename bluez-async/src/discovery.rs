@@ -0,0 +1,282 @@
+use futures::lock::Mutex;
+use std::collections::{BTreeMap, HashMap};
+use std::mem;
+use tokio::runtime::Handle;
+
+use crate::{AdapterId, BluetoothError, BluetoothSession, DiscoveryFilter, Transport};
+
+/// Merge the discovery filters of several concurrent scans into one which will find every device
+/// that any of them would.
+pub(crate) fn merge_filters<'a>(
+    filters: impl IntoIterator<Item = &'a DiscoveryFilter>,
+) -> DiscoveryFilter {
+    let mut filters = filters.into_iter();
+    let mut merged = match filters.next() {
+        Some(first) => first.clone(),
+        None => return DiscoveryFilter::default(),
+    };
+    for filter in filters {
+        // An empty list of UUIDs means any device, so only merge if both are non-empty.
+        if merged.service_uuids.is_empty() || filter.service_uuids.is_empty() {
+            merged.service_uuids.clear();
+        } else {
+            for uuid in &filter.service_uuids {
+                if !merged.service_uuids.contains(uuid) {
+                    merged.service_uuids.push(*uuid);
+                }
+            }
+        }
+        merged.rssi_threshold = merged
+            .rssi_threshold
+            .zip(filter.rssi_threshold)
+            .map(|(a, b)| a.min(b));
+        merged.pathloss_threshold = merged
+            .pathloss_threshold
+            .zip(filter.pathloss_threshold)
+            .map(|(a, b)| a.max(b));
+        merged.transport = match (merged.transport, filter.transport) {
+            (Some(a), Some(b)) if a == b => Some(a),
+            (None, None) => None,
+            _ => Some(Transport::Auto),
+        };
+        merged.duplicate_data = merge_or(merged.duplicate_data, filter.duplicate_data);
+        merged.discoverable = merge_or(merged.discoverable, filter.discoverable);
+        if merged.pattern != filter.pattern {
+            merged.pattern = None;
+        }
+    }
+    // BlueZ doesn't allow both thresholds to be set at once. This can only happen if different
+    // scans set different thresholds, in which case we can't satisfy both.
+    if merged.rssi_threshold.is_some() && merged.pathloss_threshold.is_some() {
+        merged.rssi_threshold = None;
+        merged.pathloss_threshold = None;
+    }
+    merged
+}
+
+fn merge_or(a: Option<bool>, b: Option<bool>) -> Option<bool> {
+    match (a, b) {
+        (None, None) => None,
+        _ => Some(a == Some(true) || b == Some(true)),
+    }
+}
+
+/// The scans currently active on a single adapter.
+#[derive(Debug, Default)]
+struct AdapterScans {
+    next_id: u64,
+    filters: BTreeMap<u64, DiscoveryFilter>,
+}
+
+/// Keeps track of the scans active on each adapter, so that discovery is only stopped once the
+/// last of them finishes.
+#[derive(Debug, Default)]
+pub(crate) struct DiscoveryRegistry {
+    adapters: Mutex<HashMap<AdapterId, AdapterScans>>,
+}
+
+impl DiscoveryRegistry {
+    /// Register a new scan on the given adapter with the given filter, starting discovery if it is
+    /// the first or updating the filter otherwise. Returns an ID for the scan to pass to `release`.
+    async fn acquire(
+        &self,
+        session: &BluetoothSession,
+        adapter_id: &AdapterId,
+        filter: &DiscoveryFilter,
+    ) -> Result<u64, BluetoothError> {
+        let mut adapters = self.adapters.lock().await;
+        let scans = adapters.entry(adapter_id.to_owned()).or_default();
+        let merged = merge_filters(scans.filters.values().chain(Some(filter)));
+        if scans.filters.is_empty() {
+            session
+                .start_discovery_on_adapter_with_filter(adapter_id, &merged)
+                .await?;
+        } else {
+            session.set_discovery_filter(adapter_id, &merged).await?;
+        }
+        let id = scans.next_id;
+        scans.next_id += 1;
+        scans.filters.insert(id, filter.to_owned());
+        Ok(id)
+    }
+
+    /// Unregister the given scan, stopping discovery if it was the last one or relaxing the filter
+    /// otherwise.
+    async fn release(
+        &self,
+        session: &BluetoothSession,
+        adapter_id: &AdapterId,
+        id: u64,
+    ) -> Result<(), BluetoothError> {
+        let mut adapters = self.adapters.lock().await;
+        let scans = match adapters.get_mut(adapter_id) {
+            Some(scans) => scans,
+            None => return Ok(()),
+        };
+        scans.filters.remove(&id);
+        if scans.filters.is_empty() {
+            adapters.remove(adapter_id);
+            session.stop_discovery_on_adapter(adapter_id).await
+        } else {
+            let merged = merge_filters(scans.filters.values());
+            session.set_discovery_filter(adapter_id, &merged).await?;
+            Ok(())
+        }
+    }
+}
+
+/// A handle to an ongoing scan started by
+/// [`BluetoothSession::start_scan`](struct.BluetoothSession.html#method.start_scan).
+///
+/// Discovery continues until every `ScanGuard` for the adapter has been dropped or stopped, even if
+/// there are several of them from different tasks.
+#[must_use = "discovery stops when the ScanGuard is dropped"]
+#[derive(Debug)]
+pub struct ScanGuard {
+    session: BluetoothSession,
+    runtime: Handle,
+    scans: Vec<(AdapterId, u64)>,
+}
+
+impl ScanGuard {
+    /// Start a scan with the given filter on each of the given adapters. Adapters on which it fails
+    /// to start are logged and skipped, unless it fails on all of them, in which case the last
+    /// error is returned.
+    pub(crate) async fn start(
+        session: &BluetoothSession,
+        adapters: &[AdapterId],
+        filter: &DiscoveryFilter,
+    ) -> Result<Self, BluetoothError> {
+        let mut scans = vec![];
+        let mut last_error = None;
+        for adapter in adapters {
+            match session.discovery.acquire(session, adapter, filter).await {
+                Ok(id) => scans.push((adapter.to_owned(), id)),
+                Err(e) => {
+                    if adapters.len() > 1 {
+                        log::error!("Starting discovery on {} failed: {}", adapter, e);
+                    }
+                    last_error = Some(e);
+                }
+            }
+        }
+        if scans.is_empty() {
+            return Err(last_error.unwrap_or(BluetoothError::NoBluetoothAdapters));
+        }
+        Ok(Self {
+            session: session.clone(),
+            runtime: Handle::current(),
+            scans,
+        })
+    }
+
+    /// The adapters on which this scan is running.
+    pub fn adapters(&self) -> impl Iterator<Item = &AdapterId> {
+        self.scans.iter().map(|(adapter, _)| adapter)
+    }
+
+    /// Stop the scan, and wait until discovery has been stopped or its filter updated. This is the
+    /// same as dropping the guard, except that it waits and reports any error.
+    pub async fn stop(mut self) -> Result<(), BluetoothError> {
+        let scans = mem::take(&mut self.scans);
+        release_all(&self.session, scans).await
+    }
+}
+
+impl Drop for ScanGuard {
+    fn drop(&mut self) {
+        let scans = mem::take(&mut self.scans);
+        if scans.is_empty() {
+            return;
+        }
+        let session = self.session.clone();
+        self.runtime.spawn(async move {
+            if let Err(e) = release_all(&session, scans).await {
+                log::error!("Stopping discovery failed: {}", e);
+            }
+        });
+    }
+}
+
+async fn release_all(
+    session: &BluetoothSession,
+    scans: Vec<(AdapterId, u64)>,
+) -> Result<(), BluetoothError> {
+    let mut result = Ok(());
+    for (adapter, id) in scans {
+        if let Err(e) = session.discovery.release(session, &adapter, id).await {
+            log::warn!("Stopping discovery on {} failed: {}", adapter, e);
+            result = Err(e);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::uuid_from_u16;
+
+    #[test]
+    fn merge_single() {
+        let filter = DiscoveryFilter {
+            service_uuids: vec![uuid_from_u16(0x180f)],
+            rssi_threshold: Some(-70),
+            pattern: Some("Ruuvi".to_owned()),
+            ..Default::default()
+        };
+        assert_eq!(merge_filters(std::slice::from_ref(&filter)), filter);
+        assert_eq!(merge_filters(&[]), DiscoveryFilter::default());
+    }
+
+    #[test]
+    fn merge_union() {
+        let a = DiscoveryFilter {
+            service_uuids: vec![uuid_from_u16(0x180f)],
+            rssi_threshold: Some(-70),
+            transport: Some(Transport::Le),
+            duplicate_data: Some(false),
+            pattern: Some("Ruuvi".to_owned()),
+            ..Default::default()
+        };
+        let b = DiscoveryFilter {
+            service_uuids: vec![uuid_from_u16(0x180a), uuid_from_u16(0x180f)],
+            rssi_threshold: Some(-90),
+            transport: Some(Transport::Le),
+            duplicate_data: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            merge_filters(&[a, b]),
+            DiscoveryFilter {
+                service_uuids: vec![uuid_from_u16(0x180f), uuid_from_u16(0x180a)],
+                rssi_threshold: Some(-90),
+                transport: Some(Transport::Le),
+                duplicate_data: Some(true),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn merge_unfiltered() {
+        let a = DiscoveryFilter {
+            service_uuids: vec![uuid_from_u16(0x180f)],
+            rssi_threshold: Some(-70),
+            transport: Some(Transport::Le),
+            ..Default::default()
+        };
+        let b = DiscoveryFilter {
+            pathloss_threshold: Some(20),
+            transport: Some(Transport::BrEdr),
+            ..Default::default()
+        };
+        assert_eq!(
+            merge_filters(&[a, b]),
+            DiscoveryFilter {
+                transport: Some(Transport::Auto),
+                ..Default::default()
+            }
+        );
+    }
+}
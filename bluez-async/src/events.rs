@@ -1,17 +1,25 @@
 use bluez_generated::{
-    OrgBluezAdapter1Properties, OrgBluezDevice1Properties, OrgBluezGattCharacteristic1Properties,
-    ORG_BLUEZ_ADAPTER1_NAME, ORG_BLUEZ_DEVICE1_NAME, ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME,
+    OrgBluezAdapter1Properties, OrgBluezBattery1Properties, OrgBluezDevice1Properties,
+    OrgBluezGattCharacteristic1Properties, OrgBluezLEAdvertisingManager1Properties,
+    OrgBluezMediaControl1Properties, OrgBluezMediaPlayer1Properties, OrgBluezNetwork1Properties,
+    ORG_BLUEZ_ADAPTER1_NAME, ORG_BLUEZ_BATTERY1_NAME, ORG_BLUEZ_DEVICE1_NAME,
+    ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME, ORG_BLUEZ_LEADVERTISING_MANAGER1_NAME,
+    ORG_BLUEZ_MEDIA_CONTROL1_NAME, ORG_BLUEZ_MEDIA_PLAYER1_NAME, ORG_BLUEZ_NETWORK1_NAME,
 };
 use dbus::message::{MatchRule, SignalArgs};
 use dbus::nonblock::stdintf::org_freedesktop_dbus::{
-    ObjectManagerInterfacesAdded, PropertiesPropertiesChanged,
+    ObjectManagerInterfacesAdded, ObjectManagerInterfacesRemoved, PropertiesPropertiesChanged,
 };
 use dbus::{Message, Path};
 use std::collections::HashMap;
+use std::time::Duration;
 use uuid::Uuid;
 
 use super::device::{convert_manufacturer_data, convert_service_data, convert_services};
-use super::{AdapterId, CharacteristicId, DeviceId};
+use super::{
+    AdapterId, AdvertisingIncludes, CharacteristicId, DeviceId, MediaPlayerId, PlaybackStatus,
+    RepeatMode, SecondaryChannel, ShuffleMode, TrackInfo,
+};
 
 /// An event relating to a Bluetooth device or adapter.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -37,6 +45,13 @@ pub enum BluetoothEvent {
         /// Details of the specific event.
         event: CharacteristicEvent,
     },
+    /// An event related to an AVRCP media player on a Bluetooth device.
+    MediaPlayer {
+        /// The ID of the media player in question.
+        id: MediaPlayerId,
+        /// Details of the specific event.
+        event: MediaPlayerEvent,
+    },
 }
 
 /// Details of an event related to a Bluetooth adapter.
@@ -47,6 +62,18 @@ pub enum AdapterEvent {
     Powered { powered: bool },
     /// The adapter has started or stopped scanning for devices.
     Discovering { discovering: bool },
+    /// The number of advertisements registered with the adapter has changed.
+    ActiveAdvertisingInstances { active_instances: u8 },
+    /// The number of further advertisements which may be registered with the adapter has changed.
+    SupportedAdvertisingInstances { supported_instances: u8 },
+    /// The data which BlueZ supports adding to advertisements itself has changed.
+    SupportedAdvertisingIncludes {
+        supported_includes: AdvertisingIncludes,
+    },
+    /// The secondary channels which may be used for extended advertisements have changed.
+    SupportedSecondaryChannels {
+        supported_secondary_channels: Vec<SecondaryChannel>,
+    },
 }
 
 /// Details of an event related to a Bluetooth device.
@@ -55,10 +82,15 @@ pub enum AdapterEvent {
 pub enum DeviceEvent {
     /// A new device has been discovered.
     Discovered,
+    /// The device has been removed, e.g. because BlueZ stopped receiving advertisements from it or
+    /// it was explicitly removed from the adapter.
+    Removed,
     /// The device has connected or disconnected.
     Connected { connected: bool },
     /// A new value is available for the RSSI of the device.
     Rssi { rssi: i16 },
+    /// A new value is available for the transmission power level advertised by the device.
+    TxPower { tx_power: i16 },
     /// A new value is available for the manufacturer-specific advertisement data of the device.
     ManufacturerData {
         /// The manufacturer-specific advertisement data. The keys are 'manufacturer IDs'.
@@ -76,6 +108,41 @@ pub enum DeviceEvent {
     },
     /// Service discovery has completed.
     ServicesResolved,
+    /// A new value is available for the battery level of the device, from the `org.bluez.Battery1`
+    /// interface.
+    BatteryLevel {
+        /// The battery level as a percentage.
+        percentage: u8,
+    },
+    /// The PAN connection to the device has been established or closed.
+    NetworkConnected { connected: bool },
+    /// The network interface for the PAN connection to the device has changed, e.g. because the
+    /// connection was established.
+    NetworkInterface {
+        /// The name of the network interface, e.g. `"bnep0"`.
+        interface: String,
+    },
+    /// The AVRCP connection to the device has been established or closed.
+    MediaControlConnected { connected: bool },
+    /// The device has a new active media player, which media control commands will be sent to.
+    ActiveMediaPlayer { player: MediaPlayerId },
+}
+
+/// Details of an event related to an AVRCP media player.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MediaPlayerEvent {
+    /// The playback status has changed, e.g. from playing to paused.
+    Status { status: PlaybackStatus },
+    /// The current track has changed, or new metadata is available for it.
+    Track { track: TrackInfo },
+    /// The position within the current track has changed, e.g. because the user seeked. This is
+    /// not sent continuously during playback.
+    Position { position: Duration },
+    /// The repeat setting has changed.
+    Repeat { repeat: RepeatMode },
+    /// The shuffle setting has changed.
+    Shuffle { shuffle: ShuffleMode },
 }
 
 /// Details of an event related to a GATT characteristic.
@@ -91,8 +158,9 @@ impl BluetoothEvent {
     /// events, possibly limited to those for a particular object (such as a device, service or
     /// characteristic).
     ///
-    /// Set `interfaces_added` to true to include ObjectManager InterfacesAdded signals, which map
-    /// to `DeviceEvent::Discovered` events.
+    /// Set `interfaces_added` to true to include ObjectManager InterfacesAdded and
+    /// InterfacesRemoved signals, which map to `DeviceEvent::Discovered` and `DeviceEvent::Removed`
+    /// events.
     pub(crate) fn match_rules(
         object: Option<impl Into<Path<'static>>>,
         interfaces_added: bool,
@@ -104,11 +172,14 @@ impl BluetoothEvent {
         let mut match_rules = vec![];
 
         // If we aren't filtering to a single device or characteristic, then match ObjectManager
-        // signals so we can get events for new devices being discovered.
+        // signals so we can get events for new devices being discovered, and old ones removed.
         if interfaces_added {
             let match_rule =
                 ObjectManagerInterfacesAdded::match_rule(Some(&bus_name), None).static_clone();
             match_rules.push(match_rule);
+            let match_rule =
+                ObjectManagerInterfacesRemoved::match_rule(Some(&bus_name), None).static_clone();
+            match_rules.push(match_rule);
         }
 
         // Match PropertiesChanged signals for the given device or characteristic and all objects
@@ -132,6 +203,10 @@ impl BluetoothEvent {
         } else if let Some(interfaces_added) = ObjectManagerInterfacesAdded::from_message(&message)
         {
             Self::interfaces_added_to_events(interfaces_added)
+        } else if let Some(interfaces_removed) =
+            ObjectManagerInterfacesRemoved::from_message(&message)
+        {
+            Self::interfaces_removed_to_events(interfaces_removed)
         } else {
             log::info!("Unexpected message: {:?}", message);
             vec![]
@@ -148,15 +223,48 @@ impl BluetoothEvent {
         if let Some(_device) =
             OrgBluezDevice1Properties::from_interfaces(&interfaces_added.interfaces)
         {
-            let id = DeviceId { object_path };
             events.push(BluetoothEvent::Device {
-                id,
+                id: DeviceId {
+                    object_path: object_path.clone(),
+                },
                 event: DeviceEvent::Discovered,
             })
         }
+        // The Battery1 interface is added to an existing device object once BlueZ knows its battery
+        // level, so report its initial value.
+        if let Some(percentage) =
+            OrgBluezBattery1Properties::from_interfaces(&interfaces_added.interfaces)
+                .and_then(|battery| battery.percentage())
+        {
+            events.push(BluetoothEvent::Device {
+                id: DeviceId { object_path },
+                event: DeviceEvent::BatteryLevel { percentage },
+            })
+        }
         events
     }
 
+    /// Return a list of Bluetooth events parsed from an InterfacesRemoved signal.
+    fn interfaces_removed_to_events(
+        interfaces_removed: ObjectManagerInterfacesRemoved,
+    ) -> Vec<BluetoothEvent> {
+        log::trace!("InterfacesRemoved: {:?}", interfaces_removed);
+        if interfaces_removed
+            .interfaces
+            .iter()
+            .any(|interface| interface == ORG_BLUEZ_DEVICE1_NAME)
+        {
+            vec![BluetoothEvent::Device {
+                id: DeviceId {
+                    object_path: interfaces_removed.object,
+                },
+                event: DeviceEvent::Removed,
+            }]
+        } else {
+            vec![]
+        }
+    }
+
     /// Return a list of Bluetooth events parsed from a PropertiesChanged signal.
     fn properties_changed_to_events(
         object_path: Path<'static>,
@@ -186,6 +294,46 @@ impl BluetoothEvent {
                     });
                 }
             }
+            ORG_BLUEZ_LEADVERTISING_MANAGER1_NAME => {
+                let id = AdapterId { object_path };
+                let advertising = OrgBluezLEAdvertisingManager1Properties(changed_properties);
+                if let Some(active_instances) = advertising.active_instances() {
+                    events.push(BluetoothEvent::Adapter {
+                        id: id.clone(),
+                        event: AdapterEvent::ActiveAdvertisingInstances { active_instances },
+                    });
+                }
+                if let Some(supported_instances) = advertising.supported_instances() {
+                    events.push(BluetoothEvent::Adapter {
+                        id: id.clone(),
+                        event: AdapterEvent::SupportedAdvertisingInstances {
+                            supported_instances,
+                        },
+                    });
+                }
+                if let Some(supported_includes) = advertising.supported_includes() {
+                    events.push(BluetoothEvent::Adapter {
+                        id: id.clone(),
+                        event: AdapterEvent::SupportedAdvertisingIncludes {
+                            supported_includes: AdvertisingIncludes::from_strings(
+                                supported_includes,
+                            ),
+                        },
+                    });
+                }
+                if let Some(supported_secondary_channels) =
+                    advertising.supported_secondary_channels()
+                {
+                    events.push(BluetoothEvent::Adapter {
+                        id,
+                        event: AdapterEvent::SupportedSecondaryChannels {
+                            supported_secondary_channels: SecondaryChannel::from_strings(
+                                supported_secondary_channels,
+                            ),
+                        },
+                    });
+                }
+            }
             ORG_BLUEZ_DEVICE1_NAME => {
                 let id = DeviceId { object_path };
                 let device = OrgBluezDevice1Properties(changed_properties);
@@ -195,6 +343,13 @@ impl BluetoothEvent {
                         event: DeviceEvent::Connected { connected },
                     });
                 }
+                // Report the TX power first, so that it can be used along with the new RSSI.
+                if let Some(tx_power) = device.tx_power() {
+                    events.push(BluetoothEvent::Device {
+                        id: id.clone(),
+                        event: DeviceEvent::TxPower { tx_power },
+                    });
+                }
                 if let Some(rssi) = device.rssi() {
                     events.push(BluetoothEvent::Device {
                         id: id.clone(),
@@ -232,6 +387,95 @@ impl BluetoothEvent {
                     });
                 }
             }
+            ORG_BLUEZ_BATTERY1_NAME => {
+                let id = DeviceId { object_path };
+                let battery = OrgBluezBattery1Properties(changed_properties);
+                if let Some(percentage) = battery.percentage() {
+                    events.push(BluetoothEvent::Device {
+                        id,
+                        event: DeviceEvent::BatteryLevel { percentage },
+                    });
+                }
+            }
+            ORG_BLUEZ_NETWORK1_NAME => {
+                let id = DeviceId { object_path };
+                let network = OrgBluezNetwork1Properties(changed_properties);
+                if let Some(connected) = network.connected() {
+                    events.push(BluetoothEvent::Device {
+                        id: id.clone(),
+                        event: DeviceEvent::NetworkConnected { connected },
+                    });
+                }
+                if let Some(interface) = network.interface() {
+                    events.push(BluetoothEvent::Device {
+                        id,
+                        event: DeviceEvent::NetworkInterface {
+                            interface: interface.to_owned(),
+                        },
+                    });
+                }
+            }
+            ORG_BLUEZ_MEDIA_CONTROL1_NAME => {
+                let id = DeviceId { object_path };
+                let media_control = OrgBluezMediaControl1Properties(changed_properties);
+                if let Some(connected) = media_control.connected() {
+                    events.push(BluetoothEvent::Device {
+                        id: id.clone(),
+                        event: DeviceEvent::MediaControlConnected { connected },
+                    });
+                }
+                if let Some(player) = media_control.player() {
+                    events.push(BluetoothEvent::Device {
+                        id,
+                        event: DeviceEvent::ActiveMediaPlayer {
+                            player: MediaPlayerId {
+                                object_path: player.to_owned(),
+                            },
+                        },
+                    });
+                }
+            }
+            ORG_BLUEZ_MEDIA_PLAYER1_NAME => {
+                let id = MediaPlayerId { object_path };
+                let player = OrgBluezMediaPlayer1Properties(changed_properties);
+                let mut player_events = vec![];
+                if let Some(status) = player.status() {
+                    match status.parse() {
+                        Ok(status) => player_events.push(MediaPlayerEvent::Status { status }),
+                        Err(e) => log::warn!("{}", e),
+                    }
+                }
+                if let Some(track) = player.track() {
+                    player_events.push(MediaPlayerEvent::Track {
+                        track: TrackInfo::from_properties(track),
+                    });
+                }
+                if let Some(position) = player.position() {
+                    player_events.push(MediaPlayerEvent::Position {
+                        position: Duration::from_millis(position.into()),
+                    });
+                }
+                if let Some(repeat) = player.repeat() {
+                    match repeat.parse() {
+                        Ok(repeat) => player_events.push(MediaPlayerEvent::Repeat { repeat }),
+                        Err(e) => log::warn!("{}", e),
+                    }
+                }
+                if let Some(shuffle) = player.shuffle() {
+                    match shuffle.parse() {
+                        Ok(shuffle) => player_events.push(MediaPlayerEvent::Shuffle { shuffle }),
+                        Err(e) => log::warn!("{}", e),
+                    }
+                }
+                events.extend(
+                    player_events
+                        .into_iter()
+                        .map(|event| BluetoothEvent::MediaPlayer {
+                            id: id.clone(),
+                            event,
+                        }),
+                );
+            }
             ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME => {
                 let id = CharacteristicId { object_path };
                 let characteristic = OrgBluezGattCharacteristic1Properties(changed_properties);
@@ -271,6 +515,110 @@ mod tests {
         )
     }
 
+    #[test]
+    fn advertising_instances() {
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert("ActiveInstances".to_string(), Variant(Box::new(1u8)));
+        changed_properties.insert("SupportedInstances".to_string(), Variant(Box::new(3u8)));
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.LEAdvertisingManager1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        let message = properties_changed.to_emit_message(&"/org/bluez/hci0".into());
+        let id = AdapterId::new("/org/bluez/hci0");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![
+                BluetoothEvent::Adapter {
+                    id: id.clone(),
+                    event: AdapterEvent::ActiveAdvertisingInstances {
+                        active_instances: 1
+                    }
+                },
+                BluetoothEvent::Adapter {
+                    id,
+                    event: AdapterEvent::SupportedAdvertisingInstances {
+                        supported_instances: 3
+                    }
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn network_connected() {
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert("Connected".to_string(), Variant(Box::new(true)));
+        changed_properties.insert(
+            "Interface".to_string(),
+            Variant(Box::new("bnep0".to_string())),
+        );
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.Network1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        let message =
+            properties_changed.to_emit_message(&"/org/bluez/hci0/dev_11_22_33_44_55_66".into());
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![
+                BluetoothEvent::Device {
+                    id: id.clone(),
+                    event: DeviceEvent::NetworkConnected { connected: true }
+                },
+                BluetoothEvent::Device {
+                    id,
+                    event: DeviceEvent::NetworkInterface {
+                        interface: "bnep0".to_string()
+                    }
+                },
+            ]
+        )
+    }
+
+    #[test]
+    fn media_player_track() {
+        let mut track = PropMap::new();
+        track.insert("Title".to_string(), Variant(Box::new("Song".to_string())));
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert(
+            "Status".to_string(),
+            Variant(Box::new("paused".to_string())),
+        );
+        changed_properties.insert("Track".to_string(), Variant(Box::new(track)));
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.MediaPlayer1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        let message = properties_changed
+            .to_emit_message(&"/org/bluez/hci0/dev_11_22_33_44_55_66/player0".into());
+        let id = MediaPlayerId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/player0");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![
+                BluetoothEvent::MediaPlayer {
+                    id: id.clone(),
+                    event: MediaPlayerEvent::Status {
+                        status: PlaybackStatus::Paused
+                    }
+                },
+                BluetoothEvent::MediaPlayer {
+                    id,
+                    event: MediaPlayerEvent::Track {
+                        track: TrackInfo {
+                            title: Some("Song".to_string()),
+                            ..Default::default()
+                        }
+                    }
+                },
+            ]
+        )
+    }
+
     #[test]
     fn device_rssi() {
         let rssi = 42;
@@ -285,6 +633,34 @@ mod tests {
         )
     }
 
+    #[test]
+    fn device_rssi_tx_power() {
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert("RSSI".to_string(), Variant(Box::new(-60i16)));
+        changed_properties.insert("TxPower".to_string(), Variant(Box::new(4i16)));
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.Device1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        let message =
+            properties_changed.to_emit_message(&"/org/bluez/hci0/dev_11_22_33_44_55_66".into());
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![
+                BluetoothEvent::Device {
+                    id: id.clone(),
+                    event: DeviceEvent::TxPower { tx_power: 4 }
+                },
+                BluetoothEvent::Device {
+                    id,
+                    event: DeviceEvent::Rssi { rssi: -60 }
+                }
+            ]
+        )
+    }
+
     #[test]
     fn device_manufacturer_data() {
         let mut manufacturer_data = HashMap::new();
@@ -323,8 +699,7 @@ mod tests {
 
     #[test]
     fn device_services() {
-        let mut services = Vec::new();
-        services.push(uuid_from_u32(0x11223344));
+        let services = vec![uuid_from_u32(0x11223344)];
         let message =
             device_services_message("/org/bluez/hci0/dev_11_22_33_44_55_66", services.clone());
         let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
@@ -337,6 +712,40 @@ mod tests {
         )
     }
 
+    #[test]
+    fn device_battery_level() {
+        let message = device_battery_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![BluetoothEvent::Device {
+                id,
+                event: DeviceEvent::BatteryLevel { percentage: 42 }
+            }]
+        )
+    }
+
+    #[test]
+    fn device_battery_added() {
+        let mut properties: PropMap = HashMap::new();
+        properties.insert("Percentage".to_string(), Variant(Box::new(42u8)));
+        let mut interfaces = HashMap::new();
+        interfaces.insert("org.bluez.Battery1".to_string(), properties);
+        let message = ObjectManagerInterfacesAdded {
+            object: "/org/bluez/hci0/dev_11_22_33_44_55_66".into(),
+            interfaces,
+        }
+        .to_emit_message(&"/".into());
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![BluetoothEvent::Device {
+                id,
+                event: DeviceEvent::BatteryLevel { percentage: 42 }
+            }]
+        )
+    }
+
     #[test]
     fn characteristic_value() {
         let value: Vec<u8> = vec![1, 2, 3];
@@ -368,24 +777,48 @@ mod tests {
         )
     }
 
+    #[test]
+    fn device_removed() {
+        let message = removed_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            BluetoothEvent::message_to_events(message),
+            vec![BluetoothEvent::Device {
+                id,
+                event: DeviceEvent::Removed
+            }]
+        );
+
+        // Removing some other interface from the device doesn't remove the device.
+        let message = ObjectManagerInterfacesRemoved {
+            object: "/org/bluez/hci0/dev_11_22_33_44_55_66".into(),
+            interfaces: vec!["org.bluez.Battery1".to_string()],
+        }
+        .to_emit_message(&"/".into());
+        assert_eq!(BluetoothEvent::message_to_events(message), vec![]);
+    }
+
     #[test]
     fn match_rules_all() {
         let match_rules = BluetoothEvent::match_rules(None::<DeviceId>, true);
 
         let message = new_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
+
+        let message = removed_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = adapter_powered_message("/org/bluez/hci0", true);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = device_rssi_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = characteristic_value_message(
             "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034",
-            &vec![1, 2, 3],
+            &[1, 2, 3],
         );
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
     }
 
     #[test]
@@ -394,19 +827,19 @@ mod tests {
         let match_rules = BluetoothEvent::match_rules(Some(id), false);
 
         let message = new_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = adapter_powered_message("/org/bluez/hci0", true);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = device_rssi_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = characteristic_value_message(
             "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034",
-            &vec![1, 2, 3],
+            &[1, 2, 3],
         );
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
     }
 
     #[test]
@@ -415,19 +848,19 @@ mod tests {
         let match_rules = BluetoothEvent::match_rules(Some(id), false);
 
         let message = new_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = adapter_powered_message("/org/bluez/hci0", true);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = device_rssi_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = characteristic_value_message(
             "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034",
-            &vec![1, 2, 3],
+            &[1, 2, 3],
         );
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
     }
 
     #[test]
@@ -437,19 +870,19 @@ mod tests {
         let match_rules = BluetoothEvent::match_rules(Some(id), false);
 
         let message = new_device_message("/org/bluez/hci0/dev_11_22_33_44_55_66");
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = adapter_powered_message("/org/bluez/hci0", true);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = device_rssi_message("/org/bluez/hci0/dev_11_22_33_44_55_66", 42);
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), false);
+        assert!(!match_rules.iter().any(|rule| rule.matches(&message)));
 
         let message = characteristic_value_message(
             "/org/bluez/hci0/dev_11_22_33_44_55_66/service0012/char0034",
-            &vec![1, 2, 3],
+            &[1, 2, 3],
         );
-        assert_eq!(match_rules.iter().any(|rule| rule.matches(&message)), true);
+        assert!(match_rules.iter().any(|rule| rule.matches(&message)));
     }
 
     fn new_device_message(device_path: &'static str) -> Message {
@@ -463,6 +896,14 @@ mod tests {
         interfaces_added.to_emit_message(&"/".into())
     }
 
+    fn removed_device_message(device_path: &'static str) -> Message {
+        let interfaces_removed = ObjectManagerInterfacesRemoved {
+            object: device_path.into(),
+            interfaces: vec!["org.bluez.Device1".to_string()],
+        };
+        interfaces_removed.to_emit_message(&"/".into())
+    }
+
     fn adapter_powered_message(adapter_path: &'static str, powered: bool) -> Message {
         let mut changed_properties: PropMap = HashMap::new();
         changed_properties.insert("Powered".to_string(), Variant(Box::new(powered)));
@@ -541,6 +982,17 @@ mod tests {
         properties_changed.to_emit_message(&device_path.into())
     }
 
+    fn device_battery_message(device_path: &'static str, percentage: u8) -> Message {
+        let mut changed_properties: PropMap = HashMap::new();
+        changed_properties.insert("Percentage".to_string(), Variant(Box::new(percentage)));
+        let properties_changed = PropertiesPropertiesChanged {
+            interface_name: "org.bluez.Battery1".to_string(),
+            changed_properties,
+            invalidated_properties: vec![],
+        };
+        properties_changed.to_emit_message(&device_path.into())
+    }
+
     fn characteristic_value_message(characteristic_path: &'static str, value: &[u8]) -> Message {
         let mut changed_properties: PropMap = HashMap::new();
         changed_properties.insert("Value".to_string(), Variant(Box::new(value.to_owned())));
@@ -0,0 +1,74 @@
+use dbus::channel::{MatchingReceiver, Token};
+use dbus::message::MatchRule;
+use dbus::{MethodErr, Path};
+use dbus_crossroads::Crossroads;
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::runtime::Handle;
+
+use crate::BluetoothSession;
+
+/// The path under which all objects exported by this crate are placed.
+const EXPORT_ROOT: &str = "/bluez_async";
+
+static NEXT_EXPORT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Get a new object path which is unique within this process, e.g. `/bluez_async/application3`.
+pub(crate) fn unique_path(kind: &str) -> Path<'static> {
+    let id = NEXT_EXPORT_ID.fetch_add(1, Ordering::Relaxed);
+    format!("{}/{}{}", EXPORT_ROOT, kind, id).into()
+}
+
+/// Start serving method calls for objects under the given path from the given `Crossroads` on the
+/// session's D-Bus connection, until the returned token is passed to `unserve`.
+///
+/// Async methods are run on the current tokio runtime.
+pub(crate) fn serve(session: &BluetoothSession, path: &Path<'static>, mut cr: Crossroads) -> Token {
+    let runtime = Handle::current();
+    cr.set_async_support(Some((
+        session.connection.clone(),
+        Box::new(move |future| {
+            runtime.spawn(future);
+        }),
+    )));
+    session.connection.start_receive(
+        MatchRule::new_method_call().with_namespaced_path(path.clone()),
+        Box::new(move |message, connection| {
+            // This only fails if the message isn't a method call, which the match rule excludes.
+            let _ = cr.handle_message(message, connection);
+            true
+        }),
+    )
+}
+
+/// Stop serving method calls which were being served since the given call to `serve`.
+pub(crate) fn unserve(session: &BluetoothSession, token: Token) {
+    session.connection.stop_receive(token);
+}
+
+/// Get the value of an optional property of an exported object.
+///
+/// If it is unset then this fails, so that the property is left out of `GetAll` and BlueZ uses its
+/// default.
+pub(crate) fn optional<T>(name: &str, value: Option<T>) -> Result<T, MethodErr> {
+    value.ok_or_else(|| MethodErr::no_property(&name))
+}
+
+/// Convert a duration to whole seconds for a `q` property, saturating if it is too long.
+pub(crate) fn seconds(duration: Duration) -> u16 {
+    u16::try_from(duration.as_secs()).unwrap_or(u16::MAX)
+}
+
+/// A D-Bus sender which just records the messages sent, for testing exported objects.
+#[cfg(test)]
+#[derive(Default)]
+pub(crate) struct RecordingSender(pub std::sync::Mutex<Vec<dbus::Message>>);
+
+#[cfg(test)]
+impl dbus::channel::Sender for RecordingSender {
+    fn send(&self, message: dbus::Message) -> Result<u32, ()> {
+        self.0.lock().unwrap().push(message);
+        Ok(0)
+    }
+}
@@ -0,0 +1,818 @@
+mod notifier;
+
+pub use self::notifier::{CharacteristicNotifier, SubscriptionEvent};
+
+use bluez_generated::{
+    OrgBluezGattManager1, ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME, ORG_BLUEZ_GATT_DESCRIPTOR1_NAME,
+    ORG_BLUEZ_GATT_SERVICE1_NAME,
+};
+use dbus::arg::{prop_cast, PropMap};
+use dbus::channel::Token;
+use dbus::nonblock::Proxy;
+use dbus::{MethodErr, Path};
+use dbus_crossroads::{Crossroads, IfaceBuilder, IfaceToken};
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use std::fmt::{self, Debug, Formatter};
+use std::future::Future;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::runtime::Handle;
+use uuid::Uuid;
+
+use crate::export::{serve, unique_path, unserve};
+use crate::{
+    AdapterId, BluetoothError, BluetoothSession, CharacteristicFlags, DescriptorFlags, DeviceId,
+    WriteType, DBUS_METHOD_CALL_TIMEOUT,
+};
+
+/// The minimum ATT MTU, to assume if BlueZ doesn't report one.
+const DEFAULT_MTU: u16 = 23;
+
+/// A handler for a remote device reading the value of a local characteristic or descriptor.
+pub type ReadHandler =
+    Arc<dyn Fn(ReadRequest) -> BoxFuture<'static, Result<Vec<u8>, GattError>> + Send + Sync>;
+
+/// A handler for a remote device writing the given value to a local characteristic or descriptor.
+pub type WriteHandler =
+    Arc<dyn Fn(Vec<u8>, WriteRequest) -> BoxFuture<'static, Result<(), GattError>> + Send + Sync>;
+
+/// An error to return to a remote device from a read or write handler of a local characteristic or
+/// descriptor.
+#[derive(Clone, Debug, Eq, Error, PartialEq)]
+#[non_exhaustive]
+pub enum GattError {
+    /// A generic failure, with the given message.
+    #[error("Operation failed: {0}")]
+    Failed(String),
+    /// Another operation is already in progress.
+    #[error("Operation already in progress")]
+    InProgress,
+    /// The operation isn't permitted, e.g. because the remote device hasn't paired.
+    #[error("Operation not permitted")]
+    NotPermitted,
+    /// The remote device isn't authorized to carry out the operation.
+    #[error("Operation not authorized")]
+    NotAuthorized,
+    /// The operation isn't supported.
+    #[error("Operation not supported")]
+    NotSupported,
+    /// The offset of a read or write is past the end of the value.
+    #[error("Invalid offset")]
+    InvalidOffset,
+    /// The length of a value written is invalid.
+    #[error("Invalid value length")]
+    InvalidValueLength,
+}
+
+impl GattError {
+    fn dbus_error_name(&self) -> &'static str {
+        match self {
+            Self::Failed(_) => "org.bluez.Error.Failed",
+            Self::InProgress => "org.bluez.Error.InProgress",
+            Self::NotPermitted => "org.bluez.Error.NotPermitted",
+            Self::NotAuthorized => "org.bluez.Error.NotAuthorized",
+            Self::NotSupported => "org.bluez.Error.NotSupported",
+            Self::InvalidOffset => "org.bluez.Error.InvalidOffset",
+            Self::InvalidValueLength => "org.bluez.Error.InvalidValueLength",
+        }
+    }
+}
+
+impl From<GattError> for MethodErr {
+    fn from(error: GattError) -> Self {
+        (error.dbus_error_name(), error.to_string()).into()
+    }
+}
+
+/// Details of a request from a remote device to read a local characteristic or descriptor.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct ReadRequest {
+    /// The device which is reading the value, if BlueZ reported it.
+    pub device: Option<DeviceId>,
+    /// The offset into the value from which to read.
+    pub offset: u16,
+    /// The exchanged MTU of the connection, if known.
+    pub mtu: Option<u16>,
+}
+
+impl ReadRequest {
+    fn from_options(options: &PropMap) -> Self {
+        Self {
+            device: device_from_options(options),
+            offset: prop_cast(options, "offset").copied().unwrap_or_default(),
+            mtu: prop_cast(options, "mtu").copied(),
+        }
+    }
+}
+
+/// Details of a request from a remote device to write a local characteristic or descriptor.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct WriteRequest {
+    /// The device which is writing the value, if BlueZ reported it.
+    pub device: Option<DeviceId>,
+    /// The offset into the value at which to write.
+    pub offset: u16,
+    /// The exchanged MTU of the connection, if known.
+    pub mtu: Option<u16>,
+    /// The type of write operation, if BlueZ reported it.
+    pub write_type: Option<WriteType>,
+    /// Whether this is a prepared write which is only being authorized, rather than actually
+    /// written. This is only set for characteristics with the `AUTHORIZE` flag.
+    pub prepare_authorize: bool,
+}
+
+impl WriteRequest {
+    fn from_options(options: &PropMap) -> Self {
+        Self {
+            device: device_from_options(options),
+            offset: prop_cast(options, "offset").copied().unwrap_or_default(),
+            mtu: prop_cast(options, "mtu").copied(),
+            write_type: prop_cast::<String>(options, "type").and_then(|s| WriteType::parse(s)),
+            prepare_authorize: prop_cast(options, "prepare-authorize")
+                .copied()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+fn device_from_options(options: &PropMap) -> Option<DeviceId> {
+    prop_cast::<Path>(options, "device").map(|path| DeviceId::new(path))
+}
+
+/// The read and write handlers of a local characteristic or descriptor.
+#[derive(Clone, Default)]
+struct Handlers {
+    read: Option<ReadHandler>,
+    write: Option<WriteHandler>,
+}
+
+impl Handlers {
+    fn set_read<F, Fut>(&mut self, handler: F)
+    where
+        F: Fn(ReadRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, GattError>> + Send + 'static,
+    {
+        self.read = Some(Arc::new(move |request| handler(request).boxed()));
+    }
+
+    fn set_write<F, Fut>(&mut self, handler: F)
+    where
+        F: Fn(Vec<u8>, WriteRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), GattError>> + Send + 'static,
+    {
+        self.write = Some(Arc::new(move |value, request| {
+            handler(value, request).boxed()
+        }));
+    }
+}
+
+impl Debug for Handlers {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Handlers")
+            .field("read", &self.read.is_some())
+            .field("write", &self.write.is_some())
+            .finish()
+    }
+}
+
+/// A set of local GATT services to be registered with an adapter by
+/// [`BluetoothSession::register_gatt_application`](struct.BluetoothSession.html#method.register_gatt_application),
+/// so that remote devices can use them.
+///
+/// ```no_run
+/// # use bluez_async::{
+/// #     uuid_from_u16, AdapterId, BluetoothSession, CharacteristicFlags, GattApplication,
+/// #     LocalCharacteristic, LocalService,
+/// # };
+/// # async fn example(
+/// #     session: &BluetoothSession,
+/// #     adapter: &AdapterId,
+/// # ) -> Result<(), Box<dyn std::error::Error>> {
+/// let application = GattApplication::new().service(
+///     LocalService::new(uuid_from_u16(0x180f)).characteristic(
+///         LocalCharacteristic::new(uuid_from_u16(0x2a19), CharacteristicFlags::READ)
+///             .on_read(|_request| async { Ok(vec![42]) }),
+///     ),
+/// );
+/// let handle = session.register_gatt_application(adapter, &application).await?;
+/// // The service is available until the handle is dropped.
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GattApplication {
+    services: Vec<LocalService>,
+}
+
+impl GattApplication {
+    /// Create a new application with no services.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the given service to the application.
+    pub fn service(mut self, service: LocalService) -> Self {
+        self.services.push(service);
+        self
+    }
+}
+
+/// A GATT service to be exported as part of a [`GattApplication`].
+#[derive(Clone, Debug)]
+pub struct LocalService {
+    uuid: Uuid,
+    primary: bool,
+    characteristics: Vec<LocalCharacteristic>,
+}
+
+impl LocalService {
+    /// Create a new primary service with the given UUID and no characteristics.
+    pub fn new(uuid: Uuid) -> Self {
+        Self {
+            uuid,
+            primary: true,
+            characteristics: vec![],
+        }
+    }
+
+    /// Set whether the service is a primary service. Services are primary by default.
+    pub fn primary(mut self, primary: bool) -> Self {
+        self.primary = primary;
+        self
+    }
+
+    /// Add the given characteristic to the service.
+    pub fn characteristic(mut self, characteristic: LocalCharacteristic) -> Self {
+        self.characteristics.push(characteristic);
+        self
+    }
+}
+
+/// A GATT characteristic to be exported as part of a [`LocalService`].
+#[derive(Clone, Debug)]
+pub struct LocalCharacteristic {
+    uuid: Uuid,
+    flags: CharacteristicFlags,
+    handlers: Handlers,
+    descriptors: Vec<LocalDescriptor>,
+    notifier: CharacteristicNotifier,
+    acquire_notify: bool,
+}
+
+impl LocalCharacteristic {
+    /// Create a new characteristic with the given UUID and flags, and no handlers or descriptors.
+    ///
+    /// Until a read handler is set, reads by remote devices return the value last set with
+    /// [`CharacteristicNotifier::notify`]. Writes fail with `GattError::NotSupported` until a write
+    /// handler is set.
+    pub fn new(uuid: Uuid, flags: CharacteristicFlags) -> Self {
+        Self {
+            uuid,
+            flags,
+            handlers: Handlers::default(),
+            descriptors: vec![],
+            notifier: CharacteristicNotifier::default(),
+            acquire_notify: false,
+        }
+    }
+
+    /// Set the initial value of the characteristic, which is returned to remote devices which read
+    /// it if there is no read handler.
+    pub fn initial_value(self, value: Vec<u8>) -> Self {
+        self.notifier.set_value(value);
+        self
+    }
+
+    /// Get a handle to send notifications or indications from the characteristic to subscribed
+    /// remote devices, and find out when they subscribe.
+    ///
+    /// Clones of the characteristic share the same notifier.
+    pub fn notifier(&self) -> CharacteristicNotifier {
+        self.notifier.clone()
+    }
+
+    /// Support `AcquireNotify`, so that BlueZ can receive notifications through a socket rather
+    /// than `PropertiesChanged` signals. This is more efficient for characteristics which notify
+    /// frequently.
+    pub fn acquire_notify(mut self) -> Self {
+        self.acquire_notify = true;
+        self
+    }
+
+    /// Set the handler to call when a remote device reads the characteristic.
+    ///
+    /// The handler is only used for `ReadValue` calls. The characteristic's D-Bus `Value` property
+    /// still returns the value last passed to [`CharacteristicNotifier::notify`] (or
+    /// [`initial_value`](Self::initial_value)), so call `notify` whenever the value which the
+    /// handler returns changes to keep the two in sync.
+    pub fn on_read<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(ReadRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, GattError>> + Send + 'static,
+    {
+        self.handlers.set_read(handler);
+        self
+    }
+
+    /// Set the handler to call when a remote device writes the characteristic.
+    pub fn on_write<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Vec<u8>, WriteRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), GattError>> + Send + 'static,
+    {
+        self.handlers.set_write(handler);
+        self
+    }
+
+    /// Add the given descriptor to the characteristic.
+    pub fn descriptor(mut self, descriptor: LocalDescriptor) -> Self {
+        self.descriptors.push(descriptor);
+        self
+    }
+}
+
+/// A GATT descriptor to be exported as part of a [`LocalCharacteristic`].
+#[derive(Clone, Debug)]
+pub struct LocalDescriptor {
+    uuid: Uuid,
+    flags: DescriptorFlags,
+    handlers: Handlers,
+}
+
+impl LocalDescriptor {
+    /// Create a new descriptor with the given UUID and flags, and no handlers.
+    ///
+    /// Reads and writes by remote devices fail with `GattError::NotSupported` until the
+    /// corresponding handler is set.
+    pub fn new(uuid: Uuid, flags: DescriptorFlags) -> Self {
+        Self {
+            uuid,
+            flags,
+            handlers: Handlers::default(),
+        }
+    }
+
+    /// Set the handler to call when a remote device reads the descriptor.
+    pub fn on_read<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(ReadRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, GattError>> + Send + 'static,
+    {
+        self.handlers.set_read(handler);
+        self
+    }
+
+    /// Set the handler to call when a remote device writes the descriptor.
+    pub fn on_write<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(Vec<u8>, WriteRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), GattError>> + Send + 'static,
+    {
+        self.handlers.set_write(handler);
+        self
+    }
+}
+
+/// The data of an exported service object.
+struct ServiceObject {
+    uuid: Uuid,
+    primary: bool,
+}
+
+/// The data of an exported characteristic or descriptor object.
+struct AttributeObject {
+    uuid: Uuid,
+    /// The path of the service or characteristic to which the attribute belongs.
+    parent: Path<'static>,
+    flags: Vec<String>,
+    handlers: Handlers,
+    /// The notifier of a characteristic, or `None` for a descriptor.
+    notifier: Option<CharacteristicNotifier>,
+}
+
+impl AttributeObject {
+    fn notifier(&self) -> Result<&CharacteristicNotifier, MethodErr> {
+        self.notifier
+            .as_ref()
+            .ok_or_else(|| GattError::NotSupported.into())
+    }
+}
+
+/// Read the given value from the given offset, for a read request which has no handler.
+fn read_from_offset(value: Vec<u8>, offset: u16) -> Result<Vec<u8>, GattError> {
+    value
+        .get(usize::from(offset)..)
+        .map(<[u8]>::to_vec)
+        .ok_or(GattError::InvalidOffset)
+}
+
+/// Register an interface for characteristic objects, with `AcquireNotify` support or not.
+fn register_characteristic_interface(
+    cr: &mut Crossroads,
+    acquire_notify: bool,
+) -> IfaceToken<AttributeObject> {
+    cr.register(
+        ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME,
+        |b: &mut IfaceBuilder<AttributeObject>| {
+            attribute_interface(b, "Service");
+            // This is the value last notified, even if there is a read handler; see
+            // `LocalCharacteristic::on_read`.
+            b.property("Value")
+                .get(|_, characteristic| Ok(characteristic.notifier()?.value()));
+            b.property("Notifying")
+                .get(|_, characteristic| Ok(characteristic.notifier()?.is_notifying()));
+            b.method("StartNotify", (), (), |_, characteristic, ()| {
+                characteristic.notifier()?.set_notifying(true);
+                Ok(())
+            });
+            b.method("StopNotify", (), (), |_, characteristic, ()| {
+                characteristic.notifier()?.set_notifying(false);
+                Ok(())
+            });
+            if acquire_notify {
+                b.property("NotifyAcquired")
+                    .get(|_, characteristic| Ok(characteristic.notifier()?.is_acquired()));
+                b.method_with_cr_async(
+                    "AcquireNotify",
+                    ("options",),
+                    ("fd", "mtu"),
+                    |mut ctx, cr, (options,): (PropMap,)| {
+                        let device = device_from_options(&options);
+                        let mtu = prop_cast(&options, "mtu").copied().unwrap_or(DEFAULT_MTU);
+                        let notifier = cr
+                            .data_mut::<AttributeObject>(ctx.path())
+                            .and_then(|characteristic| characteristic.notifier.clone());
+                        async move {
+                            let result = match notifier {
+                                Some(notifier) => notifier.acquire(device, mtu).map(|fd| (fd, mtu)),
+                                None => Err(GattError::NotSupported),
+                            };
+                            ctx.reply(result.map_err(MethodErr::from))
+                        }
+                    },
+                );
+            }
+        },
+    )
+}
+
+/// Add the properties and methods common to characteristics and descriptors to an interface, with
+/// the given name for the property pointing to the parent object.
+fn attribute_interface(b: &mut IfaceBuilder<AttributeObject>, parent_property: &'static str) {
+    b.property("UUID")
+        .get(|_, attribute| Ok(attribute.uuid.to_string()));
+    b.property(parent_property)
+        .get(|_, attribute| Ok(attribute.parent.clone()));
+    b.property("Flags")
+        .get(|_, attribute| Ok(attribute.flags.clone()));
+    b.method_with_cr_async(
+        "ReadValue",
+        ("options",),
+        ("value",),
+        |mut ctx, cr, (options,): (PropMap,)| {
+            let request = ReadRequest::from_options(&options);
+            let (handler, notifier) = cr
+                .data_mut::<AttributeObject>(ctx.path())
+                .map(|attribute| (attribute.handlers.read.clone(), attribute.notifier.clone()))
+                .unwrap_or_default();
+            async move {
+                let result = match (handler, notifier) {
+                    (Some(handler), _) => handler(request).await,
+                    (None, Some(notifier)) => read_from_offset(notifier.value(), request.offset),
+                    (None, None) => Err(GattError::NotSupported),
+                };
+                let result = result.map(|value| (value,));
+                ctx.reply(result.map_err(MethodErr::from))
+            }
+        },
+    );
+    b.method_with_cr_async(
+        "WriteValue",
+        ("value", "options"),
+        (),
+        |mut ctx, cr, (value, options): (Vec<u8>, PropMap)| {
+            let request = WriteRequest::from_options(&options);
+            let handler = cr
+                .data_mut::<AttributeObject>(ctx.path())
+                .and_then(|attribute| attribute.handlers.write.clone());
+            async move {
+                let result = match handler {
+                    Some(handler) => handler(value, request).await,
+                    None => Err(GattError::NotSupported),
+                };
+                ctx.reply(result.map_err(MethodErr::from))
+            }
+        },
+    );
+}
+
+impl GattApplication {
+    /// Build the tree of objects to export for the application, under the given root path. Also
+    /// returns the notifier and path of each characteristic.
+    fn to_crossroads(
+        &self,
+        root: &Path<'static>,
+    ) -> (Crossroads, Vec<(CharacteristicNotifier, Path<'static>)>) {
+        let mut cr = Crossroads::new();
+        let service_token = cr.register(
+            ORG_BLUEZ_GATT_SERVICE1_NAME,
+            |b: &mut IfaceBuilder<ServiceObject>| {
+                b.property("UUID")
+                    .get(|_, service| Ok(service.uuid.to_string()));
+                b.property("Primary").get(|_, service| Ok(service.primary));
+            },
+        );
+        let characteristic_token = register_characteristic_interface(&mut cr, false);
+        let acquire_notify_characteristic_token = register_characteristic_interface(&mut cr, true);
+        let descriptor_token = cr.register(
+            ORG_BLUEZ_GATT_DESCRIPTOR1_NAME,
+            |b: &mut IfaceBuilder<AttributeObject>| attribute_interface(b, "Characteristic"),
+        );
+
+        let mut characteristics = vec![];
+
+        let object_manager = cr.object_manager();
+        cr.insert(root.clone(), &[object_manager], ());
+        for (service_index, service) in self.services.iter().enumerate() {
+            let service_path: Path<'static> = format!("{}/service{}", root, service_index).into();
+            cr.insert(
+                service_path.clone(),
+                &[service_token],
+                ServiceObject {
+                    uuid: service.uuid,
+                    primary: service.primary,
+                },
+            );
+            for (characteristic_index, characteristic) in service.characteristics.iter().enumerate()
+            {
+                let characteristic_path: Path<'static> =
+                    format!("{}/char{}", service_path, characteristic_index).into();
+                cr.insert(
+                    characteristic_path.clone(),
+                    &[if characteristic.acquire_notify {
+                        acquire_notify_characteristic_token
+                    } else {
+                        characteristic_token
+                    }],
+                    AttributeObject {
+                        uuid: characteristic.uuid,
+                        parent: service_path.clone(),
+                        flags: characteristic.flags.to_strings(),
+                        handlers: characteristic.handlers.clone(),
+                        notifier: Some(characteristic.notifier.clone()),
+                    },
+                );
+                characteristics
+                    .push((characteristic.notifier.clone(), characteristic_path.clone()));
+                for (descriptor_index, descriptor) in characteristic.descriptors.iter().enumerate()
+                {
+                    cr.insert(
+                        format!("{}/desc{}", characteristic_path, descriptor_index),
+                        &[descriptor_token],
+                        AttributeObject {
+                            uuid: descriptor.uuid,
+                            parent: characteristic_path.clone(),
+                            flags: descriptor.flags.to_strings(),
+                            handlers: descriptor.handlers.clone(),
+                            notifier: None,
+                        },
+                    );
+                }
+            }
+        }
+        (cr, characteristics)
+    }
+}
+
+/// A handle to a [`GattApplication`] registered with an adapter by
+/// [`BluetoothSession::register_gatt_application`](struct.BluetoothSession.html#method.register_gatt_application).
+///
+/// The application is unregistered when this is dropped.
+#[must_use = "the application is unregistered when the GattApplicationHandle is dropped"]
+pub struct GattApplicationHandle {
+    session: BluetoothSession,
+    runtime: Handle,
+    adapter: AdapterId,
+    path: Path<'static>,
+    token: Option<Token>,
+    characteristics: Vec<(CharacteristicNotifier, Path<'static>)>,
+}
+
+impl Debug for GattApplicationHandle {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("GattApplicationHandle")
+            .field("adapter", &self.adapter)
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl GattApplicationHandle {
+    /// Export the given application and register it with the given adapter.
+    pub(crate) async fn register(
+        session: &BluetoothSession,
+        adapter: &AdapterId,
+        application: &GattApplication,
+    ) -> Result<Self, BluetoothError> {
+        let path = unique_path("application");
+        let (cr, characteristics) = application.to_crossroads(&path);
+        let token = serve(session, &path, cr);
+        for (notifier, characteristic_path) in &characteristics {
+            notifier.export(session.connection.clone(), characteristic_path.clone());
+        }
+        if let Err(e) = gatt_manager(session, adapter)
+            .register_application(path.clone(), PropMap::new())
+            .await
+        {
+            unexport_all(&characteristics);
+            unserve(session, token);
+            return Err(e.into());
+        }
+        Ok(Self {
+            session: session.clone(),
+            runtime: Handle::current(),
+            adapter: adapter.to_owned(),
+            path,
+            token: Some(token),
+            characteristics,
+        })
+    }
+
+    /// The adapter with which the application is registered.
+    pub fn adapter(&self) -> &AdapterId {
+        &self.adapter
+    }
+
+    /// Unregister the application, and wait until BlueZ has done so. This is the same as dropping
+    /// the handle, except that it waits and reports any error.
+    pub async fn unregister(mut self) -> Result<(), BluetoothError> {
+        unexport_all(&self.characteristics);
+        match self.token.take() {
+            Some(token) => unregister(&self.session, &self.adapter, &self.path, token).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for GattApplicationHandle {
+    fn drop(&mut self) {
+        unexport_all(&self.characteristics);
+        if let Some(token) = self.token.take() {
+            let session = self.session.clone();
+            let adapter = self.adapter.clone();
+            let path = self.path.clone();
+            self.runtime.spawn(async move {
+                if let Err(e) = unregister(&session, &adapter, &path, token).await {
+                    log::error!("Unregistering GATT application {} failed: {}", path, e);
+                }
+            });
+        }
+    }
+}
+
+fn gatt_manager(session: &BluetoothSession, adapter: &AdapterId) -> impl OrgBluezGattManager1 {
+    Proxy::new(
+        "org.bluez",
+        adapter.object_path.to_owned(),
+        DBUS_METHOD_CALL_TIMEOUT,
+        session.connection.clone(),
+    )
+}
+
+fn unexport_all(characteristics: &[(CharacteristicNotifier, Path<'static>)]) {
+    for (notifier, path) in characteristics {
+        notifier.unexport(path);
+    }
+}
+
+async fn unregister(
+    session: &BluetoothSession,
+    adapter: &AdapterId,
+    path: &Path<'static>,
+    token: Token,
+) -> Result<(), BluetoothError> {
+    let result = gatt_manager(session, adapter)
+        .unregister_application(path.clone())
+        .await;
+    // Keep serving until BlueZ has finished with the application.
+    unserve(session, token);
+    Ok(result?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::RecordingSender;
+    use crate::uuid_from_u16;
+    use dbus::arg::{RefArg, Variant};
+    use dbus::Message;
+    use std::collections::HashMap;
+
+    #[test]
+    fn request_options() {
+        let mut options: PropMap = HashMap::new();
+        options.insert(
+            "device".to_owned(),
+            Variant(Box::new(Path::from(
+                "/org/bluez/hci0/dev_11_22_33_44_55_66",
+            ))),
+        );
+        options.insert("offset".to_owned(), Variant(Box::new(4u16)));
+        options.insert("type".to_owned(), Variant(Box::new("command".to_owned())));
+        assert_eq!(
+            ReadRequest::from_options(&options),
+            ReadRequest {
+                device: Some(DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66")),
+                offset: 4,
+                mtu: None,
+            }
+        );
+        assert_eq!(
+            WriteRequest::from_options(&options),
+            WriteRequest {
+                device: Some(DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66")),
+                offset: 4,
+                mtu: None,
+                write_type: Some(WriteType::WithoutResponse),
+                prepare_authorize: false,
+            }
+        );
+        assert_eq!(
+            WriteRequest::from_options(&HashMap::new()),
+            WriteRequest::default()
+        );
+    }
+
+    #[test]
+    fn read_cached_value() {
+        assert_eq!(read_from_offset(vec![1, 2, 3], 0), Ok(vec![1, 2, 3]));
+        assert_eq!(read_from_offset(vec![1, 2, 3], 3), Ok(vec![]));
+        assert_eq!(
+            read_from_offset(vec![1, 2, 3], 4),
+            Err(GattError::InvalidOffset)
+        );
+    }
+
+    #[test]
+    fn managed_objects() {
+        let application = GattApplication::new().service(
+            LocalService::new(uuid_from_u16(0x180f)).characteristic(
+                LocalCharacteristic::new(
+                    uuid_from_u16(0x2a19),
+                    CharacteristicFlags::READ | CharacteristicFlags::NOTIFY,
+                )
+                .descriptor(LocalDescriptor::new(
+                    uuid_from_u16(0x2901),
+                    DescriptorFlags::READ,
+                )),
+            ),
+        );
+        let root = Path::from("/test/application0");
+        let (mut cr, characteristics) = application.to_crossroads(&root);
+        assert_eq!(characteristics.len(), 1);
+
+        let mut message = Message::new_method_call(
+            ":1.0",
+            root.clone(),
+            "org.freedesktop.DBus.ObjectManager",
+            "GetManagedObjects",
+        )
+        .unwrap();
+        message.set_serial(1);
+        let sender = RecordingSender::default();
+        cr.handle_message(message, &sender).unwrap();
+        let replies = sender.0.into_inner().unwrap();
+        let objects: HashMap<Path<'static>, HashMap<String, PropMap>> = replies[0].read1().unwrap();
+
+        let mut paths: Vec<_> = objects.keys().map(|path| path.to_string()).collect();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                "/test/application0/service0",
+                "/test/application0/service0/char0",
+                "/test/application0/service0/char0/desc0",
+            ]
+        );
+        let characteristic = &objects[&Path::from("/test/application0/service0/char0")]
+            [ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME];
+        assert_eq!(
+            characteristic["UUID"].as_str(),
+            Some("00002a19-0000-1000-8000-00805f9b34fb")
+        );
+        assert_eq!(
+            characteristic["Service"].as_str(),
+            Some("/test/application0/service0")
+        );
+        let flags: Vec<_> = characteristic["Flags"]
+            .0
+            .as_iter()
+            .unwrap()
+            .map(|flag| flag.as_str().unwrap().to_owned())
+            .collect();
+        assert_eq!(flags, vec!["read", "notify"]);
+    }
+}
@@ -0,0 +1,283 @@
+use bluez_generated::ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME;
+use dbus::arg::{PropMap, Variant};
+use dbus::channel::Sender;
+use dbus::message::SignalArgs;
+use dbus::nonblock::stdintf::org_freedesktop_dbus::PropertiesPropertiesChanged;
+use dbus::nonblock::SyncConnection;
+use dbus::Path;
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use socket2::{Domain, Socket, Type};
+use std::fmt::{self, Debug, Formatter};
+use std::io::ErrorKind;
+use std::mem::MaybeUninit;
+use std::os::unix::io::OwnedFd;
+use std::sync::{Arc, Mutex};
+use tokio::io::unix::AsyncFd;
+
+use super::GattError;
+use crate::{BluetoothError, DeviceId};
+
+/// An event about remote devices subscribing to or unsubscribing from notifications or
+/// indications of a local characteristic, from
+/// [`CharacteristicNotifier::subscription_events`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum SubscriptionEvent {
+    /// A remote device has subscribed, and BlueZ has started forwarding notifications.
+    Subscribed,
+    /// The last remote device has unsubscribed, and BlueZ has stopped forwarding notifications.
+    Unsubscribed,
+    /// A remote device has subscribed, and BlueZ has acquired a socket to receive notifications
+    /// through.
+    Acquired {
+        /// The device which subscribed, if BlueZ reported it.
+        device: Option<DeviceId>,
+        /// The exchanged MTU of the connection.
+        mtu: u16,
+    },
+    /// BlueZ has closed the socket which it acquired, because remote devices have unsubscribed.
+    Released,
+}
+
+/// A characteristic of a registered application which is exported on some D-Bus connection.
+struct Export {
+    connection: Arc<SyncConnection>,
+    path: Path<'static>,
+}
+
+#[derive(Default)]
+struct NotifierState {
+    value: Vec<u8>,
+    notifying: bool,
+    exports: Vec<Export>,
+    socket: Option<Arc<AsyncFd<Socket>>>,
+    subscribers: Vec<UnboundedSender<SubscriptionEvent>>,
+}
+
+impl NotifierState {
+    fn send_event(&mut self, event: SubscriptionEvent) {
+        self.subscribers
+            .retain(|subscriber| subscriber.unbounded_send(event.clone()).is_ok());
+    }
+}
+
+/// A handle to send notifications or indications from a local characteristic to subscribed
+/// remote devices, obtained from [`LocalCharacteristic::notifier`](struct.LocalCharacteristic.html#method.notifier).
+///
+/// This is cheap to clone, and all clones refer to the same characteristic.
+#[derive(Clone, Default)]
+pub struct CharacteristicNotifier {
+    state: Arc<Mutex<NotifierState>>,
+}
+
+impl Debug for CharacteristicNotifier {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let state = self.state.lock().unwrap();
+        f.debug_struct("CharacteristicNotifier")
+            .field("value", &state.value)
+            .field("notifying", &state.notifying)
+            .field("acquired", &state.socket.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl CharacteristicNotifier {
+    /// Get the current value of the characteristic, as last set by `notify`.
+    ///
+    /// This is also the value of the characteristic's D-Bus `Value` property. It is not updated by
+    /// the characteristic's read handler, if it has one.
+    pub fn value(&self) -> Vec<u8> {
+        self.state.lock().unwrap().value.clone()
+    }
+
+    /// Returns whether any remote devices are currently subscribed to the characteristic, either
+    /// through BlueZ or through an acquired socket.
+    pub fn is_notifying(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        state.notifying || state.socket.is_some()
+    }
+
+    /// Set the value of the characteristic, and send it to any subscribed remote devices.
+    ///
+    /// If BlueZ has acquired a socket for notifications then the value is sent through that,
+    /// otherwise the `Value` property is updated and a `PropertiesChanged` signal emitted, which
+    /// BlueZ forwards to subscribed devices.
+    pub fn notify(&self, value: Vec<u8>) -> Result<(), BluetoothError> {
+        let mut state = self.state.lock().unwrap();
+        state.value = value;
+        if let Some(socket) = &state.socket {
+            match socket
+                .get_ref()
+                .send_with_flags(&state.value, libc::MSG_NOSIGNAL)
+            {
+                Ok(_) => return Ok(()),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => {
+                    log::warn!("Notification socket is full, dropping notification");
+                    return Ok(());
+                }
+                Err(e) => {
+                    log::debug!("Notification socket closed: {}", e);
+                    state.socket = None;
+                    state.send_event(SubscriptionEvent::Released);
+                }
+            }
+        }
+        for export in &state.exports {
+            let mut changed_properties: PropMap = PropMap::new();
+            changed_properties.insert("Value".to_owned(), Variant(Box::new(state.value.clone())));
+            let message = PropertiesPropertiesChanged {
+                interface_name: ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME.to_owned(),
+                changed_properties,
+                invalidated_properties: vec![],
+            }
+            .to_emit_message(&export.path);
+            export
+                .connection
+                .send(message)
+                .map_err(|()| dbus::Error::new_failed("Failed to send PropertiesChanged signal"))?;
+        }
+        Ok(())
+    }
+
+    /// Get a stream of events for remote devices subscribing to and unsubscribing from the
+    /// characteristic, from now on.
+    pub fn subscription_events(&self) -> UnboundedReceiver<SubscriptionEvent> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.state.lock().unwrap().subscribers.push(sender);
+        receiver
+    }
+
+    /// Set the initial value of the characteristic, without notifying anyone.
+    pub(crate) fn set_value(&self, value: Vec<u8>) {
+        self.state.lock().unwrap().value = value;
+    }
+
+    /// Record that the characteristic has been exported at the given path, so `PropertiesChanged`
+    /// signals should be sent for it.
+    pub(crate) fn export(&self, connection: Arc<SyncConnection>, path: Path<'static>) {
+        self.state
+            .lock()
+            .unwrap()
+            .exports
+            .push(Export { connection, path });
+    }
+
+    /// Record that the characteristic is no longer exported at the given path.
+    pub(crate) fn unexport(&self, path: &Path<'static>) {
+        self.state
+            .lock()
+            .unwrap()
+            .exports
+            .retain(|export| &export.path != path);
+    }
+
+    /// Handle a `StartNotify` or `StopNotify` call from BlueZ.
+    pub(crate) fn set_notifying(&self, notifying: bool) {
+        let mut state = self.state.lock().unwrap();
+        if state.notifying != notifying {
+            state.notifying = notifying;
+            state.send_event(if notifying {
+                SubscriptionEvent::Subscribed
+            } else {
+                SubscriptionEvent::Unsubscribed
+            });
+        }
+    }
+
+    /// Returns whether BlueZ currently has an acquired socket for notifications.
+    pub(crate) fn is_acquired(&self) -> bool {
+        self.state.lock().unwrap().socket.is_some()
+    }
+
+    /// Handle an `AcquireNotify` call from BlueZ, returning the end of a new socket pair for BlueZ
+    /// to receive notifications through.
+    ///
+    /// This must be called within a tokio runtime.
+    pub(crate) fn acquire(&self, device: Option<DeviceId>, mtu: u16) -> Result<OwnedFd, GattError> {
+        let mut state = self.state.lock().unwrap();
+        if state.socket.is_some() {
+            return Err(GattError::NotPermitted);
+        }
+        let (ours, theirs) = Socket::pair(Domain::UNIX, Type::SEQPACKET, None)
+            .map_err(|e| GattError::Failed(e.to_string()))?;
+        ours.set_nonblocking(true)
+            .map_err(|e| GattError::Failed(e.to_string()))?;
+        let ours = Arc::new(AsyncFd::new(ours).map_err(|e| GattError::Failed(e.to_string()))?);
+        state.socket = Some(ours.clone());
+        state.send_event(SubscriptionEvent::Acquired { device, mtu });
+        tokio::spawn(self.clone().watch_socket(ours));
+        Ok(theirs.into())
+    }
+
+    /// Wait until BlueZ closes its end of the given socket, then forget about it.
+    async fn watch_socket(self, socket: Arc<AsyncFd<Socket>>) {
+        while let Ok(mut guard) = socket.readable().await {
+            let mut buffer = [MaybeUninit::uninit(); 32];
+            match guard.try_io(|socket| socket.get_ref().recv(&mut buffer)) {
+                // BlueZ doesn't send anything, so just ignore it if it does.
+                Ok(Ok(length)) if length > 0 => {}
+                Ok(_) => break,
+                Err(_would_block) => {}
+            }
+        }
+        let mut state = self.state.lock().unwrap();
+        if matches!(&state.socket, Some(current) if Arc::ptr_eq(current, &socket)) {
+            state.socket = None;
+            state.send_event(SubscriptionEvent::Released);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use std::io::Read;
+    use std::os::unix::net::UnixStream;
+
+    #[tokio::test]
+    async fn start_stop_notify() {
+        let notifier = CharacteristicNotifier::default();
+        let mut events = notifier.subscription_events();
+        notifier.notify(vec![1, 2]).unwrap();
+        assert_eq!(notifier.value(), vec![1, 2]);
+        assert!(!notifier.is_notifying());
+
+        notifier.set_notifying(true);
+        assert!(notifier.is_notifying());
+        notifier.set_notifying(false);
+        assert_eq!(events.next().await, Some(SubscriptionEvent::Subscribed));
+        assert_eq!(events.next().await, Some(SubscriptionEvent::Unsubscribed));
+    }
+
+    #[tokio::test]
+    async fn acquire_notify() {
+        let notifier = CharacteristicNotifier::default();
+        let mut events = notifier.subscription_events();
+        let fd = notifier.acquire(None, 23).unwrap();
+        assert!(notifier.is_notifying());
+        assert_eq!(
+            notifier.acquire(None, 23).unwrap_err(),
+            GattError::NotPermitted
+        );
+
+        // Notifications are sent through the socket.
+        notifier.notify(vec![42, 43]).unwrap();
+        let mut theirs = UnixStream::from(fd);
+        let mut buffer = [0; 10];
+        assert_eq!(theirs.read(&mut buffer).unwrap(), 2);
+        assert_eq!(&buffer[..2], &[42, 43]);
+
+        // Closing the socket releases it.
+        drop(theirs);
+        assert_eq!(
+            events.next().await,
+            Some(SubscriptionEvent::Acquired {
+                device: None,
+                mtu: 23
+            })
+        );
+        assert_eq!(events.next().await, Some(SubscriptionEvent::Released));
+        assert!(!notifier.is_notifying());
+    }
+}
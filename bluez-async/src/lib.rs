@@ -1,44 +1,117 @@
 //! An async wrapper around the D-Bus interface of BlueZ, the Linux Bluetooth daemon. This provides
 //! type-safe interfaces to a subset of the Bluetooth client (i.e. central, in Bluetooth
-//! terminology) interfaces exposed by BlueZ, focussing on the Generic Attribute Profile (GATT) of
-//! Bluetooth Low Energy (BLE).
+//! terminology) and server (i.e. peripheral) interfaces exposed by BlueZ, focussing on the Generic
+//! Attribute Profile (GATT) of Bluetooth Low Energy (BLE). As well as connecting to remote devices,
+//! it can export local GATT services for remote devices to use, with [`GattApplication`], and send
+//! LE advertisements with [`Advertisement`].
 //!
 //! Start by creating a [`BluetoothSession`].
 //!
 //! [`BluetoothSession']: struct.BluetoothSession.html
+//! [`GattApplication`]: struct.GattApplication.html
+//! [`Advertisement`]: struct.Advertisement.html
 
 mod adapter;
+pub mod advertisement;
+mod advertising;
+mod appearance;
+#[cfg(feature = "assigned-numbers")]
+pub mod assigned_numbers;
 mod bleuuid;
 mod characteristic;
+mod class_of_device;
 mod descriptor;
 mod device;
+mod device_information;
+mod discovery;
 mod events;
+mod export;
+mod gatt_server;
 mod introspect;
 mod macaddress;
+mod media;
 mod messagestream;
 mod modalias;
+mod monitor;
+mod network;
+mod presence;
+mod profile;
+mod profile_server;
+mod queue;
+mod retry;
+mod rssi;
+mod scanner;
 mod serde_path;
 mod service;
 
 pub use self::adapter::{AdapterId, AdapterInfo};
+pub use self::advertising::{
+    Advertisement, AdvertisementHandle, AdvertisementType, AdvertisingCapabilities,
+    AdvertisingIncludes, SecondaryChannel,
+};
+pub use self::appearance::{Appearance, AppearanceCategory};
 pub use self::bleuuid::{uuid_from_u16, uuid_from_u32, BleUuid};
 pub use self::characteristic::{CharacteristicFlags, CharacteristicId, CharacteristicInfo};
-pub use self::descriptor::{DescriptorId, DescriptorInfo};
+pub use self::class_of_device::{ClassOfDevice, MajorDeviceClass, ServiceClasses};
+pub use self::descriptor::{DescriptorFlags, DescriptorId, DescriptorInfo};
 pub use self::device::{AddressType, DeviceId, DeviceInfo};
-pub use self::events::{AdapterEvent, BluetoothEvent, CharacteristicEvent, DeviceEvent};
+pub use self::device_information::{
+    DeviceInformation, PnpId, SystemId, VendorIdSource, DEVICE_INFORMATION_SERVICE_UUID,
+};
+use self::discovery::DiscoveryRegistry;
+pub use self::discovery::ScanGuard;
+pub use self::events::{
+    AdapterEvent, BluetoothEvent, CharacteristicEvent, DeviceEvent, MediaPlayerEvent,
+};
+pub use self::gatt_server::{
+    CharacteristicNotifier, GattApplication, GattApplicationHandle, GattError, LocalCharacteristic,
+    LocalDescriptor, LocalService, ReadHandler, ReadRequest, SubscriptionEvent, WriteHandler,
+    WriteRequest,
+};
 use self::introspect::IntrospectParse;
 pub use self::macaddress::{MacAddress, ParseMacAddressError};
+pub use self::media::{
+    MediaPlayerId, MediaPlayerInfo, PlaybackStatus, RepeatMode, ShuffleMode, TrackInfo,
+};
 use self::messagestream::MessageStream;
-pub use self::modalias::{Modalias, ParseModaliasError};
+pub use self::modalias::{Modalias, ModaliasSource, ParseModaliasError};
+use self::monitor::MonitorRegistration;
+pub use self::monitor::{AdvertisementMonitor, MonitorEvent, MonitorPattern};
+pub use self::network::{NetworkInfo, NetworkServerHandle, PanRole};
+pub use self::presence::{PresenceConfig, PresenceEvent, PresenceTracker};
+#[doc(hidden)]
+pub use self::profile::__private;
+pub use self::profile::GattValue;
+use self::profile_server::ProfileRegistration;
+pub use self::profile_server::{
+    BluetoothStream, ProfileConnection, ProfileEvent, ProfileOptions, ProfileRole,
+    SERIAL_PORT_PROFILE_UUID,
+};
+use self::queue::OperationQueue;
+pub use self::queue::{OperationPriority, OperationQueueConfig};
+pub use self::retry::{RetryAttempt, RetryCallback, RetryPolicy, RetryPredicate};
+pub use self::rssi::{
+    DistanceEstimator, RssiConfig, RssiFilter, RssiReading, RssiSmoother, RssiStreamExt,
+    SmoothedRssi,
+};
+use self::scanner::{is_advertisement_event, ScanState};
+pub use self::scanner::{ScanResult, Scanner};
 pub use self::service::{ServiceId, ServiceInfo};
 use bluez_generated::{
-    OrgBluezAdapter1, OrgBluezAdapter1Properties, OrgBluezDevice1, OrgBluezDevice1Properties,
-    OrgBluezGattCharacteristic1, OrgBluezGattCharacteristic1Properties, OrgBluezGattDescriptor1,
-    OrgBluezGattService1, ORG_BLUEZ_ADAPTER1_NAME, ORG_BLUEZ_DEVICE1_NAME,
-    ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME,
+    OrgBluezAdapter1, OrgBluezAdapter1Properties, OrgBluezBattery1Properties, OrgBluezDevice1,
+    OrgBluezDevice1Properties, OrgBluezGattCharacteristic1, OrgBluezGattCharacteristic1Properties,
+    OrgBluezGattDescriptor1, OrgBluezGattService1, OrgBluezLEAdvertisingManager1Properties,
+    OrgBluezMediaControl1Properties, OrgBluezMediaPlayer1, OrgBluezMediaPlayer1Properties,
+    OrgBluezNetwork1, OrgBluezNetwork1Properties, ORG_BLUEZ_ADAPTER1_NAME, ORG_BLUEZ_BATTERY1_NAME,
+    ORG_BLUEZ_DEVICE1_NAME, ORG_BLUEZ_GATT_CHARACTERISTIC1_NAME,
+    ORG_BLUEZ_LEADVERTISING_MANAGER1_NAME, ORG_BLUEZ_MEDIA_CONTROL1_NAME,
+    ORG_BLUEZ_MEDIA_PLAYER1_NAME, ORG_BLUEZ_NETWORK1_NAME,
+};
+use dbus::arg::{prop_cast, PropMap, Variant};
+use dbus::message::SignalArgs;
+use dbus::nonblock::stdintf::org_freedesktop_dbus::{
+    Introspectable, ObjectManager, Properties, PropertiesPropertiesChanged,
 };
-use dbus::arg::{PropMap, Variant};
-use dbus::nonblock::stdintf::org_freedesktop_dbus::{Introspectable, ObjectManager, Properties};
 use dbus::nonblock::{Proxy, SyncConnection};
 use dbus::Path;
 use dbus_tokio::connection::IOResourceError;
@@ -59,6 +132,9 @@ const DBUS_METHOD_CALL_TIMEOUT: Duration = Duration::from_secs(30);
 // 0x7fffffff (the largest 32-bit signed integer) or INT32_MAX
 const DBUS_METHOD_CALL_MAX_TIMEOUT: Duration = Duration::from_secs(i32::MAX as u64);
 const SERVICE_DISCOVERY_TIMEOUT: Duration = Duration::from_secs(5);
+const PROFILE_STATE_TIMEOUT: Duration = Duration::from_secs(5);
+const BATTERY_SERVICE_UUID: Uuid = uuid_from_u16(0x180f);
+const BATTERY_LEVEL_CHARACTERISTIC_UUID: Uuid = uuid_from_u16(0x2a19);
 
 /// An error carrying out a Bluetooth operation.
 #[derive(Debug, Error)]
@@ -75,6 +151,12 @@ pub enum BluetoothError {
     /// No service or characteristic was found for some UUID.
     #[error("Service or characteristic UUID {uuid} not found.")]
     UuidNotFound { uuid: Uuid },
+    /// Some required characteristics were not found on a device.
+    #[error("Characteristic UUIDs {uuids:?} not found.")]
+    CharacteristicsNotFound { uuids: Vec<Uuid> },
+    /// The value of a characteristic or descriptor couldn't be decoded.
+    #[error("Invalid value: {0}")]
+    InvalidValue(String),
     /// Error parsing a UUID from a string.
     #[error("Error parsing UUID string: {0}")]
     UuidParseError(#[from] uuid::Error),
@@ -96,8 +178,75 @@ pub enum BluetoothError {
     /// Error parsing a `Modalias` from a string.
     #[error(transparent)]
     ModaliasParseError(#[from] ParseModaliasError),
+    /// Too many operations were already waiting in the operation queue for the device.
+    #[error("Operation queue for device {device} is full")]
+    OperationQueueFull { device: DeviceId },
+    /// A field of a `DiscoveryFilter` was set which the version of BlueZ in use doesn't support.
+    #[error("Discovery filter field {field} ({key}) is not supported by this version of BlueZ")]
+    UnsupportedDiscoveryFilterField {
+        /// The name of the field of `DiscoveryFilter`.
+        field: &'static str,
+        /// The corresponding BlueZ filter key.
+        key: &'static str,
+    },
+    /// The device doesn't have the given profile, or BlueZ has no plugin to connect it.
+    #[error("Profile {uuid} not available on device {device}")]
+    ProfileUnavailable { device: DeviceId, uuid: Uuid },
+    /// The given profile can't be connected or disconnected on its own.
+    #[error("Profile {uuid} of device {device} can't be connected or disconnected individually")]
+    ProfileNotSupported { device: DeviceId, uuid: Uuid },
+    /// The given profile didn't report that it was connected or disconnected within the time limit.
+    #[error("Timed out waiting for profile {uuid} of device {device} to {}", if *.connected { "connect" } else { "disconnect" })]
+    ProfileStateTimedOut {
+        device: DeviceId,
+        uuid: Uuid,
+        /// The state which was expected.
+        connected: bool,
+    },
 }
 
+impl BluetoothError {
+    /// Returns whether the error is likely to be transient, such that retrying the same operation
+    /// may succeed.
+    ///
+    /// This includes service discovery timeouts, operations rejected because another is already in
+    /// progress, D-Bus timeouts, and connection failures such as `le-connection-abort-by-local` and
+    /// page timeouts. Note that after a D-Bus timeout the operation may still have been carried
+    /// out, so `RetryPolicy` doesn't retry writes which fail with one by default.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::ServiceDiscoveryTimedOut | Self::ProfileStateTimedOut { .. } => true,
+            Self::DbusError(error) => match error.name() {
+                Some("org.bluez.Error.InProgress")
+                | Some("org.bluez.Error.NotReady")
+                | Some("org.freedesktop.DBus.Error.NoReply") => true,
+                Some("org.bluez.Error.Failed") => error.message().is_some_and(|message| {
+                    TRANSIENT_FAILURE_MESSAGES
+                        .iter()
+                        .any(|transient| message.contains(transient))
+                }),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns whether the error is a D-Bus timeout, in which case the operation may or may not
+    /// have been carried out.
+    pub(crate) fn is_no_reply(&self) -> bool {
+        matches!(self, Self::DbusError(error) if error.name() == Some("org.freedesktop.DBus.Error.NoReply"))
+    }
+}
+
+/// Messages of `org.bluez.Error.Failed` errors which indicate a transient connection failure.
+const TRANSIENT_FAILURE_MESSAGES: &[&str] = &[
+    "le-connection-abort-by-local",
+    "br-connection-page-timeout",
+    "Page Timeout",
+    "Software caused connection abort",
+    "Operation already in progress",
+];
+
 /// Error type for futures representing tasks spawned by this crate.
 #[derive(Debug, Error)]
 pub enum SpawnError {
@@ -158,6 +307,94 @@ pub struct DiscoveryFilter {
     pub pattern: Option<String>,
 }
 
+impl DiscoveryFilter {
+    /// The BlueZ keys of all the fields, along with their field names.
+    const FIELDS: [(&'static str, &'static str); 7] = [
+        ("UUIDs", "service_uuids"),
+        ("RSSI", "rssi_threshold"),
+        ("Pathloss", "pathloss_threshold"),
+        ("Transport", "transport"),
+        ("DuplicateData", "duplicate_data"),
+        ("Discoverable", "discoverable"),
+        ("Pattern", "pattern"),
+    ];
+
+    fn is_set(&self, key: &str) -> bool {
+        match key {
+            "UUIDs" => !self.service_uuids.is_empty(),
+            "RSSI" => self.rssi_threshold.is_some(),
+            "Pathloss" => self.pathloss_threshold.is_some(),
+            "Transport" => self.transport.is_some(),
+            "DuplicateData" => self.duplicate_data.is_some(),
+            "Discoverable" => self.discoverable.is_some(),
+            "Pattern" => self.pattern.is_some(),
+            _ => false,
+        }
+    }
+
+    fn clear(&mut self, key: &str) {
+        match key {
+            "UUIDs" => self.service_uuids.clear(),
+            "RSSI" => self.rssi_threshold = None,
+            "Pathloss" => self.pathloss_threshold = None,
+            "Transport" => self.transport = None,
+            "DuplicateData" => self.duplicate_data = None,
+            "Discoverable" => self.discoverable = None,
+            "Pattern" => self.pattern = None,
+            _ => {}
+        }
+    }
+
+    /// Get the BlueZ keys and field names of the fields which are set but not in the given list of
+    /// supported keys.
+    fn unsupported_fields<'a>(
+        &'a self,
+        supported_keys: &'a [String],
+    ) -> impl Iterator<Item = (&'static str, &'static str)> + 'a {
+        Self::FIELDS.iter().copied().filter(move |(key, _)| {
+            self.is_set(key) && !supported_keys.iter().any(|supported| supported == key)
+        })
+    }
+
+    /// Check that every field which is set is in the given list of supported BlueZ filter keys, as
+    /// returned by `BluetoothSession::get_discovery_filters`.
+    ///
+    /// Fails with `BluetoothError::UnsupportedDiscoveryFilterField` for the first field which isn't.
+    pub fn validate(&self, supported_keys: &[String]) -> Result<(), BluetoothError> {
+        match self.unsupported_fields(supported_keys).next() {
+            Some((key, field)) => {
+                Err(BluetoothError::UnsupportedDiscoveryFilterField { field, key })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Get a copy of the filter with any fields which aren't in the given list of supported BlueZ
+    /// filter keys cleared, logging a warning for each.
+    pub fn without_unsupported(&self, supported_keys: &[String]) -> DiscoveryFilter {
+        let mut filter = self.clone();
+        for (key, field) in self.unsupported_fields(supported_keys) {
+            log::warn!(
+                "Ignoring discovery filter field {} ({}) as it is not supported by this version of BlueZ",
+                field,
+                key
+            );
+            filter.clear(key);
+        }
+        filter
+    }
+}
+
+/// What to do if a `DiscoveryFilter` sets a field which the version of BlueZ in use doesn't support.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum UnsupportedFilterFieldPolicy {
+    /// Fail with `BluetoothError::UnsupportedDiscoveryFilterField`.
+    #[default]
+    Reject,
+    /// Log a warning and leave the field out of the filter.
+    Drop,
+}
+
 impl From<&DiscoveryFilter> for PropMap {
     fn from(filter: &DiscoveryFilter) -> Self {
         let mut map: PropMap = HashMap::new();
@@ -219,6 +456,15 @@ impl WriteType {
             Self::Reliable => "reliable",
         }
     }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "request" => Some(Self::WithResponse),
+            "command" => Some(Self::WithoutResponse),
+            "reliable" => Some(Self::Reliable),
+            _ => None,
+        }
+    }
 }
 
 impl Display for WriteType {
@@ -266,6 +512,11 @@ impl From<WriteOptions> for PropMap {
 #[derive(Clone)]
 pub struct BluetoothSession {
     connection: Arc<SyncConnection>,
+    operation_queue: Option<Arc<OperationQueue>>,
+    priority: OperationPriority,
+    retry_policy: Option<Arc<RetryPolicy>>,
+    discovery: Arc<DiscoveryRegistry>,
+    unsupported_filter_field_policy: UnsupportedFilterFieldPolicy,
 }
 
 impl Debug for BluetoothSession {
@@ -293,7 +544,106 @@ impl BluetoothSession {
             let err = dbus_resource.await;
             Err(SpawnError::DbusConnectionLost(err))
         });
-        Ok((dbus_handle.map(|res| res?), BluetoothSession { connection }))
+        Ok((
+            dbus_handle.map(|res| res?),
+            BluetoothSession {
+                connection,
+                operation_queue: None,
+                priority: OperationPriority::default(),
+                retry_policy: None,
+                discovery: Default::default(),
+                unsupported_filter_field_policy: UnsupportedFilterFieldPolicy::default(),
+            },
+        ))
+    }
+
+    /// Enable the per-device operation queue, with the given configuration.
+    ///
+    /// BlueZ rejects a GATT operation on a device while another is still in progress on the same
+    /// device. With the queue enabled, reads, writes, `start_notify` and `connect` calls for the same
+    /// device are run strictly one at a time (in priority order), while operations on different
+    /// devices may still run in parallel.
+    ///
+    /// This returns a new handle to the same session. The queue is shared between the returned
+    /// handle and all clones of it, but not with `self` or any other existing handles.
+    pub fn with_operation_queue(&self, config: OperationQueueConfig) -> Self {
+        Self {
+            operation_queue: Some(Arc::new(OperationQueue::new(config))),
+            ..self.clone()
+        }
+    }
+
+    /// Get a handle to the same session which retries `connect`, reads, writes and `start_notify`
+    /// according to the given policy if they fail with a retryable error.
+    ///
+    /// This is cheap, so can be used either to attach a policy to the session as a whole or for a
+    /// single call, e.g. `session.with_retry_policy(policy).connect(&device).await`.
+    pub fn with_retry_policy(&self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy: Some(Arc::new(retry_policy)),
+            ..self.clone()
+        }
+    }
+
+    /// Get a handle to the same session which submits operations to the operation queue with the
+    /// given priority.
+    ///
+    /// This has no effect unless the operation queue has been enabled with `with_operation_queue`.
+    pub fn with_priority(&self, priority: OperationPriority) -> Self {
+        Self {
+            priority,
+            ..self.clone()
+        }
+    }
+
+    /// Get a handle to the same session which handles discovery filter fields that the version of
+    /// BlueZ in use doesn't support according to the given policy. By default they are rejected.
+    pub fn with_unsupported_filter_field_policy(
+        &self,
+        unsupported_filter_field_policy: UnsupportedFilterFieldPolicy,
+    ) -> Self {
+        Self {
+            unsupported_filter_field_policy,
+            ..self.clone()
+        }
+    }
+
+    /// Get the list of discovery filter keys which the given adapter supports, e.g. `"RSSI"` or
+    /// `"Pattern"`.
+    pub async fn get_discovery_filters(
+        &self,
+        adapter_id: &AdapterId,
+    ) -> Result<Vec<String>, BluetoothError> {
+        Ok(self.adapter(adapter_id).get_discovery_filters().await?)
+    }
+
+    /// Set the discovery filter on the given adapter, after checking it against the keys which the
+    /// adapter supports and applying the `UnsupportedFilterFieldPolicy`.
+    async fn set_discovery_filter(
+        &self,
+        adapter_id: &AdapterId,
+        discovery_filter: &DiscoveryFilter,
+    ) -> Result<(), BluetoothError> {
+        let discovery_filter = match self.get_discovery_filters(adapter_id).await {
+            Ok(supported_keys) => match self.unsupported_filter_field_policy {
+                UnsupportedFilterFieldPolicy::Reject => {
+                    discovery_filter.validate(&supported_keys)?;
+                    discovery_filter.to_owned()
+                }
+                UnsupportedFilterFieldPolicy::Drop => {
+                    discovery_filter.without_unsupported(&supported_keys)
+                }
+            },
+            Err(e) => {
+                // Older versions of BlueZ don't have GetDiscoveryFilters, so just try our best.
+                log::debug!("Couldn't get supported discovery filters: {}", e);
+                discovery_filter.to_owned()
+            }
+        };
+        self.adapter(adapter_id)
+            .set_discovery_filter((&discovery_filter).into())
+            .await?;
+        Ok(())
     }
 
     /// Power on all Bluetooth adapters, remove any discovery filter, and then start scanning for
@@ -359,13 +709,52 @@ impl BluetoothSession {
     ) -> Result<(), BluetoothError> {
         let adapter = self.adapter(adapter_id);
         adapter.set_powered(true).await?;
-        adapter
-            .set_discovery_filter(discovery_filter.into())
+        self.set_discovery_filter(adapter_id, discovery_filter)
             .await?;
         adapter.start_discovery().await?;
         Ok(())
     }
 
+    /// Power on all Bluetooth adapters and start scanning for devices with the given filter, until
+    /// the returned `ScanGuard` is dropped.
+    ///
+    /// Unlike `start_discovery_with_filter`, this may safely be called from several tasks at once.
+    /// Discovery is only stopped once the last `ScanGuard` for each adapter is dropped, and in the
+    /// meantime BlueZ is given a filter which is the union of the filters of all ongoing scans. Any
+    /// further filtering must therefore be done by the caller, e.g. with a `Scanner`.
+    ///
+    /// Adapters on which discovery fails to start are logged and skipped. If it fails on all of
+    /// them then the last error is returned.
+    ///
+    /// This shouldn't be mixed with `start_discovery` and `stop_discovery` on the same session.
+    pub async fn start_scan(
+        &self,
+        discovery_filter: &DiscoveryFilter,
+    ) -> Result<ScanGuard, BluetoothError> {
+        let adapters: Vec<AdapterId> = self
+            .get_adapters()
+            .await?
+            .into_iter()
+            .map(|adapter| adapter.id)
+            .collect();
+        if adapters.is_empty() {
+            return Err(BluetoothError::NoBluetoothAdapters);
+        }
+        ScanGuard::start(self, &adapters, discovery_filter).await
+    }
+
+    /// Power on the given Bluetooth adapter and start scanning for devices with the given filter,
+    /// until the returned `ScanGuard` is dropped.
+    ///
+    /// See `start_scan` for details.
+    pub async fn start_scan_on_adapter(
+        &self,
+        adapter: &AdapterId,
+        discovery_filter: &DiscoveryFilter,
+    ) -> Result<ScanGuard, BluetoothError> {
+        ScanGuard::start(self, &[adapter.to_owned()], discovery_filter).await
+    }
+
     /// Stop scanning for devices on all Bluetooth adapters.
     pub async fn stop_discovery(&self) -> Result<(), BluetoothError> {
         let adapters = self.get_adapters().await?;
@@ -390,6 +779,83 @@ impl BluetoothSession {
         Ok(())
     }
 
+    /// Start scanning for devices with the given `Scanner`, and get a stream of results for the
+    /// devices which match its filters.
+    ///
+    /// This starts discovery in the same way as `start_scan`, and stops it again when the stream is
+    /// dropped unless other scans are still using it. Each device's details are fetched from BlueZ
+    /// when it is discovered, and then kept up to date from its property change events.
+    pub async fn scan(
+        &self,
+        scanner: &Scanner,
+    ) -> Result<impl Stream<Item = ScanResult>, BluetoothError> {
+        let events = self
+            .filtered_event_stream(scanner.get_adapter(), true)
+            .await?;
+        let guard = if let Some(adapter) = scanner.get_adapter() {
+            self.start_scan_on_adapter(adapter, scanner.get_discovery_filter())
+                .await?
+        } else {
+            self.start_scan(scanner.get_discovery_filter()).await?
+        };
+
+        let state = ScanState::new(scanner.clone());
+        let session = self.clone();
+        Ok(events
+            .filter_map(move |event| {
+                // Keep discovery running for as long as the stream is alive.
+                let _guard = &guard;
+                let state = state.clone();
+                let session = session.clone();
+                async move {
+                    let (id, event) = match event {
+                        BluetoothEvent::Device {
+                            id,
+                            event: DeviceEvent::Removed,
+                        } => {
+                            state.remove(&id);
+                            return None;
+                        }
+                        BluetoothEvent::Device { id, event } if is_advertisement_event(&event) => {
+                            (id, event)
+                        }
+                        _ => return None,
+                    };
+                    let result = match state.update(&id, &event) {
+                        Some(result) => result,
+                        None => {
+                            // Avoid fetching the device's properties if we wouldn't report it
+                            // anyway.
+                            if state.rate_limited(&id, tokio::time::Instant::now()) {
+                                return None;
+                            }
+                            match session.get_device_info(&id).await {
+                                Ok(device) => {
+                                    let result = ScanResult::from(device);
+                                    state.insert(result.clone());
+                                    result
+                                }
+                                Err(e) => {
+                                    log::warn!(
+                                        "Error getting info for scanned device {}: {}",
+                                        id,
+                                        e
+                                    );
+                                    return None;
+                                }
+                            }
+                        }
+                    };
+                    if state.should_report(&result, tokio::time::Instant::now()) {
+                        Some(result)
+                    } else {
+                        None
+                    }
+                }
+            })
+            .boxed())
+    }
+
     /// Get a list of all Bluetooth adapters on the system.
     pub async fn get_adapters(&self) -> Result<Vec<AdapterInfo>, BluetoothError> {
         let bluez_root = Proxy::new(
@@ -405,7 +871,14 @@ impl BluetoothSession {
             .into_iter()
             .filter_map(|(object_path, interfaces)| {
                 let adapter_properties = OrgBluezAdapter1Properties::from_interfaces(&interfaces)?;
-                AdapterInfo::from_properties(AdapterId { object_path }, adapter_properties).ok()
+                let advertising_properties =
+                    OrgBluezLEAdvertisingManager1Properties::from_interfaces(&interfaces);
+                AdapterInfo::from_properties(
+                    AdapterId { object_path },
+                    adapter_properties,
+                    advertising_properties,
+                )
+                .ok()
             })
             .collect())
     }
@@ -424,7 +897,13 @@ impl BluetoothSession {
             .into_iter()
             .filter_map(|(object_path, interfaces)| {
                 let device_properties = OrgBluezDevice1Properties::from_interfaces(&interfaces)?;
-                DeviceInfo::from_properties(DeviceId { object_path }, device_properties).ok()
+                let battery_properties = OrgBluezBattery1Properties::from_interfaces(&interfaces);
+                DeviceInfo::from_properties(
+                    DeviceId { object_path },
+                    device_properties,
+                    battery_properties,
+                )
+                .ok()
             })
             .collect();
         Ok(devices)
@@ -568,18 +1047,177 @@ impl BluetoothSession {
             .await
     }
 
+    /// Convenience method to look up several GATT characteristics advertised by a device at once,
+    /// given pairs of service UUID and characteristic UUID.
+    ///
+    /// The list of services is fetched only once, and the characteristics of each service at most
+    /// once. The result has an entry for each of the given pairs, in the same order, which is `None`
+    /// if no such service or characteristic was found.
+    pub async fn get_service_characteristics_by_uuids(
+        &self,
+        device: &DeviceId,
+        uuids: &[(Uuid, Uuid)],
+    ) -> Result<Vec<Option<CharacteristicInfo>>, BluetoothError> {
+        let services = self.get_services(device).await?;
+        let mut characteristics_by_service: HashMap<Uuid, Vec<CharacteristicInfo>> = HashMap::new();
+        for &(service_uuid, _) in uuids {
+            if characteristics_by_service.contains_key(&service_uuid) {
+                continue;
+            }
+            let characteristics = if let Some(service) =
+                services.iter().find(|service| service.uuid == service_uuid)
+            {
+                self.get_characteristics(&service.id).await?
+            } else {
+                vec![]
+            };
+            characteristics_by_service.insert(service_uuid, characteristics);
+        }
+        Ok(uuids
+            .iter()
+            .map(|(service_uuid, characteristic_uuid)| {
+                characteristics_by_service[service_uuid]
+                    .iter()
+                    .find(|characteristic| characteristic.uuid == *characteristic_uuid)
+                    .cloned()
+            })
+            .collect())
+    }
+
+    /// Read all the available characteristics of the GATT Device Information Service of the given
+    /// device.
+    ///
+    /// Characteristics which the device doesn't provide or which can't be read, e.g. because they
+    /// require encryption, are left as `None`. Fails with `BluetoothError::UuidNotFound` if the
+    /// device doesn't have a Device Information Service at all. Note that this generally won't work
+    /// until the device is connected.
+    pub async fn read_device_information(
+        &self,
+        device: &DeviceId,
+    ) -> Result<DeviceInformation, BluetoothError> {
+        let service = self
+            .get_service_by_uuid(device, DEVICE_INFORMATION_SERVICE_UUID)
+            .await?;
+        let characteristics = self.get_characteristics(&service.id).await?;
+        DeviceInformation::read(
+            characteristics
+                .into_iter()
+                .map(|characteristic| (characteristic.uuid, characteristic.id)),
+            |id| async move { self.read_characteristic_value(&id).await },
+        )
+        .await
+    }
+
     /// Get information about the given Bluetooth device.
     pub async fn get_device_info(&self, id: &DeviceId) -> Result<DeviceInfo, BluetoothError> {
         let device = self.device(id, DBUS_METHOD_CALL_TIMEOUT);
         let properties = device.get_all(ORG_BLUEZ_DEVICE1_NAME).await?;
-        DeviceInfo::from_properties(id.to_owned(), OrgBluezDevice1Properties(&properties))
+        // The Battery1 interface is only present for some connected devices.
+        let battery_properties = optional_interface(device.get_all(ORG_BLUEZ_BATTERY1_NAME).await)?;
+        DeviceInfo::from_properties(
+            id.to_owned(),
+            OrgBluezDevice1Properties(&properties),
+            battery_properties.as_ref().map(OrgBluezBattery1Properties),
+        )
+    }
+
+    /// Get the battery level of the given device as a percentage.
+    ///
+    /// This uses the `org.bluez.Battery1` interface if BlueZ provides it for the device, which
+    /// includes classic devices reporting their battery level via HFP. Otherwise it falls back to
+    /// reading the Battery Level characteristic of the GATT Battery Service, which requires the
+    /// device to be connected.
+    pub async fn get_battery_level(&self, id: &DeviceId) -> Result<u8, BluetoothError> {
+        let device = self.device(id, DBUS_METHOD_CALL_TIMEOUT);
+        if let Some(properties) = optional_interface(device.get_all(ORG_BLUEZ_BATTERY1_NAME).await)?
+        {
+            if let Some(percentage) = OrgBluezBattery1Properties(&properties).percentage() {
+                return Ok(percentage);
+            }
+        }
+        let characteristic = self
+            .get_service_characteristic_by_uuid(
+                id,
+                BATTERY_SERVICE_UUID,
+                BATTERY_LEVEL_CHARACTERISTIC_UUID,
+            )
+            .await?;
+        let value = self.read_characteristic_value(&characteristic.id).await?;
+        u8::from_gatt_bytes(&value)
+    }
+
+    /// Get the state of the PAN connection to the given device.
+    ///
+    /// This fails if the device doesn't support PAN.
+    pub async fn get_network_info(&self, id: &DeviceId) -> Result<NetworkInfo, BluetoothError> {
+        let properties = self
+            .device(id, DBUS_METHOD_CALL_TIMEOUT)
+            .get_all(ORG_BLUEZ_NETWORK1_NAME)
+            .await?;
+        NetworkInfo::from_properties(OrgBluezNetwork1Properties(&properties))
+    }
+
+    /// Get the active AVRCP media player of the given device, such as the music app on a connected
+    /// phone, if it has one.
+    ///
+    /// This fails if AVRCP isn't available for the device.
+    pub async fn get_media_player(
+        &self,
+        id: &DeviceId,
+    ) -> Result<Option<MediaPlayerId>, BluetoothError> {
+        let properties = self
+            .device(id, DBUS_METHOD_CALL_TIMEOUT)
+            .get_all(ORG_BLUEZ_MEDIA_CONTROL1_NAME)
+            .await?;
+        Ok(OrgBluezMediaControl1Properties(&properties)
+            .player()
+            .map(|player| MediaPlayerId {
+                object_path: player.to_owned(),
+            }))
+    }
+
+    /// Get information about the given media player, including its playback status and the
+    /// metadata of the current track.
+    pub async fn get_media_player_info(
+        &self,
+        id: &MediaPlayerId,
+    ) -> Result<MediaPlayerInfo, BluetoothError> {
+        let properties = self
+            .media_player(id)
+            .get_all(ORG_BLUEZ_MEDIA_PLAYER1_NAME)
+            .await?;
+        MediaPlayerInfo::from_properties(id.to_owned(), OrgBluezMediaPlayer1Properties(&properties))
     }
 
     /// Get information about the given Bluetooth adapter.
     pub async fn get_adapter_info(&self, id: &AdapterId) -> Result<AdapterInfo, BluetoothError> {
         let adapter = self.adapter(id);
         let properties = adapter.get_all(ORG_BLUEZ_ADAPTER1_NAME).await?;
-        AdapterInfo::from_properties(id.to_owned(), OrgBluezAdapter1Properties(&properties))
+        // The LEAdvertisingManager1 interface is only present for adapters which support LE.
+        let advertising_properties =
+            optional_interface(adapter.get_all(ORG_BLUEZ_LEADVERTISING_MANAGER1_NAME).await)?;
+        AdapterInfo::from_properties(
+            id.to_owned(),
+            OrgBluezAdapter1Properties(&properties),
+            advertising_properties
+                .as_ref()
+                .map(OrgBluezLEAdvertisingManager1Properties),
+        )
+    }
+
+    /// Get the LE advertising capabilities of the given Bluetooth adapter, such as how many more
+    /// advertisements may be registered and whether extended advertising is supported.
+    pub async fn get_advertising_capabilities(
+        &self,
+        id: &AdapterId,
+    ) -> Result<AdvertisingCapabilities, BluetoothError> {
+        let properties = self
+            .adapter(id)
+            .get_all(ORG_BLUEZ_LEADVERTISING_MANAGER1_NAME)
+            .await?;
+        AdvertisingCapabilities::from_properties(OrgBluezLEAdvertisingManager1Properties(
+            &properties,
+        ))
     }
 
     /// Get information about the given GATT service.
@@ -644,6 +1282,24 @@ impl BluetoothSession {
         )
     }
 
+    fn network(&self, id: &DeviceId) -> impl OrgBluezNetwork1 {
+        Proxy::new(
+            "org.bluez",
+            id.object_path.to_owned(),
+            DBUS_METHOD_CALL_TIMEOUT,
+            self.connection.clone(),
+        )
+    }
+
+    fn media_player(&self, id: &MediaPlayerId) -> impl OrgBluezMediaPlayer1 + Properties {
+        Proxy::new(
+            "org.bluez",
+            id.object_path.to_owned(),
+            DBUS_METHOD_CALL_TIMEOUT,
+            self.connection.clone(),
+        )
+    }
+
     fn service(&self, id: &ServiceId) -> impl OrgBluezGattService1 + Introspectable + Properties {
         Proxy::new(
             "org.bluez",
@@ -707,6 +1363,110 @@ impl BluetoothSession {
         .unwrap_or(Err(BluetoothError::ServiceDiscoveryTimedOut))
     }
 
+    /// Wait until the given profile of the given device reports that it is connected or
+    /// disconnected, if its state is exposed by BlueZ.
+    async fn await_profile_state(
+        &self,
+        device_id: &DeviceId,
+        uuid: Uuid,
+        connected: bool,
+    ) -> Result<(), BluetoothError> {
+        let interface = match profile_state_interface(uuid) {
+            Some(interface) => interface,
+            None => return Ok(()),
+        };
+
+        // We need to subscribe to signals before checking current value to avoid a race condition.
+        let match_rule = PropertiesPropertiesChanged::match_rule(
+            Some(&"org.bluez".into()),
+            Some(&device_id.object_path),
+        )
+        .static_clone();
+        let msg_match = self.connection.add_match(match_rule).await?;
+        let mut messages = MessageStream::new(msg_match, self.connection.clone());
+        let device = self.device(device_id, DBUS_METHOD_CALL_TIMEOUT);
+        match device.get::<bool>(interface, "Connected").await {
+            Ok(current) if current == connected => return Ok(()),
+            Ok(_) => {}
+            Err(e) => {
+                // The device doesn't have the interface, so there's no state to wait for.
+                log::debug!("Can't get {} state of {}: {}", interface, device_id, e);
+                return Ok(());
+            }
+        }
+
+        let changed = timeout(PROFILE_STATE_TIMEOUT, async {
+            while let Some(message) = messages.next().await {
+                if let Some(properties_changed) =
+                    PropertiesPropertiesChanged::from_message(&message)
+                {
+                    if properties_changed.interface_name == interface
+                        && prop_cast(&properties_changed.changed_properties, "Connected")
+                            == Some(&connected)
+                    {
+                        return true;
+                    }
+                }
+            }
+            false
+        })
+        .await;
+        if changed == Ok(true) {
+            Ok(())
+        } else {
+            Err(BluetoothError::ProfileStateTimedOut {
+                device: device_id.to_owned(),
+                uuid,
+                connected,
+            })
+        }
+    }
+
+    /// Run the given operation for the given device, subject to the operation queue and retry policy
+    /// if they are configured.
+    async fn run_operation<T, F: Future<Output = Result<T, BluetoothError>>>(
+        &self,
+        device: &DeviceId,
+        operation: impl Fn() -> F,
+    ) -> Result<T, BluetoothError> {
+        if let Some(retry_policy) = &self.retry_policy {
+            retry_policy.run(|| self.queued(device, operation())).await
+        } else {
+            self.queued(device, operation()).await
+        }
+    }
+
+    /// Like `run_operation`, but for writes, which may not be safe to retry if it's not known
+    /// whether they were carried out.
+    async fn run_write_operation<T, F: Future<Output = Result<T, BluetoothError>>>(
+        &self,
+        device: &DeviceId,
+        operation: impl Fn() -> F,
+    ) -> Result<T, BluetoothError> {
+        if let Some(retry_policy) = &self.retry_policy {
+            retry_policy
+                .run_write(|| self.queued(device, operation()))
+                .await
+        } else {
+            self.queued(device, operation()).await
+        }
+    }
+
+    /// Run the given operation once no other operation is running for the given device, if the
+    /// operation queue is enabled. Otherwise just run it straight away.
+    async fn queued<T>(
+        &self,
+        device: &DeviceId,
+        operation: impl Future<Output = Result<T, BluetoothError>>,
+    ) -> Result<T, BluetoothError> {
+        let _permit = if let Some(operation_queue) = &self.operation_queue {
+            Some(operation_queue.acquire(device, self.priority).await?)
+        } else {
+            None
+        };
+        operation.await
+    }
+
     /// Connect to the given Bluetooth device.
     pub async fn connect(&self, id: &DeviceId) -> Result<(), BluetoothError> {
         self.connect_with_timeout(id, DBUS_METHOD_CALL_TIMEOUT)
@@ -719,8 +1479,11 @@ impl BluetoothSession {
         id: &DeviceId,
         timeout: Duration,
     ) -> Result<(), BluetoothError> {
-        self.device(id, timeout).connect().await?;
-        self.await_service_discovery(id).await
+        self.run_operation(id, || async {
+            self.device(id, timeout).connect().await?;
+            self.await_service_discovery(id).await
+        })
+        .await
     }
 
     /// Disconnect from the given Bluetooth device.
@@ -731,6 +1494,159 @@ impl BluetoothSession {
             .await?)
     }
 
+    /// Connect the given profile of the given Bluetooth device, rather than all the profiles which
+    /// `connect` would.
+    ///
+    /// For profiles whose state BlueZ exposes, such as PAN and AVRCP, this waits until the profile
+    /// reports that it is connected. Succeeds if the profile is already connected.
+    pub async fn connect_profile(&self, id: &DeviceId, uuid: Uuid) -> Result<(), BluetoothError> {
+        self.run_operation(id, || async {
+            match self
+                .device(id, DBUS_METHOD_CALL_TIMEOUT)
+                .connect_profile(&uuid.to_string())
+                .await
+            {
+                Ok(()) => {}
+                Err(e) if e.name() == Some("org.bluez.Error.AlreadyConnected") => {
+                    log::debug!("Profile {} of {} already connected", uuid, id);
+                }
+                Err(e) => return Err(profile_error(e, id, uuid)),
+            }
+            self.await_profile_state(id, uuid, true).await
+        })
+        .await
+    }
+
+    /// Disconnect the given profile of the given Bluetooth device, leaving any other profiles
+    /// connected.
+    ///
+    /// For profiles whose state BlueZ exposes, such as PAN and AVRCP, this waits until the profile
+    /// reports that it is disconnected. Succeeds if the profile is not connected.
+    pub async fn disconnect_profile(
+        &self,
+        id: &DeviceId,
+        uuid: Uuid,
+    ) -> Result<(), BluetoothError> {
+        match self
+            .device(id, DBUS_METHOD_CALL_TIMEOUT)
+            .disconnect_profile(&uuid.to_string())
+            .await
+        {
+            Ok(()) => {}
+            Err(e) if e.name() == Some("org.bluez.Error.NotConnected") => {
+                log::debug!("Profile {} of {} not connected", uuid, id);
+            }
+            Err(e) => return Err(profile_error(e, id, uuid)),
+        }
+        self.await_profile_state(id, uuid, false).await
+    }
+
+    /// Connect to the PAN service of the given device in the given role, e.g. `PanRole::Nap` to
+    /// tether to a network access point.
+    ///
+    /// Returns the name of the network interface which BlueZ creates for the connection, e.g.
+    /// `"bnep0"`. It is up to the caller to configure the interface, e.g. with DHCP.
+    pub async fn network_connect(
+        &self,
+        id: &DeviceId,
+        role: PanRole,
+    ) -> Result<String, BluetoothError> {
+        self.run_operation(id, || async {
+            self.network(id)
+                .connect(&role.uuid().to_string())
+                .await
+                .map_err(|e| match e.name() {
+                    // The device doesn't have the Network1 interface at all.
+                    Some("org.freedesktop.DBus.Error.UnknownMethod") => {
+                        BluetoothError::ProfileUnavailable {
+                            device: id.to_owned(),
+                            uuid: role.uuid(),
+                        }
+                    }
+                    _ => profile_error(e, id, role.uuid()),
+                })
+        })
+        .await
+    }
+
+    /// Disconnect the PAN connection to the given device.
+    pub async fn network_disconnect(&self, id: &DeviceId) -> Result<(), BluetoothError> {
+        Ok(self.network(id).disconnect().await?)
+    }
+
+    /// Register a PAN server for the given role with the given adapter, so that remote devices can
+    /// connect to it. Connections are added to the given bridge interface, which must already
+    /// exist.
+    ///
+    /// The server remains registered until the returned handle is dropped.
+    pub async fn network_server_register(
+        &self,
+        adapter: &AdapterId,
+        role: PanRole,
+        bridge: &str,
+    ) -> Result<NetworkServerHandle, BluetoothError> {
+        NetworkServerHandle::register(self, adapter, role, bridge).await
+    }
+
+    /// Start or resume playback on the given media player.
+    pub async fn media_play(&self, id: &MediaPlayerId) -> Result<(), BluetoothError> {
+        Ok(self.media_player(id).play().await?)
+    }
+
+    /// Pause playback on the given media player.
+    pub async fn media_pause(&self, id: &MediaPlayerId) -> Result<(), BluetoothError> {
+        Ok(self.media_player(id).pause().await?)
+    }
+
+    /// Stop playback on the given media player.
+    pub async fn media_stop(&self, id: &MediaPlayerId) -> Result<(), BluetoothError> {
+        Ok(self.media_player(id).stop().await?)
+    }
+
+    /// Skip to the next track on the given media player.
+    pub async fn media_next(&self, id: &MediaPlayerId) -> Result<(), BluetoothError> {
+        Ok(self.media_player(id).next().await?)
+    }
+
+    /// Go back to the previous track on the given media player.
+    pub async fn media_previous(&self, id: &MediaPlayerId) -> Result<(), BluetoothError> {
+        Ok(self.media_player(id).previous().await?)
+    }
+
+    /// Start fast forwarding on the given media player. Call `media_play` to stop.
+    pub async fn media_fast_forward(&self, id: &MediaPlayerId) -> Result<(), BluetoothError> {
+        Ok(self.media_player(id).fast_forward().await?)
+    }
+
+    /// Start rewinding on the given media player. Call `media_play` to stop.
+    pub async fn media_rewind(&self, id: &MediaPlayerId) -> Result<(), BluetoothError> {
+        Ok(self.media_player(id).rewind().await?)
+    }
+
+    /// Set the repeat setting of the given media player.
+    pub async fn set_media_repeat(
+        &self,
+        id: &MediaPlayerId,
+        repeat: RepeatMode,
+    ) -> Result<(), BluetoothError> {
+        Ok(self
+            .media_player(id)
+            .set_repeat(repeat.as_str().to_owned())
+            .await?)
+    }
+
+    /// Set the shuffle setting of the given media player.
+    pub async fn set_media_shuffle(
+        &self,
+        id: &MediaPlayerId,
+        shuffle: ShuffleMode,
+    ) -> Result<(), BluetoothError> {
+        Ok(self
+            .media_player(id)
+            .set_shuffle(shuffle.as_str().to_owned())
+            .await?)
+    }
+
     /// Read the value of the given GATT characteristic.
     ///
     /// This is equivalent to calling `read_characteristic_value_with_offset(0)`.
@@ -748,7 +1664,10 @@ impl BluetoothSession {
         offset: usize,
     ) -> Result<Vec<u8>, BluetoothError> {
         let characteristic = self.characteristic(id);
-        Ok(characteristic.read_value(offset_to_propmap(offset)).await?)
+        self.run_operation(&id.service().device(), || async {
+            Ok(characteristic.read_value(offset_to_propmap(offset)).await?)
+        })
+        .await
     }
 
     /// Write the given value to the given GATT characteristic, with default options.
@@ -771,9 +1690,13 @@ impl BluetoothSession {
         options: WriteOptions,
     ) -> Result<(), BluetoothError> {
         let characteristic = self.characteristic(id);
-        Ok(characteristic
-            .write_value(value.into(), options.into())
-            .await?)
+        let value = value.into();
+        self.run_write_operation(&id.service().device(), || async {
+            Ok(characteristic
+                .write_value(value.clone(), options.into())
+                .await?)
+        })
+        .await
     }
 
     /// Read the value of the given GATT descriptor.
@@ -793,7 +1716,10 @@ impl BluetoothSession {
         offset: usize,
     ) -> Result<Vec<u8>, BluetoothError> {
         let descriptor = self.descriptor(id);
-        Ok(descriptor.read_value(offset_to_propmap(offset)).await?)
+        self.run_operation(&id.characteristic().service().device(), || async {
+            Ok(descriptor.read_value(offset_to_propmap(offset)).await?)
+        })
+        .await
     }
 
     /// Write the given value to the given GATT descriptor.
@@ -815,16 +1741,23 @@ impl BluetoothSession {
         offset: usize,
     ) -> Result<(), BluetoothError> {
         let descriptor = self.descriptor(id);
-        Ok(descriptor
-            .write_value(value.into(), offset_to_propmap(offset))
-            .await?)
+        let value = value.into();
+        self.run_write_operation(&id.characteristic().service().device(), || async {
+            Ok(descriptor
+                .write_value(value.clone(), offset_to_propmap(offset))
+                .await?)
+        })
+        .await
     }
 
     /// Start notifications on the given GATT characteristic.
     pub async fn start_notify(&self, id: &CharacteristicId) -> Result<(), BluetoothError> {
         let characteristic = self.characteristic(id);
-        characteristic.start_notify().await?;
-        Ok(())
+        self.run_operation(&id.service().device(), || async {
+            characteristic.start_notify().await?;
+            Ok(())
+        })
+        .await
     }
 
     /// Stop notifications on the given GATT characteristic.
@@ -834,6 +1767,74 @@ impl BluetoothSession {
         Ok(())
     }
 
+    /// Export the given local GATT services over D-Bus and register them with the given adapter,
+    /// so that remote devices which connect to it can use them.
+    ///
+    /// The services remain registered until the returned handle is dropped.
+    pub async fn register_gatt_application(
+        &self,
+        adapter: &AdapterId,
+        application: &GattApplication,
+    ) -> Result<GattApplicationHandle, BluetoothError> {
+        GattApplicationHandle::register(self, adapter, application).await
+    }
+
+    /// Export the given advertisement over D-Bus and register it with the given adapter, so that
+    /// the adapter starts sending it.
+    ///
+    /// The advertisement remains registered until the returned handle is dropped.
+    pub async fn start_advertising(
+        &self,
+        adapter: &AdapterId,
+        advertisement: &Advertisement,
+    ) -> Result<AdvertisementHandle, BluetoothError> {
+        AdvertisementHandle::register(self, adapter, advertisement).await
+    }
+
+    /// Register the given advertisement monitor with the given adapter, and get a stream of events
+    /// for devices which it finds or loses.
+    ///
+    /// Pattern matching and RSSI filtering is offloaded to the adapter's controller if it supports
+    /// it, so this is cheaper than running discovery with duplicate data. Older versions of BlueZ
+    /// only provide advertisement monitors with experimental features enabled.
+    ///
+    /// The monitor remains registered until the returned stream is dropped. The stream ends if
+    /// BlueZ releases the monitor, e.g. because it is invalid or the adapter is removed.
+    pub async fn monitor_advertisements(
+        &self,
+        adapter: &AdapterId,
+        monitor: &AdvertisementMonitor,
+    ) -> Result<impl Stream<Item = MonitorEvent>, BluetoothError> {
+        let (registration, events) = MonitorRegistration::register(self, adapter, monitor).await?;
+        Ok(events.map(move |event| {
+            // Keep the monitor registered for as long as the stream is alive.
+            let _registration = &registration;
+            event
+        }))
+    }
+
+    /// Register a Bluetooth Classic profile with the given UUID, such as
+    /// [`SERIAL_PORT_PROFILE_UUID`], and get a stream of events for connections to it.
+    ///
+    /// Each connection, whether made by a remote device or by
+    /// [`connect`](#method.connect)ing to a device which provides the profile, is reported as a
+    /// [`ProfileEvent::NewConnection`] with a socket to communicate over.
+    ///
+    /// The profile remains registered until the returned stream is dropped. The stream ends if
+    /// BlueZ releases the profile.
+    pub async fn register_profile(
+        &self,
+        uuid: Uuid,
+        options: &ProfileOptions,
+    ) -> Result<impl Stream<Item = ProfileEvent>, BluetoothError> {
+        let (registration, events) = ProfileRegistration::register(self, uuid, options).await?;
+        Ok(events.map(move |event| {
+            // Keep the profile registered for as long as the stream is alive.
+            let _registration = &registration;
+            event
+        }))
+    }
+
     /// Get a stream of events for all devices.
     pub async fn event_stream(&self) -> Result<impl Stream<Item = BluetoothEvent>, BluetoothError> {
         self.filtered_event_stream(None::<&DeviceId>, true).await
@@ -891,3 +1892,150 @@ fn offset_to_propmap(offset: usize) -> PropMap {
     }
     map
 }
+
+/// Convert the result of getting the properties of an interface which the object may not have, so
+/// that the interface being missing gives `None` rather than an error. Any other error is returned.
+fn optional_interface(
+    result: Result<PropMap, dbus::Error>,
+) -> Result<Option<PropMap>, BluetoothError> {
+    match result {
+        Ok(properties) => Ok(Some(properties)),
+        Err(e)
+            if matches!(
+                e.name(),
+                Some("org.freedesktop.DBus.Error.UnknownInterface")
+                    | Some("org.freedesktop.DBus.Error.InvalidArgs")
+            ) =>
+        {
+            Ok(None)
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Convert an error from `ConnectProfile` or `DisconnectProfile` to a more specific error where
+/// possible.
+fn profile_error(error: dbus::Error, device: &DeviceId, uuid: Uuid) -> BluetoothError {
+    match error.name() {
+        Some("org.bluez.Error.DoesNotExist")
+        | Some("org.bluez.Error.NotAvailable")
+        | Some("org.bluez.Error.InvalidArguments") => BluetoothError::ProfileUnavailable {
+            device: device.to_owned(),
+            uuid,
+        },
+        Some("org.bluez.Error.NotSupported") => BluetoothError::ProfileNotSupported {
+            device: device.to_owned(),
+            uuid,
+        },
+        _ => error.into(),
+    }
+}
+
+/// Get the D-Bus interface on a device object whose `Connected` property reflects the state of the
+/// given profile, if there is one.
+fn profile_state_interface(uuid: Uuid) -> Option<&'static str> {
+    match uuid.to_ble_u16() {
+        // PANU, NAP and GN.
+        Some(0x1115..=0x1117) => Some(ORG_BLUEZ_NETWORK1_NAME),
+        // AVRCP target, AVRCP and AVRCP controller.
+        Some(0x110c) | Some(0x110e) | Some(0x110f) => Some(ORG_BLUEZ_MEDIA_CONTROL1_NAME),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn discovery_filter_validate() {
+        let supported_keys = vec!["UUIDs".to_string(), "RSSI".to_string()];
+        let filter = DiscoveryFilter {
+            service_uuids: vec![uuid_from_u16(0x180f)],
+            rssi_threshold: Some(-70),
+            ..Default::default()
+        };
+        assert!(filter.validate(&supported_keys).is_ok());
+        assert_eq!(filter.without_unsupported(&supported_keys), filter);
+
+        let filter = DiscoveryFilter {
+            rssi_threshold: Some(-70),
+            pattern: Some("Ruuvi".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            filter.validate(&supported_keys),
+            Err(BluetoothError::UnsupportedDiscoveryFilterField {
+                field: "pattern",
+                key: "Pattern"
+            })
+        ));
+        assert_eq!(
+            filter.without_unsupported(&supported_keys),
+            DiscoveryFilter {
+                rssi_threshold: Some(-70),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn optional_interfaces() {
+        assert!(optional_interface(Ok(PropMap::new())).unwrap().is_some());
+        assert!(optional_interface(Err(dbus::Error::new_custom(
+            "org.freedesktop.DBus.Error.InvalidArgs",
+            "No such interface 'org.bluez.Battery1'"
+        )))
+        .unwrap()
+        .is_none());
+        assert!(matches!(
+            optional_interface(Err(dbus::Error::new_custom(
+                "org.freedesktop.DBus.Error.NoReply",
+                "Did not receive a reply"
+            ))),
+            Err(BluetoothError::DbusError(_))
+        ));
+    }
+
+    #[test]
+    fn profile_errors() {
+        let device = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let uuid = uuid_from_u16(0x1108);
+        assert!(matches!(
+            profile_error(
+                dbus::Error::new_custom("org.bluez.Error.NotAvailable", "Exhausted profiles"),
+                &device,
+                uuid
+            ),
+            BluetoothError::ProfileUnavailable { device: d, uuid: u } if d == device && u == uuid
+        ));
+        assert!(matches!(
+            profile_error(
+                dbus::Error::new_custom("org.bluez.Error.NotSupported", "Not supported"),
+                &device,
+                uuid
+            ),
+            BluetoothError::ProfileNotSupported { .. }
+        ));
+        let error = profile_error(
+            dbus::Error::new_custom("org.bluez.Error.Failed", "br-connection-page-timeout"),
+            &device,
+            uuid,
+        );
+        assert!(matches!(error, BluetoothError::DbusError(_)));
+        assert!(error.is_transient());
+    }
+
+    #[test]
+    fn profile_state_interfaces() {
+        assert_eq!(
+            profile_state_interface(uuid_from_u16(0x1116)),
+            Some(ORG_BLUEZ_NETWORK1_NAME)
+        );
+        assert_eq!(
+            profile_state_interface(uuid_from_u16(0x110e)),
+            Some(ORG_BLUEZ_MEDIA_CONTROL1_NAME)
+        );
+        assert_eq!(profile_state_interface(SERIAL_PORT_PROFILE_UUID), None);
+    }
+}
@@ -0,0 +1,319 @@
+use bluez_generated::OrgBluezMediaPlayer1Properties;
+use dbus::arg::{prop_cast, PropMap};
+use dbus::Path;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{BluetoothError, DeviceId};
+
+/// Opaque identifier for an AVRCP media player on a Bluetooth device, such as the music app on a
+/// connected phone.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct MediaPlayerId {
+    #[serde(with = "crate::serde_path")]
+    pub(crate) object_path: Path<'static>,
+}
+
+impl MediaPlayerId {
+    #[cfg(test)]
+    pub(crate) fn new(object_path: &str) -> Self {
+        Self {
+            object_path: object_path.to_owned().into(),
+        }
+    }
+
+    /// Get the ID of the device which the media player belongs to.
+    pub fn device(&self) -> DeviceId {
+        let index = self
+            .object_path
+            .rfind('/')
+            .expect("MediaPlayerId object_path must contain a slash.");
+        DeviceId::new(&self.object_path[0..index])
+    }
+}
+
+impl From<MediaPlayerId> for Path<'static> {
+    fn from(id: MediaPlayerId) -> Self {
+        id.object_path
+    }
+}
+
+impl Display for MediaPlayerId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.object_path
+                .to_string()
+                .strip_prefix("/org/bluez/")
+                .ok_or(fmt::Error)?
+        )
+    }
+}
+
+/// The playback status of a media player.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PlaybackStatus {
+    Playing,
+    Stopped,
+    Paused,
+    ForwardSeek,
+    ReverseSeek,
+    Error,
+}
+
+impl FromStr for PlaybackStatus {
+    type Err = BluetoothError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "playing" => Ok(Self::Playing),
+            "stopped" => Ok(Self::Stopped),
+            "paused" => Ok(Self::Paused),
+            "forward-seek" => Ok(Self::ForwardSeek),
+            "reverse-seek" => Ok(Self::ReverseSeek),
+            "error" => Ok(Self::Error),
+            _ => Err(BluetoothError::InvalidValue(format!(
+                "Unknown playback status {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// The repeat setting of a media player.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RepeatMode {
+    Off,
+    SingleTrack,
+    AllTracks,
+    Group,
+}
+
+impl RepeatMode {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::SingleTrack => "singletrack",
+            Self::AllTracks => "alltracks",
+            Self::Group => "group",
+        }
+    }
+}
+
+impl FromStr for RepeatMode {
+    type Err = BluetoothError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "singletrack" => Ok(Self::SingleTrack),
+            "alltracks" => Ok(Self::AllTracks),
+            "group" => Ok(Self::Group),
+            _ => Err(BluetoothError::InvalidValue(format!(
+                "Unknown repeat mode {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// The shuffle setting of a media player.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ShuffleMode {
+    Off,
+    AllTracks,
+    Group,
+}
+
+impl ShuffleMode {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "off",
+            Self::AllTracks => "alltracks",
+            Self::Group => "group",
+        }
+    }
+}
+
+impl FromStr for ShuffleMode {
+    type Err = BluetoothError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Self::Off),
+            "alltracks" => Ok(Self::AllTracks),
+            "group" => Ok(Self::Group),
+            _ => Err(BluetoothError::InvalidValue(format!(
+                "Unknown shuffle mode {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Metadata about the current track of a media player. Any of the fields may be missing if the
+/// remote device doesn't provide them.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TrackInfo {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub genre: Option<String>,
+    /// The number of the track within its album.
+    pub track_number: Option<u32>,
+    /// The number of tracks in the album.
+    pub number_of_tracks: Option<u32>,
+    pub duration: Option<Duration>,
+}
+
+impl TrackInfo {
+    pub(crate) fn from_properties(track: &PropMap) -> Self {
+        Self {
+            title: prop_cast::<String>(track, "Title").cloned(),
+            artist: prop_cast::<String>(track, "Artist").cloned(),
+            album: prop_cast::<String>(track, "Album").cloned(),
+            genre: prop_cast::<String>(track, "Genre").cloned(),
+            track_number: prop_cast(track, "TrackNumber").copied(),
+            number_of_tracks: prop_cast(track, "NumberOfTracks").copied(),
+            duration: prop_cast(track, "Duration")
+                .copied()
+                .map(|millis: u32| Duration::from_millis(millis.into())),
+        }
+    }
+}
+
+/// Information about an AVRCP media player on a Bluetooth device.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MediaPlayerInfo {
+    /// An opaque identifier for the media player.
+    pub id: MediaPlayerId,
+    /// The name of the player, e.g. the name of the app on a phone.
+    pub name: Option<String>,
+    pub status: PlaybackStatus,
+    /// The position within the current track.
+    pub position: Duration,
+    pub track: TrackInfo,
+    /// The repeat setting, if the player supports it.
+    pub repeat: Option<RepeatMode>,
+    /// The shuffle setting, if the player supports it.
+    pub shuffle: Option<ShuffleMode>,
+}
+
+impl MediaPlayerInfo {
+    pub(crate) fn from_properties(
+        id: MediaPlayerId,
+        player_properties: OrgBluezMediaPlayer1Properties,
+    ) -> Result<Self, BluetoothError> {
+        let status = player_properties
+            .status()
+            .ok_or(BluetoothError::RequiredPropertyMissing("Status"))?
+            .parse()?;
+        let position = player_properties
+            .position()
+            .ok_or(BluetoothError::RequiredPropertyMissing("Position"))?;
+        Ok(Self {
+            id,
+            name: player_properties.name().cloned(),
+            status,
+            position: Duration::from_millis(position.into()),
+            track: player_properties
+                .track()
+                .map(TrackInfo::from_properties)
+                .unwrap_or_default(),
+            repeat: player_properties
+                .repeat()
+                .map(|repeat| repeat.parse())
+                .transpose()?,
+            shuffle: player_properties
+                .shuffle()
+                .map(|shuffle| shuffle.parse())
+                .transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbus::arg::Variant;
+
+    #[test]
+    fn player_device() {
+        let player_id = MediaPlayerId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/player0");
+        assert_eq!(
+            player_id.device(),
+            DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66")
+        );
+        assert_eq!(player_id.to_string(), "hci0/dev_11_22_33_44_55_66/player0");
+    }
+
+    #[test]
+    fn info_from_properties() {
+        let id = MediaPlayerId::new("/org/bluez/hci0/dev_11_22_33_44_55_66/player0");
+        let mut track = PropMap::new();
+        track.insert("Title".to_string(), Variant(Box::new("Song".to_string())));
+        track.insert("Artist".to_string(), Variant(Box::new("Band".to_string())));
+        track.insert("Duration".to_string(), Variant(Box::new(215_000u32)));
+        track.insert("TrackNumber".to_string(), Variant(Box::new(3u32)));
+        let mut properties = PropMap::new();
+        properties.insert("Name".to_string(), Variant(Box::new("Music".to_string())));
+        properties.insert(
+            "Status".to_string(),
+            Variant(Box::new("playing".to_string())),
+        );
+        properties.insert("Position".to_string(), Variant(Box::new(12_500u32)));
+        properties.insert("Track".to_string(), Variant(Box::new(track)));
+        properties.insert(
+            "Repeat".to_string(),
+            Variant(Box::new("alltracks".to_string())),
+        );
+
+        assert_eq!(
+            MediaPlayerInfo::from_properties(
+                id.clone(),
+                OrgBluezMediaPlayer1Properties(&properties)
+            )
+            .unwrap(),
+            MediaPlayerInfo {
+                id,
+                name: Some("Music".to_string()),
+                status: PlaybackStatus::Playing,
+                position: Duration::from_millis(12_500),
+                track: TrackInfo {
+                    title: Some("Song".to_string()),
+                    artist: Some("Band".to_string()),
+                    track_number: Some(3),
+                    duration: Some(Duration::from_millis(215_000)),
+                    ..Default::default()
+                },
+                repeat: Some(RepeatMode::AllTracks),
+                shuffle: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_modes() {
+        for repeat in [
+            RepeatMode::Off,
+            RepeatMode::SingleTrack,
+            RepeatMode::AllTracks,
+            RepeatMode::Group,
+        ]
+        .iter()
+        {
+            assert_eq!(repeat.as_str().parse::<RepeatMode>().unwrap(), *repeat);
+        }
+        for shuffle in [ShuffleMode::Off, ShuffleMode::AllTracks, ShuffleMode::Group].iter() {
+            assert_eq!(shuffle.as_str().parse::<ShuffleMode>().unwrap(), *shuffle);
+        }
+        assert_eq!(
+            "forward-seek".parse::<PlaybackStatus>().unwrap(),
+            PlaybackStatus::ForwardSeek
+        );
+        assert!("rewinding".parse::<PlaybackStatus>().is_err());
+    }
+}
@@ -9,22 +9,73 @@ use thiserror::Error;
 #[error("Error parsing modalias string {0:?}")]
 pub struct ParseModaliasError(String);
 
-/// A parsed modalias string.
-///
-/// For now only the USB subtype is supported.
+/// The organisation which assigned the vendor ID of a [`Modalias`], given by its subtype.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum ModaliasSource {
+    /// A USB Implementer's Forum assigned vendor ID, with the `usb` subtype.
+    Usb,
+    /// A Bluetooth SIG assigned company identifier, with the `bluetooth` subtype.
+    Bluetooth,
+    /// Some other subtype.
+    Unknown(String),
+}
+
+impl ModaliasSource {
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Usb => "usb",
+            Self::Bluetooth => "bluetooth",
+            Self::Unknown(subtype) => subtype,
+        }
+    }
+}
+
+impl From<&str> for ModaliasSource {
+    fn from(subtype: &str) -> Self {
+        match subtype {
+            "usb" => Self::Usb,
+            "bluetooth" => Self::Bluetooth,
+            _ => Self::Unknown(subtype.to_owned()),
+        }
+    }
+}
+
+impl Display for ModaliasSource {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A parsed modalias string, such as `usb:v1D6Bp0246d0537` or `bluetooth:v004Cp0312d0110`.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Modalias {
+    /// Which organisation assigned the vendor ID.
+    pub source: ModaliasSource,
     pub vendor_id: u16,
     pub product_id: u16,
     pub device_id: u16,
 }
 
+impl Modalias {
+    /// Look up the name of the vendor, if it is known.
+    ///
+    /// Only vendor IDs assigned by the Bluetooth SIG can currently be looked up.
+    #[cfg(feature = "assigned-numbers")]
+    pub fn vendor_name(&self) -> Option<&'static str> {
+        match self.source {
+            ModaliasSource::Bluetooth => crate::assigned_numbers::company_name(self.vendor_id),
+            _ => None,
+        }
+    }
+}
+
 impl Display for Modalias {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
             f,
-            "usb:v{:04X}p{:04X}d{:04X}",
-            self.vendor_id, self.product_id, self.device_id
+            "{}:v{:04X}p{:04X}d{:04X}",
+            self.source, self.vendor_id, self.product_id, self.device_id
         )
     }
 }
@@ -43,10 +94,8 @@ impl TryFrom<RawModalias> for Modalias {
     type Error = ();
 
     fn try_from(raw: RawModalias) -> Result<Self, Self::Error> {
-        if raw.subtype != "usb" {
-            return Err(());
-        }
         Ok(Modalias {
+            source: raw.subtype.as_str().into(),
             vendor_id: u16::from_str_radix(raw.values.get("v").ok_or(())?, 16).map_err(|_| ())?,
             product_id: u16::from_str_radix(raw.values.get("p").ok_or(())?, 16).map_err(|_| ())?,
             device_id: u16::from_str_radix(raw.values.get("d").ok_or(())?, 16).map_err(|_| ())?,
@@ -107,6 +156,7 @@ mod tests {
         assert_eq!(
             Modalias::from_str("usb:v0000p0000d0000").unwrap(),
             Modalias {
+                source: ModaliasSource::Usb,
                 vendor_id: 0,
                 product_id: 0,
                 device_id: 0
@@ -115,6 +165,7 @@ mod tests {
         assert_eq!(
             Modalias::from_str("usb:v1234p5678d90AB").unwrap(),
             Modalias {
+                source: ModaliasSource::Usb,
                 vendor_id: 0x1234,
                 product_id: 0x5678,
                 device_id: 0x90AB
@@ -123,11 +174,40 @@ mod tests {
     }
 
     #[test]
-    fn parse_invalid_subtype() {
-        assert!(matches!(
-            Modalias::from_str("blah:v0000p0000d0000"),
-            Err(ParseModaliasError(_))
-        ));
+    fn parse_bluetooth() {
+        assert_eq!(
+            Modalias::from_str("bluetooth:v004Cp0312d0110").unwrap(),
+            Modalias {
+                source: ModaliasSource::Bluetooth,
+                vendor_id: 0x004c,
+                product_id: 0x0312,
+                device_id: 0x0110
+            }
+        );
+    }
+
+    #[test]
+    fn parse_unknown_subtype() {
+        let modalias = Modalias::from_str("blah:v0000p0000d0000").unwrap();
+        assert_eq!(modalias.source, ModaliasSource::Unknown("blah".to_owned()));
+        assert_eq!(modalias.to_string(), "blah:v0000p0000d0000");
+    }
+
+    #[cfg(feature = "assigned-numbers")]
+    #[test]
+    fn vendor_name() {
+        assert_eq!(
+            Modalias::from_str("bluetooth:v004Cp0312d0110")
+                .unwrap()
+                .vendor_name(),
+            Some("Apple, Inc.")
+        );
+        assert_eq!(
+            Modalias::from_str("usb:v004Cp0312d0110")
+                .unwrap()
+                .vendor_name(),
+            None
+        );
     }
 
     #[test]
@@ -146,6 +226,7 @@ mod tests {
     fn to_string() {
         assert_eq!(
             Modalias {
+                source: ModaliasSource::Usb,
                 vendor_id: 0,
                 product_id: 0,
                 device_id: 0
@@ -155,6 +236,7 @@ mod tests {
         );
         assert_eq!(
             Modalias {
+                source: ModaliasSource::Usb,
                 vendor_id: 0x1234,
                 product_id: 0x5678,
                 device_id: 0x90AB
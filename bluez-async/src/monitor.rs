@@ -0,0 +1,464 @@
+use bluez_generated::{
+    OrgBluezAdvertisementMonitorManager1, ORG_BLUEZ_ADVERTISEMENT_MONITOR1_NAME,
+};
+use dbus::channel::Token;
+use dbus::nonblock::Proxy;
+use dbus::Path;
+use dbus_crossroads::{Crossroads, IfaceBuilder};
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Formatter};
+use std::time::Duration;
+use tokio::runtime::Handle;
+
+use crate::export::{optional, seconds, serve, unique_path, unserve};
+use crate::{AdapterId, BluetoothError, BluetoothSession, DeviceId, DBUS_METHOD_CALL_TIMEOUT};
+
+/// The only type of monitor which BlueZ currently supports.
+const MONITOR_TYPE_OR_PATTERNS: &str = "or_patterns";
+/// The longest RSSI sampling period which BlueZ accepts, in units of 100 ms.
+const MAX_SAMPLING_PERIOD: u16 = 0xfe;
+/// The RSSI sampling period value which tells BlueZ to only report the first advertisement.
+const SAMPLING_PERIOD_REPORT_ONCE: u16 = 0xff;
+
+/// A pattern to match against the advertisement data of devices, for an [`AdvertisementMonitor`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct MonitorPattern {
+    /// The AD type of the advertisement data structure to match, e.g. `0xff` for
+    /// manufacturer-specific data or `0x16` for 16-bit UUID service data.
+    pub ad_type: u8,
+    /// The offset within the data structure at which `content` must appear.
+    pub start_position: u8,
+    /// The bytes to match.
+    pub content: Vec<u8>,
+}
+
+impl MonitorPattern {
+    /// Create a pattern matching the given bytes at the given offset within advertisement data of
+    /// the given AD type.
+    pub fn new(ad_type: u8, start_position: u8, content: Vec<u8>) -> Self {
+        Self {
+            ad_type,
+            start_position,
+            content,
+        }
+    }
+}
+
+/// An event from an advertisement monitor, from
+/// [`BluetoothSession::monitor_advertisements`](struct.BluetoothSession.html#method.monitor_advertisements).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum MonitorEvent {
+    /// A device matching the monitor has been found, with an RSSI above the high threshold for at
+    /// least the high timeout.
+    DeviceFound { id: DeviceId },
+    /// A device which was found has been lost, because its RSSI has been below the low threshold
+    /// for at least the low timeout or it has stopped advertising.
+    DeviceLost { id: DeviceId },
+}
+
+impl MonitorEvent {
+    /// The ID of the device which the event is about.
+    pub fn id(&self) -> &DeviceId {
+        match self {
+            Self::DeviceFound { id } | Self::DeviceLost { id } => id,
+        }
+    }
+}
+
+/// An advertisement monitor, to have BlueZ (and the adapter's controller, if it supports it)
+/// report devices whose advertisements match any of a set of patterns, rather than running
+/// discovery and filtering all advertisements in the application.
+///
+/// Start monitoring with
+/// [`BluetoothSession::monitor_advertisements`](struct.BluetoothSession.html#method.monitor_advertisements).
+///
+/// ```no_run
+/// # use bluez_async::{AdapterId, AdvertisementMonitor, BluetoothSession, MonitorPattern};
+/// # use futures::StreamExt;
+/// # use std::time::Duration;
+/// # async fn example(session: &BluetoothSession, adapter: &AdapterId) -> Result<(), Box<dyn std::error::Error>> {
+/// // Match manufacturer-specific data for Ruuvi Innovations.
+/// let monitor = AdvertisementMonitor::new()
+///     .pattern(MonitorPattern::new(0xff, 0, vec![0x99, 0x04]))
+///     .rssi_high_threshold(-70, Duration::from_secs(1))
+///     .rssi_low_threshold(-90, Duration::from_secs(30));
+/// let mut events = session.monitor_advertisements(adapter, &monitor).await?;
+/// while let Some(event) = events.next().await {
+///     println!("{:?}", event);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AdvertisementMonitor {
+    patterns: Vec<MonitorPattern>,
+    rssi_high_threshold: Option<i16>,
+    rssi_high_timeout: Option<Duration>,
+    rssi_low_threshold: Option<i16>,
+    rssi_low_timeout: Option<Duration>,
+    /// The RSSI sampling period in units of 100 ms, or `SAMPLING_PERIOD_REPORT_ONCE`.
+    rssi_sampling_period: Option<u16>,
+}
+
+impl AdvertisementMonitor {
+    /// Create a new monitor with no patterns.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Report devices whose advertisement data matches the given pattern. If this is called
+    /// several times then devices matching any of the patterns are reported.
+    pub fn pattern(mut self, pattern: MonitorPattern) -> Self {
+        self.patterns.push(pattern);
+        self
+    }
+
+    /// Only report a device as found once its RSSI has been at least the given threshold in dBm
+    /// for the given time. The time has a resolution of seconds.
+    pub fn rssi_high_threshold(mut self, threshold: i16, timeout: Duration) -> Self {
+        self.rssi_high_threshold = Some(threshold);
+        self.rssi_high_timeout = Some(timeout);
+        self
+    }
+
+    /// Report a device as lost once its RSSI has been below the given threshold in dBm for the
+    /// given time. The time has a resolution of seconds.
+    pub fn rssi_low_threshold(mut self, threshold: i16, timeout: Duration) -> Self {
+        self.rssi_low_threshold = Some(threshold);
+        self.rssi_low_timeout = Some(timeout);
+        self
+    }
+
+    /// Set how often the controller reports advertisements from matching devices to BlueZ. This has
+    /// a resolution of 100 ms, and longer periods are limited to 25.4 seconds. Zero means that every
+    /// advertisement is reported.
+    pub fn rssi_sampling_period(mut self, period: Duration) -> Self {
+        self.rssi_sampling_period = Some(sampling_period(period));
+        self
+    }
+
+    /// Only report the first advertisement from each matching device to BlueZ while it is being
+    /// monitored, rather than sampling them periodically. This replaces any period set with
+    /// [`rssi_sampling_period`](Self::rssi_sampling_period).
+    pub fn rssi_report_once(mut self) -> Self {
+        self.rssi_sampling_period = Some(SAMPLING_PERIOD_REPORT_ONCE);
+        self
+    }
+
+    /// Build a `Crossroads` to serve the monitor under the given root path, sending events to the
+    /// given sender.
+    fn to_crossroads(
+        &self,
+        root: &Path<'static>,
+        events: UnboundedSender<MonitorEvent>,
+    ) -> Crossroads {
+        let mut cr = Crossroads::new();
+        let token = cr.register(
+            ORG_BLUEZ_ADVERTISEMENT_MONITOR1_NAME,
+            |b: &mut IfaceBuilder<MonitorObject>| {
+                b.property("Type")
+                    .get(|_, _| Ok(MONITOR_TYPE_OR_PATTERNS.to_owned()));
+                b.property("Patterns").get(|_, monitor| {
+                    Ok(monitor
+                        .monitor
+                        .patterns
+                        .iter()
+                        .map(|pattern| {
+                            (
+                                pattern.start_position,
+                                pattern.ad_type,
+                                pattern.content.clone(),
+                            )
+                        })
+                        .collect::<Vec<_>>())
+                });
+                // Unset optional properties are left out of `GetAll`, so BlueZ uses its defaults.
+                b.property("RSSIHighThreshold").get(|_, monitor| {
+                    optional("RSSIHighThreshold", monitor.monitor.rssi_high_threshold)
+                });
+                b.property("RSSIHighTimeout").get(|_, monitor| {
+                    optional(
+                        "RSSIHighTimeout",
+                        monitor.monitor.rssi_high_timeout.map(seconds),
+                    )
+                });
+                b.property("RSSILowThreshold").get(|_, monitor| {
+                    optional("RSSILowThreshold", monitor.monitor.rssi_low_threshold)
+                });
+                b.property("RSSILowTimeout").get(|_, monitor| {
+                    optional(
+                        "RSSILowTimeout",
+                        monitor.monitor.rssi_low_timeout.map(seconds),
+                    )
+                });
+                b.property("RSSISamplingPeriod").get(|_, monitor| {
+                    optional("RSSISamplingPeriod", monitor.monitor.rssi_sampling_period)
+                });
+                b.method("Release", (), (), |_, monitor, ()| {
+                    log::debug!("BlueZ released advertisement monitor");
+                    // Dropping the sender ends the stream of events.
+                    monitor.events = None;
+                    Ok(())
+                });
+                b.method("Activate", (), (), |_, _, ()| {
+                    log::debug!("BlueZ activated advertisement monitor");
+                    Ok(())
+                });
+                b.method("DeviceFound", ("device",), (), |_, monitor, (device,)| {
+                    monitor.send(MonitorEvent::DeviceFound {
+                        id: device_id(device),
+                    });
+                    Ok(())
+                });
+                b.method("DeviceLost", ("device",), (), |_, monitor, (device,)| {
+                    monitor.send(MonitorEvent::DeviceLost {
+                        id: device_id(device),
+                    });
+                    Ok(())
+                });
+            },
+        );
+        let object_manager = cr.object_manager();
+        cr.insert(root.clone(), &[object_manager], ());
+        cr.insert(
+            format!("{}/monitor0", root),
+            &[token],
+            MonitorObject {
+                monitor: self.clone(),
+                events: Some(events),
+            },
+        );
+        cr
+    }
+}
+
+/// The data of an exported `org.bluez.AdvertisementMonitor1` object.
+struct MonitorObject {
+    monitor: AdvertisementMonitor,
+    events: Option<UnboundedSender<MonitorEvent>>,
+}
+
+impl MonitorObject {
+    fn send(&mut self, event: MonitorEvent) {
+        if let Some(events) = &self.events {
+            if events.unbounded_send(event).is_err() {
+                // The stream has been dropped, so there's no point sending any more events.
+                self.events = None;
+            }
+        }
+    }
+}
+
+fn device_id(path: Path<'static>) -> DeviceId {
+    DeviceId { object_path: path }
+}
+
+/// Convert a sampling period to units of 100 ms, clamping it to the longest period BlueZ accepts.
+fn sampling_period(period: Duration) -> u16 {
+    u16::try_from(period.as_millis() / 100).map_or(MAX_SAMPLING_PERIOD, |period| {
+        period.min(MAX_SAMPLING_PERIOD)
+    })
+}
+
+/// A registration of an advertisement monitor with an adapter, which is unregistered when it is
+/// dropped.
+pub(crate) struct MonitorRegistration {
+    session: BluetoothSession,
+    runtime: Handle,
+    adapter: AdapterId,
+    path: Path<'static>,
+    token: Option<Token>,
+}
+
+impl Debug for MonitorRegistration {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("MonitorRegistration")
+            .field("adapter", &self.adapter)
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl MonitorRegistration {
+    /// Export the given monitor and register it with the given adapter, returning the registration
+    /// and a stream of events from it.
+    pub(crate) async fn register(
+        session: &BluetoothSession,
+        adapter: &AdapterId,
+        monitor: &AdvertisementMonitor,
+    ) -> Result<(Self, UnboundedReceiver<MonitorEvent>), BluetoothError> {
+        let path = unique_path("monitor");
+        let (sender, receiver) = mpsc::unbounded();
+        let cr = monitor.to_crossroads(&path, sender);
+        let token = serve(session, &path, cr);
+        if let Err(e) = monitor_manager(session, adapter)
+            .register_monitor(path.clone())
+            .await
+        {
+            unserve(session, token);
+            return Err(e.into());
+        }
+        Ok((
+            Self {
+                session: session.clone(),
+                runtime: Handle::current(),
+                adapter: adapter.to_owned(),
+                path,
+                token: Some(token),
+            },
+            receiver,
+        ))
+    }
+}
+
+impl Drop for MonitorRegistration {
+    fn drop(&mut self) {
+        if let Some(token) = self.token.take() {
+            let session = self.session.clone();
+            let adapter = self.adapter.clone();
+            let path = self.path.clone();
+            self.runtime.spawn(async move {
+                let result = monitor_manager(&session, &adapter)
+                    .unregister_monitor(path.clone())
+                    .await;
+                unserve(&session, token);
+                if let Err(e) = result {
+                    log::error!("Unregistering advertisement monitor {} failed: {}", path, e);
+                }
+            });
+        }
+    }
+}
+
+fn monitor_manager(
+    session: &BluetoothSession,
+    adapter: &AdapterId,
+) -> impl OrgBluezAdvertisementMonitorManager1 {
+    Proxy::new(
+        "org.bluez",
+        adapter.object_path.to_owned(),
+        DBUS_METHOD_CALL_TIMEOUT,
+        session.connection.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::RecordingSender;
+    use dbus::arg::{PropMap, RefArg};
+    use dbus::Message;
+    use futures::StreamExt;
+    use std::collections::HashMap;
+
+    fn call(cr: &mut Crossroads, message: Message) -> Message {
+        let mut message = message;
+        message.set_serial(1);
+        let sender = RecordingSender::default();
+        cr.handle_message(message, &sender).unwrap();
+        sender.0.into_inner().unwrap().remove(0)
+    }
+
+    #[test]
+    fn managed_objects() {
+        let root = Path::from("/test/monitor0");
+        let (sender, _receiver) = mpsc::unbounded();
+        let mut cr = AdvertisementMonitor::new()
+            .pattern(MonitorPattern::new(0xff, 0, vec![0x99, 0x04]))
+            .rssi_high_threshold(-70, Duration::from_secs(2))
+            .rssi_sampling_period(Duration::from_secs(1))
+            .to_crossroads(&root, sender);
+
+        let reply = call(
+            &mut cr,
+            Message::new_method_call(
+                ":1.0",
+                root.clone(),
+                "org.freedesktop.DBus.ObjectManager",
+                "GetManagedObjects",
+            )
+            .unwrap(),
+        );
+        let objects: HashMap<Path<'static>, HashMap<String, PropMap>> = reply.read1().unwrap();
+        let monitor =
+            &objects[&Path::from("/test/monitor0/monitor0")][ORG_BLUEZ_ADVERTISEMENT_MONITOR1_NAME];
+        assert_eq!(monitor["Type"].as_str(), Some("or_patterns"));
+        assert_eq!(monitor["RSSIHighThreshold"].as_i64(), Some(-70));
+        assert_eq!(monitor["RSSIHighTimeout"].as_u64(), Some(2));
+        assert_eq!(monitor["RSSISamplingPeriod"].as_u64(), Some(10));
+        assert!(!monitor.contains_key("RSSILowThreshold"));
+        let pattern: Vec<_> = monitor["Patterns"]
+            .0
+            .as_iter()
+            .unwrap()
+            .next()
+            .unwrap()
+            .as_iter()
+            .unwrap()
+            .collect();
+        assert_eq!(pattern[0].as_u64(), Some(0));
+        assert_eq!(pattern[1].as_u64(), Some(0xff));
+    }
+
+    #[test]
+    fn sampling_periods() {
+        assert_eq!(sampling_period(Duration::ZERO), 0);
+        assert_eq!(sampling_period(Duration::from_millis(1250)), 12);
+        assert_eq!(sampling_period(Duration::from_millis(25_400)), 0xfe);
+        assert_eq!(sampling_period(Duration::from_secs(60)), 0xfe);
+        assert_eq!(sampling_period(Duration::MAX), 0xfe);
+        assert_eq!(
+            AdvertisementMonitor::new()
+                .rssi_sampling_period(Duration::from_secs(1))
+                .rssi_report_once()
+                .rssi_sampling_period,
+            Some(0xff)
+        );
+    }
+
+    #[tokio::test]
+    async fn events() {
+        let root = Path::from("/test/monitor0");
+        let (sender, mut receiver) = mpsc::unbounded();
+        let mut cr = AdvertisementMonitor::new()
+            .pattern(MonitorPattern::new(0xff, 0, vec![0x99, 0x04]))
+            .to_crossroads(&root, sender);
+        let monitor_path = "/test/monitor0/monitor0";
+        let device_path = Path::from("/org/bluez/hci0/dev_11_22_33_44_55_66");
+
+        for method in ["DeviceFound", "DeviceLost"] {
+            call(
+                &mut cr,
+                Message::new_method_call(
+                    ":1.0",
+                    monitor_path,
+                    ORG_BLUEZ_ADVERTISEMENT_MONITOR1_NAME,
+                    method,
+                )
+                .unwrap()
+                .append1(device_path.clone()),
+            );
+        }
+        call(
+            &mut cr,
+            Message::new_method_call(
+                ":1.0",
+                monitor_path,
+                ORG_BLUEZ_ADVERTISEMENT_MONITOR1_NAME,
+                "Release",
+            )
+            .unwrap(),
+        );
+
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        assert_eq!(
+            receiver.next().await,
+            Some(MonitorEvent::DeviceFound { id: id.clone() })
+        );
+        assert_eq!(receiver.next().await, Some(MonitorEvent::DeviceLost { id }));
+        // Releasing the monitor ends the stream.
+        assert_eq!(receiver.next().await, None);
+    }
+}
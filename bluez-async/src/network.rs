@@ -0,0 +1,228 @@
+use bluez_generated::{OrgBluezNetwork1Properties, OrgBluezNetworkServer1};
+use dbus::nonblock::Proxy;
+use std::fmt::{self, Debug, Display, Formatter};
+use tokio::runtime::Handle;
+use uuid::Uuid;
+
+use crate::{uuid_from_u16, AdapterId, BluetoothError, BluetoothSession, DBUS_METHOD_CALL_TIMEOUT};
+
+/// A role in the Bluetooth Personal Area Networking (PAN) profile.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PanRole {
+    /// PAN User, which connects to a NAP or GN.
+    Panu,
+    /// Network Access Point, which bridges the PANUs connected to it to another network.
+    Nap,
+    /// Group ad-hoc Network, which connects the PANUs connected to it with each other.
+    Gn,
+}
+
+impl PanRole {
+    /// The UUID of the PAN service for this role.
+    pub const fn uuid(self) -> Uuid {
+        match self {
+            Self::Panu => uuid_from_u16(0x1115),
+            Self::Nap => uuid_from_u16(0x1116),
+            Self::Gn => uuid_from_u16(0x1117),
+        }
+    }
+
+    /// Get the role whose PAN service has the given UUID, if any.
+    pub fn from_uuid(uuid: Uuid) -> Option<Self> {
+        [Self::Panu, Self::Nap, Self::Gn]
+            .iter()
+            .copied()
+            .find(|role| role.uuid() == uuid)
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Panu => "panu",
+            Self::Nap => "nap",
+            Self::Gn => "gn",
+        }
+    }
+}
+
+impl Display for PanRole {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Panu => "PANU",
+            Self::Nap => "NAP",
+            Self::Gn => "GN",
+        })
+    }
+}
+
+/// The state of the PAN connection to a Bluetooth device.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NetworkInfo {
+    /// Whether the network connection is established.
+    pub connected: bool,
+    /// The name of the network interface for the connection, e.g. `"bnep0"`, if it is connected.
+    pub interface: Option<String>,
+    /// The role of the remote device in the connection, if it is connected.
+    pub role: Option<PanRole>,
+}
+
+impl NetworkInfo {
+    pub(crate) fn from_properties(
+        network_properties: OrgBluezNetwork1Properties,
+    ) -> Result<Self, BluetoothError> {
+        let connected = network_properties
+            .connected()
+            .ok_or(BluetoothError::RequiredPropertyMissing("Connected"))?;
+        let role = network_properties
+            .uuid()
+            .map(|uuid| Uuid::parse_str(uuid))
+            .transpose()?
+            .and_then(PanRole::from_uuid);
+        Ok(Self {
+            connected,
+            interface: network_properties.interface().cloned(),
+            role,
+        })
+    }
+}
+
+/// A handle to a PAN server which has been registered with an adapter, from
+/// [`BluetoothSession::network_server_register`](struct.BluetoothSession.html#method.network_server_register).
+///
+/// The server is unregistered when this is dropped.
+#[must_use = "the network server is unregistered when the NetworkServerHandle is dropped"]
+pub struct NetworkServerHandle {
+    session: BluetoothSession,
+    runtime: Handle,
+    adapter: AdapterId,
+    role: PanRole,
+    registered: bool,
+}
+
+impl Debug for NetworkServerHandle {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("NetworkServerHandle")
+            .field("adapter", &self.adapter)
+            .field("role", &self.role)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NetworkServerHandle {
+    /// Register a PAN server for the given role with the given adapter, adding connections to the
+    /// given bridge interface.
+    pub(crate) async fn register(
+        session: &BluetoothSession,
+        adapter: &AdapterId,
+        role: PanRole,
+        bridge: &str,
+    ) -> Result<Self, BluetoothError> {
+        network_server(session, adapter)
+            .register(role.as_str(), bridge)
+            .await?;
+        Ok(Self {
+            session: session.clone(),
+            runtime: Handle::current(),
+            adapter: adapter.to_owned(),
+            role,
+            registered: true,
+        })
+    }
+
+    /// The adapter with which the server is registered.
+    pub fn adapter(&self) -> &AdapterId {
+        &self.adapter
+    }
+
+    /// The role which the server provides.
+    pub fn role(&self) -> PanRole {
+        self.role
+    }
+
+    /// Unregister the server, and wait until BlueZ has done so. This is the same as dropping the
+    /// handle, except that it waits and reports any error.
+    pub async fn unregister(mut self) -> Result<(), BluetoothError> {
+        self.registered = false;
+        Ok(network_server(&self.session, &self.adapter)
+            .unregister(self.role.as_str())
+            .await?)
+    }
+}
+
+impl Drop for NetworkServerHandle {
+    fn drop(&mut self) {
+        if self.registered {
+            let session = self.session.clone();
+            let adapter = self.adapter.clone();
+            let role = self.role;
+            self.runtime.spawn(async move {
+                if let Err(e) = network_server(&session, &adapter)
+                    .unregister(role.as_str())
+                    .await
+                {
+                    log::error!(
+                        "Unregistering {} network server on {} failed: {}",
+                        role,
+                        adapter,
+                        e
+                    );
+                }
+            });
+        }
+    }
+}
+
+fn network_server(session: &BluetoothSession, adapter: &AdapterId) -> impl OrgBluezNetworkServer1 {
+    Proxy::new(
+        "org.bluez",
+        adapter.object_path.to_owned(),
+        DBUS_METHOD_CALL_TIMEOUT,
+        session.connection.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbus::arg::{PropMap, Variant};
+
+    #[test]
+    fn role_uuids() {
+        for role in [PanRole::Panu, PanRole::Nap, PanRole::Gn].iter().copied() {
+            assert_eq!(PanRole::from_uuid(role.uuid()), Some(role));
+        }
+        assert_eq!(PanRole::from_uuid(uuid_from_u16(0x1101)), None);
+    }
+
+    #[test]
+    fn info_from_properties() {
+        let mut properties = PropMap::new();
+        properties.insert("Connected".to_string(), Variant(Box::new(true)));
+        properties.insert(
+            "Interface".to_string(),
+            Variant(Box::new("bnep0".to_string())),
+        );
+        properties.insert(
+            "UUID".to_string(),
+            Variant(Box::new("00001116-0000-1000-8000-00805f9b34fb".to_string())),
+        );
+        assert_eq!(
+            NetworkInfo::from_properties(OrgBluezNetwork1Properties(&properties)).unwrap(),
+            NetworkInfo {
+                connected: true,
+                interface: Some("bnep0".to_string()),
+                role: Some(PanRole::Nap),
+            }
+        );
+
+        let mut properties = PropMap::new();
+        properties.insert("Connected".to_string(), Variant(Box::new(false)));
+        assert_eq!(
+            NetworkInfo::from_properties(OrgBluezNetwork1Properties(&properties)).unwrap(),
+            NetworkInfo {
+                connected: false,
+                interface: None,
+                role: None,
+            }
+        );
+    }
+}
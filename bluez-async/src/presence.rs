@@ -0,0 +1,357 @@
+use futures::future::{self, Either};
+use futures::stream::{self, Stream, StreamExt};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use tokio::time::{self, Instant, MissedTickBehavior};
+
+use crate::scanner::is_advertisement_event;
+use crate::{BluetoothEvent, DeviceEvent, DeviceId};
+
+/// Configuration for a [`PresenceTracker`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PresenceConfig {
+    /// How long a device may go without advertising before it is considered lost.
+    pub lost_timeout: Duration,
+    /// Overrides of `lost_timeout` for particular devices, e.g. for devices which are known to
+    /// advertise infrequently.
+    pub device_lost_timeouts: HashMap<DeviceId, Duration>,
+    /// If set, emit a `PresenceEvent::StillPresent` at most this often for each device while it
+    /// keeps advertising.
+    pub still_present_interval: Option<Duration>,
+    /// How often to check for devices which have timed out.
+    pub check_interval: Duration,
+}
+
+impl Default for PresenceConfig {
+    fn default() -> Self {
+        Self {
+            lost_timeout: Duration::from_secs(30),
+            device_lost_timeouts: HashMap::new(),
+            still_present_interval: Some(Duration::from_secs(60)),
+            check_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// A change in whether a device is present, emitted by a [`PresenceTracker`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum PresenceEvent {
+    /// The device has started advertising, either for the first time or after being lost.
+    Appeared { id: DeviceId },
+    /// The device is still advertising. This is emitted periodically according to
+    /// `PresenceConfig::still_present_interval`.
+    StillPresent { id: DeviceId },
+    /// The device hasn't advertised for longer than its lost timeout.
+    Lost { id: DeviceId },
+}
+
+impl PresenceEvent {
+    /// The ID of the device which the event is about.
+    pub fn id(&self) -> &DeviceId {
+        match self {
+            Self::Appeared { id } | Self::StillPresent { id } | Self::Lost { id } => id,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct DevicePresence {
+    /// When the device last advertised.
+    last_seen: Instant,
+    /// When we last emitted an `Appeared` or `StillPresent` event for the device.
+    last_reported: Instant,
+    /// Whether the device is currently connected. Connected devices don't advertise, so they are
+    /// never considered lost.
+    connected: bool,
+}
+
+/// Tracks which devices are present, based on a stream of [`BluetoothEvent`]s.
+///
+/// BlueZ doesn't report when a device goes out of range, it just stops receiving advertisements
+/// from it. This considers a device lost once it hasn't advertised for a configurable time.
+/// Connected devices are considered present until they disconnect. Devices which BlueZ removes are
+/// lost straight away.
+#[derive(Debug)]
+pub struct PresenceTracker {
+    config: PresenceConfig,
+    devices: HashMap<DeviceId, DevicePresence>,
+}
+
+impl PresenceTracker {
+    /// Create a new tracker with the given configuration, which doesn't yet know about any devices.
+    pub fn new(config: PresenceConfig) -> Self {
+        Self {
+            config,
+            devices: HashMap::new(),
+        }
+    }
+
+    /// Set the lost timeout for a particular device, overriding `PresenceConfig::lost_timeout`.
+    pub fn set_lost_timeout(&mut self, id: DeviceId, lost_timeout: Duration) {
+        self.config.device_lost_timeouts.insert(id, lost_timeout);
+    }
+
+    /// Returns whether the given device is currently considered present.
+    pub fn is_present(&self, id: &DeviceId) -> bool {
+        self.devices.contains_key(id)
+    }
+
+    fn lost_timeout(&self, id: &DeviceId) -> Duration {
+        self.config
+            .device_lost_timeouts
+            .get(id)
+            .copied()
+            .unwrap_or(self.config.lost_timeout)
+    }
+
+    /// Update the state of the tracker for the given event received at the given time, and return
+    /// any resulting presence event.
+    pub fn handle_event(&mut self, event: BluetoothEvent, now: Instant) -> Option<PresenceEvent> {
+        let (id, event) = match event {
+            BluetoothEvent::Device { id, event } => (id, event),
+            _ => return None,
+        };
+        let connected = match event {
+            DeviceEvent::Removed => {
+                // BlueZ has forgotten about the device, so there is no need to wait for it to
+                // time out.
+                return self.devices.remove(&id).map(|_| PresenceEvent::Lost { id });
+            }
+            DeviceEvent::Connected { connected } => Some(connected),
+            _ if is_advertisement_event(&event) => None,
+            _ => return None,
+        };
+        match self.devices.get_mut(&id) {
+            Some(device) => {
+                device.last_seen = now;
+                if let Some(connected) = connected {
+                    device.connected = connected;
+                }
+                match self.config.still_present_interval {
+                    Some(interval) if now >= device.last_reported + interval => {
+                        device.last_reported = now;
+                        Some(PresenceEvent::StillPresent { id })
+                    }
+                    _ => None,
+                }
+            }
+            // A disconnection doesn't mean that an unknown device is present.
+            None if connected == Some(false) => None,
+            None => {
+                self.devices.insert(
+                    id.clone(),
+                    DevicePresence {
+                        last_seen: now,
+                        last_reported: now,
+                        connected: connected.unwrap_or(false),
+                    },
+                );
+                Some(PresenceEvent::Appeared { id })
+            }
+        }
+    }
+
+    /// Check for devices which haven't advertised within their lost timeout as of the given time,
+    /// forget about them and return `Lost` events for them.
+    pub fn check_timeouts(&mut self, now: Instant) -> Vec<PresenceEvent> {
+        let lost: Vec<DeviceId> = self
+            .devices
+            .iter()
+            .filter(|(id, device)| {
+                !device.connected && now >= device.last_seen + self.lost_timeout(id)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+        lost.into_iter()
+            .map(|id| {
+                self.devices.remove(&id);
+                PresenceEvent::Lost { id }
+            })
+            .collect()
+    }
+
+    /// Turn the given stream of events into a stream of presence events. The returned stream ends
+    /// when the stream of events does.
+    pub fn track(
+        self,
+        events: impl Stream<Item = BluetoothEvent> + Send + 'static,
+    ) -> impl Stream<Item = PresenceEvent> + Unpin {
+        let mut interval = time::interval(self.config.check_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let state = (self, Box::pin(events), interval, VecDeque::new());
+        stream::unfold(
+            state,
+            |(mut tracker, mut events, mut interval, mut pending)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Some((event, (tracker, events, interval, pending)));
+                    }
+                    let tick = Box::pin(interval.tick());
+                    match future::select(events.next(), tick).await {
+                        Either::Left((Some(event), _)) => {
+                            pending.extend(tracker.handle_event(event, Instant::now()));
+                        }
+                        Either::Left((None, _)) => return None,
+                        Either::Right((now, _)) => pending.extend(tracker.check_timeouts(now)),
+                    }
+                }
+            },
+        )
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rssi_event(id: &DeviceId) -> BluetoothEvent {
+        BluetoothEvent::Device {
+            id: id.clone(),
+            event: DeviceEvent::Rssi { rssi: -60 },
+        }
+    }
+
+    #[test]
+    fn appear_still_present_lost() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let mut tracker = PresenceTracker::new(PresenceConfig {
+            lost_timeout: Duration::from_secs(10),
+            still_present_interval: Some(Duration::from_secs(5)),
+            ..Default::default()
+        });
+        let start = Instant::now();
+        assert_eq!(
+            tracker.handle_event(rssi_event(&id), start),
+            Some(PresenceEvent::Appeared { id: id.clone() })
+        );
+        assert_eq!(
+            tracker.handle_event(rssi_event(&id), start + Duration::from_secs(2)),
+            None
+        );
+        assert_eq!(
+            tracker.handle_event(rssi_event(&id), start + Duration::from_secs(6)),
+            Some(PresenceEvent::StillPresent { id: id.clone() })
+        );
+        assert_eq!(
+            tracker.check_timeouts(start + Duration::from_secs(15)),
+            vec![]
+        );
+        assert_eq!(
+            tracker.check_timeouts(start + Duration::from_secs(16)),
+            vec![PresenceEvent::Lost { id: id.clone() }]
+        );
+        assert!(!tracker.is_present(&id));
+        assert_eq!(
+            tracker.handle_event(rssi_event(&id), start + Duration::from_secs(17)),
+            Some(PresenceEvent::Appeared { id })
+        );
+    }
+
+    #[test]
+    fn per_device_timeout() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let other = DeviceId::new("/org/bluez/hci0/dev_66_55_44_33_22_11");
+        let mut tracker = PresenceTracker::new(PresenceConfig {
+            lost_timeout: Duration::from_secs(10),
+            ..Default::default()
+        });
+        tracker.set_lost_timeout(id.clone(), Duration::from_secs(60));
+        let start = Instant::now();
+        tracker.handle_event(rssi_event(&id), start);
+        tracker.handle_event(rssi_event(&other), start);
+        assert_eq!(
+            tracker.check_timeouts(start + Duration::from_secs(10)),
+            vec![PresenceEvent::Lost { id: other }]
+        );
+        assert!(tracker.is_present(&id));
+    }
+
+    #[test]
+    fn connected_not_lost() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let mut tracker = PresenceTracker::new(PresenceConfig::default());
+        let start = Instant::now();
+        tracker.handle_event(rssi_event(&id), start);
+        tracker.handle_event(
+            BluetoothEvent::Device {
+                id: id.clone(),
+                event: DeviceEvent::Connected { connected: true },
+            },
+            start,
+        );
+        assert_eq!(
+            tracker.check_timeouts(start + Duration::from_secs(600)),
+            vec![]
+        );
+        tracker.handle_event(
+            BluetoothEvent::Device {
+                id: id.clone(),
+                event: DeviceEvent::Connected { connected: false },
+            },
+            start + Duration::from_secs(600),
+        );
+        assert_eq!(
+            tracker.check_timeouts(start + Duration::from_secs(630)),
+            vec![PresenceEvent::Lost { id }]
+        );
+    }
+
+    #[test]
+    fn disconnect_unknown_device() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let mut tracker = PresenceTracker::new(PresenceConfig::default());
+        assert_eq!(
+            tracker.handle_event(
+                BluetoothEvent::Device {
+                    id: id.clone(),
+                    event: DeviceEvent::Connected { connected: false },
+                },
+                Instant::now(),
+            ),
+            None
+        );
+        assert!(!tracker.is_present(&id));
+    }
+
+    #[test]
+    fn removed_lost() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let removed = BluetoothEvent::Device {
+            id: id.clone(),
+            event: DeviceEvent::Removed,
+        };
+        let mut tracker = PresenceTracker::new(PresenceConfig::default());
+        let start = Instant::now();
+        tracker.handle_event(rssi_event(&id), start);
+        assert_eq!(
+            tracker.handle_event(removed.clone(), start + Duration::from_secs(1)),
+            Some(PresenceEvent::Lost { id: id.clone() })
+        );
+        assert!(!tracker.is_present(&id));
+        // A second removal, or one for an unknown device, is ignored.
+        assert_eq!(
+            tracker.handle_event(removed, start + Duration::from_secs(2)),
+            None
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn track_stream() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let events = stream::iter(vec![rssi_event(&id)]).chain(stream::pending());
+        let tracker = PresenceTracker::new(PresenceConfig {
+            lost_timeout: Duration::from_secs(5),
+            ..Default::default()
+        });
+        let presence: Vec<_> = tracker.track(events).take(2).collect().await;
+        assert_eq!(
+            presence,
+            vec![
+                PresenceEvent::Appeared { id: id.clone() },
+                PresenceEvent::Lost { id }
+            ]
+        );
+    }
+}
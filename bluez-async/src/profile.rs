@@ -0,0 +1,192 @@
+use std::convert::TryInto;
+
+use crate::BluetoothError;
+
+/// A type which can be converted to and from the value of a GATT characteristic.
+///
+/// This is used by the typed accessors generated by `#[derive(GattProfile)]` from the
+/// `bluez-async-derive` crate. Integers are encoded in little-endian byte order, as is usual for
+/// GATT characteristics.
+pub trait GattValue: Sized {
+    /// Decode a value from the raw bytes of a characteristic.
+    fn from_gatt_bytes(bytes: &[u8]) -> Result<Self, BluetoothError>;
+
+    /// Encode the value as raw bytes to write to a characteristic.
+    fn to_gatt_bytes(&self) -> Vec<u8>;
+}
+
+impl GattValue for Vec<u8> {
+    fn from_gatt_bytes(bytes: &[u8]) -> Result<Self, BluetoothError> {
+        Ok(bytes.to_owned())
+    }
+
+    fn to_gatt_bytes(&self) -> Vec<u8> {
+        self.clone()
+    }
+}
+
+impl GattValue for String {
+    fn from_gatt_bytes(bytes: &[u8]) -> Result<Self, BluetoothError> {
+        String::from_utf8(bytes.to_owned())
+            .map_err(|e| BluetoothError::InvalidValue(format!("invalid UTF-8 string: {}", e)))
+    }
+
+    fn to_gatt_bytes(&self) -> Vec<u8> {
+        self.as_bytes().to_owned()
+    }
+}
+
+impl GattValue for bool {
+    fn from_gatt_bytes(bytes: &[u8]) -> Result<Self, BluetoothError> {
+        Ok(u8::from_gatt_bytes(bytes)? != 0)
+    }
+
+    fn to_gatt_bytes(&self) -> Vec<u8> {
+        vec![*self as u8]
+    }
+}
+
+macro_rules! impl_gatt_value_for_integer {
+    ($($type:ty),*) => {
+        $(
+            impl GattValue for $type {
+                fn from_gatt_bytes(bytes: &[u8]) -> Result<Self, BluetoothError> {
+                    let bytes = bytes.try_into().map_err(|_| {
+                        BluetoothError::InvalidValue(format!(
+                            "expected {} bytes for {} but got {}",
+                            std::mem::size_of::<Self>(),
+                            stringify!($type),
+                            bytes.len()
+                        ))
+                    })?;
+                    Ok(Self::from_le_bytes(bytes))
+                }
+
+                fn to_gatt_bytes(&self) -> Vec<u8> {
+                    self.to_le_bytes().to_vec()
+                }
+            }
+        )*
+    };
+}
+
+impl_gatt_value_for_integer!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+/// Helpers used by the code generated by `bluez-async-derive`. These are not part of the public
+/// API.
+#[doc(hidden)]
+pub mod __private {
+    pub use futures::Stream;
+    use futures::StreamExt;
+    use uuid::Uuid;
+
+    use super::GattValue;
+    use crate::{
+        BluetoothError, BluetoothEvent, BluetoothSession, CharacteristicEvent, CharacteristicId,
+        DeviceId,
+    };
+
+    /// Look up all the given (service UUID, characteristic UUID, required) triples, failing with a
+    /// single error listing all the required characteristics which are missing.
+    pub async fn resolve(
+        session: &BluetoothSession,
+        device: &DeviceId,
+        uuids: &[(Uuid, Uuid, bool)],
+    ) -> Result<Vec<Option<CharacteristicId>>, BluetoothError> {
+        let pairs: Vec<(Uuid, Uuid)> = uuids
+            .iter()
+            .map(|&(service, characteristic, _)| (service, characteristic))
+            .collect();
+        let characteristics: Vec<Option<CharacteristicId>> = session
+            .get_service_characteristics_by_uuids(device, &pairs)
+            .await?
+            .into_iter()
+            .map(|characteristic| characteristic.map(|characteristic| characteristic.id))
+            .collect();
+        let missing: Vec<Uuid> = uuids
+            .iter()
+            .zip(&characteristics)
+            .filter(|((_, _, required), characteristic)| *required && characteristic.is_none())
+            .map(|((_, uuid, _), _)| *uuid)
+            .collect();
+        if missing.is_empty() {
+            Ok(characteristics)
+        } else {
+            Err(BluetoothError::CharacteristicsNotFound { uuids: missing })
+        }
+    }
+
+    pub fn get(
+        id: Option<&CharacteristicId>,
+        uuid: Uuid,
+    ) -> Result<&CharacteristicId, BluetoothError> {
+        id.ok_or(BluetoothError::UuidNotFound { uuid })
+    }
+
+    pub async fn read<T: GattValue>(
+        session: &BluetoothSession,
+        id: &CharacteristicId,
+    ) -> Result<T, BluetoothError> {
+        T::from_gatt_bytes(&session.read_characteristic_value(id).await?)
+    }
+
+    pub async fn write<T: GattValue>(
+        session: &BluetoothSession,
+        id: &CharacteristicId,
+        value: &T,
+    ) -> Result<(), BluetoothError> {
+        session
+            .write_characteristic_value(id, value.to_gatt_bytes())
+            .await
+    }
+
+    pub async fn subscribe<T: GattValue>(
+        session: &BluetoothSession,
+        id: &CharacteristicId,
+    ) -> Result<impl Stream<Item = Result<T, BluetoothError>>, BluetoothError> {
+        let events = session.characteristic_event_stream(id).await?;
+        session.start_notify(id).await?;
+        Ok(events.filter_map(|event| async move {
+            match event {
+                BluetoothEvent::Characteristic {
+                    event: CharacteristicEvent::Value { value },
+                    ..
+                } => Some(T::from_gatt_bytes(&value)),
+                _ => None,
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers() {
+        assert_eq!(u16::from_gatt_bytes(&[0x34, 0x12]).unwrap(), 0x1234);
+        assert_eq!(0x1234u16.to_gatt_bytes(), vec![0x34, 0x12]);
+        assert_eq!(i8::from_gatt_bytes(&[0xff]).unwrap(), -1);
+        assert!(matches!(
+            u32::from_gatt_bytes(&[1, 2, 3]),
+            Err(BluetoothError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn strings() {
+        assert_eq!(String::from_gatt_bytes(b"hello").unwrap(), "hello");
+        assert_eq!("hello".to_string().to_gatt_bytes(), b"hello");
+        assert!(matches!(
+            String::from_gatt_bytes(&[0xff]),
+            Err(BluetoothError::InvalidValue(_))
+        ));
+    }
+
+    #[test]
+    fn bools() {
+        assert!(bool::from_gatt_bytes(&[1]).unwrap());
+        assert!(!bool::from_gatt_bytes(&[0]).unwrap());
+        assert_eq!(true.to_gatt_bytes(), vec![1]);
+    }
+}
@@ -0,0 +1,532 @@
+use bluez_generated::OrgBluezProfileManager1;
+use dbus::arg::{prop_cast, OwnedFd, PropMap, Variant};
+use dbus::channel::Token;
+use dbus::nonblock::Proxy;
+use dbus::{MethodErr, Path};
+use dbus_crossroads::{Crossroads, IfaceBuilder};
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use futures::ready;
+use socket2::Socket;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::io::{self, Read, Write};
+use std::net::Shutdown;
+use std::pin::Pin;
+use std::sync::{Arc, Weak};
+use std::task::{Context, Poll};
+use tokio::io::unix::AsyncFd;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::runtime::Handle;
+use uuid::Uuid;
+
+use crate::export::{serve, unique_path, unserve};
+use crate::{uuid_from_u16, BluetoothError, BluetoothSession, DeviceId, DBUS_METHOD_CALL_TIMEOUT};
+
+const PROFILE_INTERFACE: &str = "org.bluez.Profile1";
+
+/// The UUID of the Serial Port Profile (SPP), for RFCOMM serial connections.
+pub const SERIAL_PORT_PROFILE_UUID: Uuid = uuid_from_u16(0x1101);
+
+/// The role of a local profile in connections.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProfileRole {
+    /// Connect to remote devices which provide the profile.
+    Client,
+    /// Listen for connections from remote devices.
+    Server,
+}
+
+impl ProfileRole {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Client => "client",
+            Self::Server => "server",
+        }
+    }
+}
+
+impl Display for ProfileRole {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Options for registering a profile with
+/// [`BluetoothSession::register_profile`](struct.BluetoothSession.html#method.register_profile).
+/// Options may be set to `None` to use the BlueZ defaults.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProfileOptions {
+    /// A human-readable name for the profile.
+    pub name: Option<String>,
+    /// The primary service class UUID, if it is different from the profile UUID.
+    pub service: Option<Uuid>,
+    /// Whether to act as a client or server. By default BlueZ does both.
+    pub role: Option<ProfileRole>,
+    /// The RFCOMM channel on which to listen.
+    pub channel: Option<u16>,
+    /// The L2CAP PSM on which to listen.
+    pub psm: Option<u16>,
+    /// Whether remote devices must be paired to connect.
+    pub require_authentication: Option<bool>,
+    /// Whether connections must be authorized by the agent.
+    pub require_authorization: Option<bool>,
+    /// Whether BlueZ should connect the profile automatically when a device connects.
+    pub auto_connect: Option<bool>,
+    /// An SDP record for the profile in XML format, rather than the one BlueZ generates.
+    pub service_record: Option<String>,
+    /// The version of the profile.
+    pub version: Option<u16>,
+    /// The features of the profile.
+    pub features: Option<u16>,
+}
+
+impl From<&ProfileOptions> for PropMap {
+    fn from(options: &ProfileOptions) -> Self {
+        let mut map: PropMap = HashMap::new();
+        if let Some(name) = &options.name {
+            map.insert("Name".to_string(), Variant(Box::new(name.to_owned())));
+        }
+        if let Some(service) = options.service {
+            map.insert(
+                "Service".to_string(),
+                Variant(Box::new(service.to_string())),
+            );
+        }
+        if let Some(role) = options.role {
+            map.insert("Role".to_string(), Variant(Box::new(role.to_string())));
+        }
+        if let Some(channel) = options.channel {
+            map.insert("Channel".to_string(), Variant(Box::new(channel)));
+        }
+        if let Some(psm) = options.psm {
+            map.insert("PSM".to_string(), Variant(Box::new(psm)));
+        }
+        if let Some(require_authentication) = options.require_authentication {
+            map.insert(
+                "RequireAuthentication".to_string(),
+                Variant(Box::new(require_authentication)),
+            );
+        }
+        if let Some(require_authorization) = options.require_authorization {
+            map.insert(
+                "RequireAuthorization".to_string(),
+                Variant(Box::new(require_authorization)),
+            );
+        }
+        if let Some(auto_connect) = options.auto_connect {
+            map.insert("AutoConnect".to_string(), Variant(Box::new(auto_connect)));
+        }
+        if let Some(service_record) = &options.service_record {
+            map.insert(
+                "ServiceRecord".to_string(),
+                Variant(Box::new(service_record.to_owned())),
+            );
+        }
+        if let Some(version) = options.version {
+            map.insert("Version".to_string(), Variant(Box::new(version)));
+        }
+        if let Some(features) = options.features {
+            map.insert("Features".to_string(), Variant(Box::new(features)));
+        }
+        map
+    }
+}
+
+/// An event for a registered profile, from
+/// [`BluetoothSession::register_profile`](struct.BluetoothSession.html#method.register_profile).
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ProfileEvent {
+    /// A new connection has been made for the profile.
+    NewConnection(ProfileConnection),
+    /// BlueZ has requested that connections to the given device be closed, e.g. because it is
+    /// being disconnected. Connections to it have already been shut down, so reads will return
+    /// end-of-file and writes will fail.
+    DisconnectionRequested { device: DeviceId },
+}
+
+/// A connection to a remote device for a registered profile.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ProfileConnection {
+    /// The remote device which is connected.
+    pub device: DeviceId,
+    /// The version of the profile which the remote device supports, if known.
+    pub version: Option<u16>,
+    /// The features of the profile which the remote device supports, if known.
+    pub features: Option<u16>,
+    /// The socket for the connection.
+    pub stream: BluetoothStream,
+}
+
+/// A connected Bluetooth socket, such as an RFCOMM or L2CAP connection for a profile.
+///
+/// This implements tokio's `AsyncRead` and `AsyncWrite`, and must be used within a tokio runtime.
+pub struct BluetoothStream {
+    socket: Arc<AsyncFd<Socket>>,
+}
+
+impl Debug for BluetoothStream {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("BluetoothStream")
+            .field("socket", self.socket.get_ref())
+            .finish()
+    }
+}
+
+impl BluetoothStream {
+    /// Wrap the given socket, which must be in non-blocking mode.
+    fn new(socket: Socket) -> io::Result<Self> {
+        Ok(Self {
+            socket: Arc::new(AsyncFd::new(socket)?),
+        })
+    }
+}
+
+impl AsyncRead for BluetoothStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+        buf: &mut ReadBuf,
+    ) -> Poll<io::Result<()>> {
+        loop {
+            let mut guard = ready!(self.socket.poll_read_ready(cx))?;
+            match guard.try_io(|socket| socket.get_ref().read(buf.initialize_unfilled())) {
+                Ok(Ok(length)) => {
+                    buf.advance(length);
+                    return Poll::Ready(Ok(()));
+                }
+                Ok(Err(e)) => return Poll::Ready(Err(e)),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+}
+
+impl AsyncWrite for BluetoothStream {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context, buf: &[u8]) -> Poll<io::Result<usize>> {
+        loop {
+            let mut guard = ready!(self.socket.poll_write_ready(cx))?;
+            // Avoid SIGPIPE if the remote device has disconnected.
+            match guard.try_io(|socket| socket.get_ref().send_with_flags(buf, libc::MSG_NOSIGNAL)) {
+                Ok(result) => return Poll::Ready(result),
+                Err(_would_block) => continue,
+            }
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(self.socket.get_ref().flush())
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<io::Result<()>> {
+        Poll::Ready(self.socket.get_ref().shutdown(Shutdown::Write))
+    }
+}
+
+/// The data of an exported `org.bluez.Profile1` object.
+struct ProfileObject {
+    runtime: Handle,
+    events: Option<UnboundedSender<ProfileEvent>>,
+    connections: Vec<(DeviceId, Weak<AsyncFd<Socket>>)>,
+}
+
+impl ProfileObject {
+    /// Handle a `NewConnection` call from BlueZ.
+    fn new_connection(
+        &mut self,
+        device: DeviceId,
+        fd: OwnedFd,
+        properties: &PropMap,
+    ) -> Result<(), MethodErr> {
+        let events = self
+            .events
+            .as_ref()
+            .ok_or_else(|| MethodErr::failed("Profile is no longer in use"))?;
+        let socket = Socket::from(fd);
+        socket
+            .set_nonblocking(true)
+            .map_err(|e| MethodErr::failed(&e))?;
+        let stream = {
+            // The AsyncFd must be registered with the runtime's reactor.
+            let _guard = self.runtime.enter();
+            BluetoothStream::new(socket).map_err(|e| MethodErr::failed(&e))?
+        };
+        self.connections
+            .retain(|(_, socket)| socket.strong_count() > 0);
+        self.connections
+            .push((device.clone(), Arc::downgrade(&stream.socket)));
+        events
+            .unbounded_send(ProfileEvent::NewConnection(ProfileConnection {
+                device,
+                version: prop_cast(properties, "Version").copied(),
+                features: prop_cast(properties, "Features").copied(),
+                stream,
+            }))
+            .map_err(|_| MethodErr::failed("Profile is no longer in use"))
+    }
+
+    /// Handle a `RequestDisconnection` call from BlueZ.
+    fn request_disconnection(&mut self, device: DeviceId) {
+        self.connections.retain(|(connection_device, socket)| {
+            if connection_device != &device {
+                return true;
+            }
+            if let Some(socket) = socket.upgrade() {
+                if let Err(e) = socket.get_ref().shutdown(Shutdown::Both) {
+                    log::warn!("Shutting down connection to {} failed: {}", device, e);
+                }
+            }
+            false
+        });
+        if let Some(events) = &self.events {
+            let _ = events.unbounded_send(ProfileEvent::DisconnectionRequested { device });
+        }
+    }
+}
+
+/// Build a `Crossroads` to serve a profile at the given path, sending events to the given sender.
+fn profile_crossroads(
+    path: &Path<'static>,
+    runtime: Handle,
+    events: UnboundedSender<ProfileEvent>,
+) -> Crossroads {
+    let mut cr = Crossroads::new();
+    let token = cr.register(PROFILE_INTERFACE, |b: &mut IfaceBuilder<ProfileObject>| {
+        b.method("Release", (), (), |_, profile, ()| {
+            log::debug!("BlueZ released profile");
+            // Dropping the sender ends the stream of events.
+            profile.events = None;
+            Ok(())
+        });
+        b.method(
+            "NewConnection",
+            ("device", "fd", "fd_properties"),
+            (),
+            |_, profile, (device, fd, properties): (Path<'static>, OwnedFd, PropMap)| {
+                profile.new_connection(
+                    DeviceId {
+                        object_path: device,
+                    },
+                    fd,
+                    &properties,
+                )
+            },
+        );
+        b.method(
+            "RequestDisconnection",
+            ("device",),
+            (),
+            |_, profile, (device,): (Path<'static>,)| {
+                profile.request_disconnection(DeviceId {
+                    object_path: device,
+                });
+                Ok(())
+            },
+        );
+    });
+    cr.insert(
+        path.clone(),
+        &[token],
+        ProfileObject {
+            runtime,
+            events: Some(events),
+            connections: vec![],
+        },
+    );
+    cr
+}
+
+/// A registration of a profile with BlueZ, which is unregistered when it is dropped.
+pub(crate) struct ProfileRegistration {
+    session: BluetoothSession,
+    runtime: Handle,
+    path: Path<'static>,
+    token: Option<Token>,
+}
+
+impl Debug for ProfileRegistration {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ProfileRegistration")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ProfileRegistration {
+    /// Export a profile and register it with BlueZ for the given UUID, returning the registration
+    /// and a stream of events for it.
+    pub(crate) async fn register(
+        session: &BluetoothSession,
+        uuid: Uuid,
+        options: &ProfileOptions,
+    ) -> Result<(Self, UnboundedReceiver<ProfileEvent>), BluetoothError> {
+        let path = unique_path("profile");
+        let runtime = Handle::current();
+        let (sender, receiver) = mpsc::unbounded();
+        let cr = profile_crossroads(&path, runtime.clone(), sender);
+        let token = serve(session, &path, cr);
+        if let Err(e) = profile_manager(session)
+            .register_profile(path.clone(), &uuid.to_string(), options.into())
+            .await
+        {
+            unserve(session, token);
+            return Err(e.into());
+        }
+        Ok((
+            Self {
+                session: session.clone(),
+                runtime,
+                path,
+                token: Some(token),
+            },
+            receiver,
+        ))
+    }
+}
+
+impl Drop for ProfileRegistration {
+    fn drop(&mut self) {
+        if let Some(token) = self.token.take() {
+            let session = self.session.clone();
+            let path = self.path.clone();
+            self.runtime.spawn(async move {
+                let result = profile_manager(&session)
+                    .unregister_profile(path.clone())
+                    .await;
+                unserve(&session, token);
+                if let Err(e) = result {
+                    log::error!("Unregistering profile {} failed: {}", path, e);
+                }
+            });
+        }
+    }
+}
+
+fn profile_manager(session: &BluetoothSession) -> impl OrgBluezProfileManager1 {
+    Proxy::new(
+        "org.bluez",
+        "/org/bluez",
+        DBUS_METHOD_CALL_TIMEOUT,
+        session.connection.clone(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::RecordingSender;
+    use dbus::Message;
+    use futures::StreamExt;
+    use socket2::{Domain, Type};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn call(cr: &mut Crossroads, message: Message) -> Message {
+        let mut message = message;
+        message.set_serial(1);
+        let sender = RecordingSender::default();
+        cr.handle_message(message, &sender).unwrap();
+        sender.0.into_inner().unwrap().remove(0)
+    }
+
+    #[test]
+    fn options() {
+        let options = ProfileOptions {
+            name: Some("Serial Port".to_string()),
+            role: Some(ProfileRole::Server),
+            channel: Some(1),
+            require_authentication: Some(false),
+            ..Default::default()
+        };
+        let map = PropMap::from(&options);
+        assert_eq!(map.len(), 4);
+        assert_eq!(
+            prop_cast::<String>(&map, "Name").map(String::as_str),
+            Some("Serial Port")
+        );
+        assert_eq!(
+            prop_cast::<String>(&map, "Role").map(String::as_str),
+            Some("server")
+        );
+        assert_eq!(prop_cast::<u16>(&map, "Channel"), Some(&1));
+        assert_eq!(
+            prop_cast::<bool>(&map, "RequireAuthentication"),
+            Some(&false)
+        );
+        assert!(PropMap::from(&ProfileOptions::default()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn stream_read_write() {
+        let (ours, theirs) = Socket::pair(Domain::UNIX, Type::STREAM, None).unwrap();
+        ours.set_nonblocking(true).unwrap();
+        theirs.set_nonblocking(true).unwrap();
+        let mut ours = BluetoothStream::new(ours).unwrap();
+        let mut theirs = BluetoothStream::new(theirs).unwrap();
+
+        ours.write_all(b"hello").await.unwrap();
+        let mut buffer = [0; 5];
+        theirs.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(&buffer, b"hello");
+
+        ours.shutdown().await.unwrap();
+        assert_eq!(theirs.read(&mut buffer).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn connection_events() {
+        let path = Path::from("/test/profile0");
+        let (sender, mut receiver) = mpsc::unbounded();
+        let mut cr = profile_crossroads(&path, Handle::current(), sender);
+        let device_path = Path::from("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let device = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+
+        let (ours, theirs) = Socket::pair(Domain::UNIX, Type::STREAM, None).unwrap();
+        let mut properties = PropMap::new();
+        properties.insert("Version".to_string(), Variant(Box::new(0x0102u16)));
+        call(
+            &mut cr,
+            Message::new_method_call(":1.0", path.clone(), PROFILE_INTERFACE, "NewConnection")
+                .unwrap()
+                .append3(device_path.clone(), OwnedFd::from(ours), properties),
+        );
+        let mut connection = match receiver.next().await {
+            Some(ProfileEvent::NewConnection(connection)) => connection,
+            event => panic!("Unexpected event {:?}", event),
+        };
+        assert_eq!(connection.device, device);
+        assert_eq!(connection.version, Some(0x0102));
+        assert_eq!(connection.features, None);
+
+        // Data from the remote device can be read from the stream.
+        (&theirs).write_all(b"data").unwrap();
+        let mut buffer = [0; 4];
+        connection.stream.read_exact(&mut buffer).await.unwrap();
+        assert_eq!(&buffer, b"data");
+
+        // Requesting disconnection shuts down the connection.
+        call(
+            &mut cr,
+            Message::new_method_call(
+                ":1.0",
+                path.clone(),
+                PROFILE_INTERFACE,
+                "RequestDisconnection",
+            )
+            .unwrap()
+            .append1(device_path),
+        );
+        assert!(matches!(
+            receiver.next().await,
+            Some(ProfileEvent::DisconnectionRequested { device: disconnected }) if disconnected == device
+        ));
+        assert_eq!(connection.stream.read(&mut buffer).await.unwrap(), 0);
+
+        // Releasing the profile ends the stream.
+        call(
+            &mut cr,
+            Message::new_method_call(":1.0", path, PROFILE_INTERFACE, "Release").unwrap(),
+        );
+        assert!(receiver.next().await.is_none());
+    }
+}
@@ -0,0 +1,292 @@
+use futures::channel::oneshot;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use crate::{BluetoothError, DeviceId};
+
+/// The priority of an operation in the per-device operation queue.
+///
+/// Operations with a higher priority are run before any waiting operations with a lower priority.
+/// Operations with the same priority are run in the order in which they were submitted.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum OperationPriority {
+    /// Run after all other waiting operations.
+    Low,
+    /// The default priority.
+    #[default]
+    Normal,
+    /// Run before all other waiting operations.
+    High,
+}
+
+/// Configuration for the per-device operation queue of a
+/// [`BluetoothSession`](struct.BluetoothSession.html).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OperationQueueConfig {
+    /// The maximum number of operations which may be waiting for a single device, not counting the
+    /// one which is currently running. Any further operations will fail immediately with
+    /// `BluetoothError::OperationQueueFull`.
+    pub max_queue_depth: usize,
+}
+
+impl Default for OperationQueueConfig {
+    fn default() -> Self {
+        Self {
+            max_queue_depth: 32,
+        }
+    }
+}
+
+/// A queue which ensures that only one operation at a time runs for each device.
+#[derive(Debug)]
+pub(crate) struct OperationQueue {
+    config: OperationQueueConfig,
+    devices: Mutex<HashMap<DeviceId, DeviceQueue>>,
+}
+
+#[derive(Debug, Default)]
+struct DeviceQueue {
+    waiting: BinaryHeap<Waiter>,
+    next_sequence: u64,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    priority: OperationPriority,
+    sequence: u64,
+    sender: oneshot::Sender<OperationPermit>,
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Waiter {}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Waiter {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so the earliest sequence number must compare as greatest.
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.sequence.cmp(&self.sequence))
+    }
+}
+
+/// Permission to run an operation on a device. The next waiting operation for the device is started
+/// when this is dropped.
+#[derive(Debug)]
+pub(crate) struct OperationPermit {
+    inner: Option<(Arc<OperationQueue>, DeviceId)>,
+}
+
+impl Drop for OperationPermit {
+    fn drop(&mut self) {
+        if let Some((queue, device)) = self.inner.take() {
+            queue.release(device);
+        }
+    }
+}
+
+impl OperationQueue {
+    pub fn new(config: OperationQueueConfig) -> Self {
+        Self {
+            config,
+            devices: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait until there is no other operation running for the given device, and then return a
+    /// permit which must be held for the duration of the operation.
+    pub async fn acquire(
+        self: &Arc<Self>,
+        device: &DeviceId,
+        priority: OperationPriority,
+    ) -> Result<OperationPermit, BluetoothError> {
+        let receiver = {
+            let mut devices = self.devices.lock().unwrap();
+            if let Some(device_queue) = devices.get_mut(device) {
+                // Don't count operations which were cancelled while they were waiting.
+                device_queue
+                    .waiting
+                    .retain(|waiter| !waiter.sender.is_canceled());
+                if device_queue.waiting.len() >= self.config.max_queue_depth {
+                    return Err(BluetoothError::OperationQueueFull {
+                        device: device.to_owned(),
+                    });
+                }
+                let (sender, receiver) = oneshot::channel();
+                device_queue.waiting.push(Waiter {
+                    priority,
+                    sequence: device_queue.next_sequence,
+                    sender,
+                });
+                device_queue.next_sequence += 1;
+                receiver
+            } else {
+                // Nothing is running for the device, so the operation can start straight away.
+                devices.insert(device.to_owned(), DeviceQueue::default());
+                return Ok(self.permit(device.to_owned()));
+            }
+        };
+        // The sender is only dropped without sending when the permit is handed to someone else, and
+        // that can't happen while we are still waiting.
+        Ok(receiver
+            .await
+            .expect("Operation queue dropped waiting operation."))
+    }
+
+    fn permit(self: &Arc<Self>, device: DeviceId) -> OperationPermit {
+        OperationPermit {
+            inner: Some((self.clone(), device)),
+        }
+    }
+
+    /// Hand the device over to the next waiting operation, if there is one.
+    fn release(self: &Arc<Self>, device: DeviceId) {
+        loop {
+            let waiter = {
+                let mut devices = self.devices.lock().unwrap();
+                let device_queue = devices
+                    .get_mut(&device)
+                    .expect("Released device missing from operation queue.");
+                match device_queue.waiting.pop() {
+                    Some(waiter) => waiter,
+                    None => {
+                        devices.remove(&device);
+                        return;
+                    }
+                }
+            };
+            // Send the permit without holding the lock, as it may be dropped as part of sending if
+            // the receiver goes away concurrently.
+            match waiter.sender.send(self.permit(device.clone())) {
+                Ok(()) => return,
+                Err(mut permit) => {
+                    // The waiting operation was cancelled, so try the next one instead.
+                    permit.inner = None;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    fn queue(max_queue_depth: usize) -> Arc<OperationQueue> {
+        Arc::new(OperationQueue::new(OperationQueueConfig {
+            max_queue_depth,
+        }))
+    }
+
+    #[tokio::test]
+    async fn different_devices_run_in_parallel() {
+        let queue = queue(1);
+        let device1 = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let device2 = DeviceId::new("/org/bluez/hci0/dev_66_55_44_33_22_11");
+
+        let _permit1 = queue
+            .acquire(&device1, OperationPriority::Normal)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+        let _permit2 = queue
+            .acquire(&device2, OperationPriority::Normal)
+            .now_or_never()
+            .unwrap()
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn same_device_runs_in_priority_order() {
+        let queue = queue(10);
+        let device = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+
+        let permit = queue
+            .acquire(&device, OperationPriority::Normal)
+            .await
+            .unwrap();
+        let mut low = queue.acquire(&device, OperationPriority::Low).boxed();
+        let mut normal = queue.acquire(&device, OperationPriority::Normal).boxed();
+        let mut high = queue.acquire(&device, OperationPriority::High).boxed();
+        assert!((&mut low).now_or_never().is_none());
+        assert!((&mut normal).now_or_never().is_none());
+        assert!((&mut high).now_or_never().is_none());
+
+        drop(permit);
+        assert!((&mut low).now_or_never().is_none());
+        assert!((&mut normal).now_or_never().is_none());
+        let permit = high.await.unwrap();
+
+        drop(permit);
+        assert!((&mut low).now_or_never().is_none());
+        let permit = normal.await.unwrap();
+
+        drop(permit);
+        low.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn cancelled_operation_skipped() {
+        let queue = queue(10);
+        let device = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+
+        let permit = queue
+            .acquire(&device, OperationPriority::Normal)
+            .await
+            .unwrap();
+        let mut cancelled = queue.acquire(&device, OperationPriority::High).boxed();
+        assert!((&mut cancelled).now_or_never().is_none());
+        let mut next = queue.acquire(&device, OperationPriority::Normal).boxed();
+        assert!((&mut next).now_or_never().is_none());
+        drop(cancelled);
+
+        drop(permit);
+        next.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn queue_full() {
+        let queue = queue(1);
+        let device = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+
+        let _permit = queue
+            .acquire(&device, OperationPriority::Normal)
+            .await
+            .unwrap();
+        let mut waiting = queue.acquire(&device, OperationPriority::Normal).boxed();
+        assert!((&mut waiting).now_or_never().is_none());
+        assert!(matches!(
+            queue.acquire(&device, OperationPriority::High).await,
+            Err(BluetoothError::OperationQueueFull { device: full_device }) if full_device == device
+        ));
+    }
+
+    #[tokio::test]
+    async fn cancelled_operations_not_counted() {
+        let queue = queue(1);
+        let device = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+
+        let _permit = queue
+            .acquire(&device, OperationPriority::Normal)
+            .await
+            .unwrap();
+        let mut cancelled = queue.acquire(&device, OperationPriority::Normal).boxed();
+        assert!((&mut cancelled).now_or_never().is_none());
+        drop(cancelled);
+
+        let mut waiting = queue.acquire(&device, OperationPriority::Normal).boxed();
+        assert!((&mut waiting).now_or_never().is_none());
+    }
+}
@@ -0,0 +1,352 @@
+use rand::Rng;
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Formatter};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::BluetoothError;
+
+/// Details of a failed attempt which is about to be retried, passed to the
+/// [`RetryPolicy::on_retry`](struct.RetryPolicy.html#structfield.on_retry) callback.
+#[derive(Debug)]
+pub struct RetryAttempt<'a> {
+    /// The number of the attempt which failed, starting from 1.
+    pub attempt: u32,
+    /// The error with which the attempt failed.
+    pub error: &'a BluetoothError,
+    /// How long we will wait before the next attempt.
+    pub delay: Duration,
+}
+
+/// A predicate to decide whether an error is worth retrying.
+pub type RetryPredicate = Arc<dyn Fn(&BluetoothError) -> bool + Send + Sync>;
+
+/// A callback which is called before each retry.
+pub type RetryCallback = Arc<dyn Fn(&RetryAttempt) + Send + Sync>;
+
+/// A policy for retrying operations which fail with transient errors, with exponential backoff.
+///
+/// This can be attached to a [`BluetoothSession`](struct.BluetoothSession.html) with
+/// `with_retry_policy`, in which case it applies to `connect`, reads, writes and `start_notify`.
+///
+/// Writes are not retried after a D-Bus `NoReply` error by default, because the write may already
+/// have reached the device, and repeating it could run a non-idempotent command twice. Set
+/// `retry_writes_on_no_reply` if the characteristics and descriptors written are idempotent.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts, including the first one. A value of 1 disables retries.
+    pub max_attempts: u32,
+    /// How long to wait before the first retry.
+    pub initial_backoff: Duration,
+    /// The maximum time to wait between attempts.
+    pub max_backoff: Duration,
+    /// The factor by which the time to wait is multiplied after each retry.
+    pub backoff_multiplier: f64,
+    /// The fraction by which to randomly vary each wait, between 0.0 and 1.0. For example, 0.1 means
+    /// that each wait may be up to 10% shorter or longer than it would be otherwise. Values outside
+    /// this range are clamped, and NaN or infinite values disable jitter.
+    pub jitter: f64,
+    /// Predicate to decide whether an error is worth retrying. Defaults to
+    /// [`BluetoothError::is_transient`](enum.BluetoothError.html#method.is_transient).
+    pub retryable: RetryPredicate,
+    /// Whether to retry writes which fail with `org.freedesktop.DBus.Error.NoReply`, even though
+    /// they may have already been carried out. Defaults to false.
+    pub retry_writes_on_no_reply: bool,
+    /// Callback which is called before each retry, in addition to a warning being logged.
+    pub on_retry: Option<RetryCallback>,
+}
+
+impl Debug for RetryPolicy {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("backoff_multiplier", &self.backoff_multiplier)
+            .field("jitter", &self.jitter)
+            .field("retry_writes_on_no_reply", &self.retry_writes_on_no_reply)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            backoff_multiplier: 2.0,
+            jitter: 0.1,
+            retryable: Arc::new(BluetoothError::is_transient),
+            retry_writes_on_no_reply: false,
+            on_retry: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The time to wait after the given attempt (starting from 1) fails, before jitter is applied.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+        let backoff = self.initial_backoff.as_secs_f64() * self.backoff_multiplier.powi(exponent);
+        Duration::try_from_secs_f64(backoff)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    fn backoff_with_jitter(&self, attempt: u32) -> Duration {
+        let backoff = self.backoff(attempt);
+        if !self.jitter.is_finite() {
+            return backoff;
+        }
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return backoff;
+        }
+        backoff.mul_f64(1.0 + rand::thread_rng().gen_range(-jitter..=jitter))
+    }
+
+    /// Run the given operation, retrying it according to this policy if it fails.
+    ///
+    /// The operation is assumed to be safe to repeat. Use `run_write` for operations which might
+    /// not be.
+    pub async fn run<T, F: Future<Output = Result<T, BluetoothError>>>(
+        &self,
+        operation: impl FnMut() -> F,
+    ) -> Result<T, BluetoothError> {
+        self.run_inner(operation, false).await
+    }
+
+    /// Run the given write operation, retrying it according to this policy if it fails.
+    ///
+    /// Unlike `run`, this doesn't retry after a D-Bus `NoReply` error unless
+    /// `retry_writes_on_no_reply` is set.
+    pub async fn run_write<T, F: Future<Output = Result<T, BluetoothError>>>(
+        &self,
+        operation: impl FnMut() -> F,
+    ) -> Result<T, BluetoothError> {
+        self.run_inner(operation, true).await
+    }
+
+    /// Returns whether the given error from an operation should be retried.
+    fn should_retry(&self, error: &BluetoothError, write: bool) -> bool {
+        if write && !self.retry_writes_on_no_reply && error.is_no_reply() {
+            return false;
+        }
+        (self.retryable)(error)
+    }
+
+    async fn run_inner<T, F: Future<Output = Result<T, BluetoothError>>>(
+        &self,
+        mut operation: impl FnMut() -> F,
+        write: bool,
+    ) -> Result<T, BluetoothError> {
+        let mut attempt = 1;
+        loop {
+            match operation().await {
+                Err(error) if attempt < self.max_attempts && self.should_retry(&error, write) => {
+                    let delay = self.backoff_with_jitter(attempt);
+                    log::warn!(
+                        "Attempt {} of {} failed ({}), retrying in {:?}",
+                        attempt,
+                        self.max_attempts,
+                        error,
+                        delay
+                    );
+                    if let Some(on_retry) = &self.on_retry {
+                        on_retry(&RetryAttempt {
+                            attempt,
+                            error: &error,
+                            delay,
+                        });
+                    }
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn in_progress() -> BluetoothError {
+        dbus::Error::new_custom(
+            "org.bluez.Error.InProgress",
+            "Operation already in progress",
+        )
+        .into()
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            jitter: 0.0,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[test]
+    fn backoff() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+            backoff_multiplier: 2.0,
+            ..policy()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+        assert_eq!(policy.backoff(100), Duration::from_secs(5));
+    }
+
+    #[test]
+    fn backoff_jitter() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_secs(10),
+            jitter: 0.5,
+            ..policy()
+        };
+        for _ in 0..100 {
+            let backoff = policy.backoff_with_jitter(1);
+            assert!(backoff >= Duration::from_secs(5));
+            assert!(backoff <= Duration::from_secs(15));
+        }
+    }
+
+    #[test]
+    fn backoff_invalid_jitter() {
+        for jitter in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY].iter().copied() {
+            let policy = RetryPolicy {
+                initial_backoff: Duration::from_secs(10),
+                jitter,
+                ..policy()
+            };
+            assert_eq!(policy.backoff_with_jitter(1), Duration::from_secs(10));
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retries_until_success() {
+        let attempts = AtomicU32::new(0);
+        let result = policy()
+            .run(|| async {
+                if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+                    Err(in_progress())
+                } else {
+                    Ok(42)
+                }
+            })
+            .await;
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn gives_up_after_max_attempts() {
+        let attempts = AtomicU32::new(0);
+        let retries = Arc::new(AtomicU32::new(0));
+        let retries_clone = retries.clone();
+        let policy = RetryPolicy {
+            max_attempts: 4,
+            on_retry: Some(Arc::new(move |retry: &RetryAttempt| {
+                retries_clone.fetch_add(1, Ordering::SeqCst);
+                assert!(retry.error.is_transient());
+            })),
+            ..policy()
+        };
+        let result: Result<(), _> = policy
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(in_progress())
+            })
+            .await;
+        assert!(matches!(result, Err(BluetoothError::DbusError(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 4);
+        assert_eq!(retries.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn does_not_retry_permanent_error() {
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), _> = policy()
+            .run(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(BluetoothError::NoBluetoothAdapters)
+            })
+            .await;
+        assert!(matches!(result, Err(BluetoothError::NoBluetoothAdapters)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn write_not_retried_on_no_reply() {
+        let no_reply = || {
+            BluetoothError::from(dbus::Error::new_custom(
+                "org.freedesktop.DBus.Error.NoReply",
+                "Did not receive a reply",
+            ))
+        };
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), _> = policy()
+            .run_write(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(no_reply())
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+
+        // Other transient errors are still retried.
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), _> = policy()
+            .run_write(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(in_progress())
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        let attempts = AtomicU32::new(0);
+        let policy = RetryPolicy {
+            retry_writes_on_no_reply: true,
+            ..policy()
+        };
+        let result: Result<(), _> = policy
+            .run_write(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(no_reply())
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn transient_errors() {
+        assert!(in_progress().is_transient());
+        assert!(BluetoothError::ServiceDiscoveryTimedOut.is_transient());
+        assert!(BluetoothError::from(dbus::Error::new_custom(
+            "org.bluez.Error.Failed",
+            "le-connection-abort-by-local"
+        ))
+        .is_transient());
+        assert!(!BluetoothError::from(dbus::Error::new_custom(
+            "org.bluez.Error.Failed",
+            "Not connected"
+        ))
+        .is_transient());
+        assert!(!BluetoothError::from(dbus::Error::new_custom(
+            "org.bluez.Error.NotPermitted",
+            "Read not permitted"
+        ))
+        .is_transient());
+        assert!(!BluetoothError::NoBluetoothAdapters.is_transient());
+    }
+}
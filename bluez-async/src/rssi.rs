@@ -0,0 +1,514 @@
+use futures::future::{self, Ready};
+use futures::stream::{FilterMap, Stream, StreamExt};
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+use tokio::time::Instant;
+
+use crate::{BluetoothEvent, DeviceEvent, DeviceId};
+
+/// The approximate free-space path loss at 1 m for 2.4 GHz, in dB. This is used to estimate the
+/// RSSI at 1 m from the advertised transmission power.
+const PATH_LOSS_AT_1_M: i16 = 41;
+
+/// A filter to smooth noisy RSSI values.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RssiFilter {
+    /// An exponential moving average, where each new value is weighted by `alpha` (between 0.0 and
+    /// 1.0) and the previous average by `1 - alpha`.
+    ExponentialMovingAverage { alpha: f64 },
+    /// The median of the last `window` values.
+    Median { window: usize },
+    /// A one-dimensional Kalman filter, assuming the true RSSI is roughly constant.
+    Kalman {
+        /// The variance of the true RSSI between readings. Higher values follow changes faster.
+        process_noise: f64,
+        /// The variance of the measurement noise. Higher values smooth more.
+        measurement_noise: f64,
+    },
+}
+
+impl Default for RssiFilter {
+    fn default() -> Self {
+        Self::ExponentialMovingAverage { alpha: 0.25 }
+    }
+}
+
+/// The state of an [`RssiFilter`] for a single device.
+#[derive(Clone, Debug)]
+pub struct RssiSmoother {
+    filter: RssiFilter,
+    value: Option<f64>,
+    /// The last few values, for the median filter.
+    window: VecDeque<i16>,
+    /// The estimated error variance, for the Kalman filter.
+    error: f64,
+}
+
+impl RssiSmoother {
+    /// Create a new smoother using the given filter, with no readings yet.
+    pub fn new(filter: RssiFilter) -> Self {
+        Self {
+            filter,
+            value: None,
+            window: VecDeque::new(),
+            error: 0.0,
+        }
+    }
+
+    /// Get the current smoothed value, or `None` if there have been no readings yet.
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// Add a new raw reading, and return the new smoothed value.
+    pub fn update(&mut self, rssi: i16) -> f64 {
+        let rssi_f = f64::from(rssi);
+        let value = match self.filter {
+            RssiFilter::ExponentialMovingAverage { alpha } => match self.value {
+                Some(previous) => {
+                    let alpha = alpha.clamp(0.0, 1.0);
+                    alpha * rssi_f + (1.0 - alpha) * previous
+                }
+                None => rssi_f,
+            },
+            RssiFilter::Median { window } => {
+                self.window.push_back(rssi);
+                while self.window.len() > window.max(1) {
+                    self.window.pop_front();
+                }
+                median(&self.window)
+            }
+            RssiFilter::Kalman {
+                process_noise,
+                measurement_noise,
+            } => match self.value {
+                Some(previous) => {
+                    let predicted_error = self.error + process_noise;
+                    let gain = predicted_error / (predicted_error + measurement_noise);
+                    self.error = (1.0 - gain) * predicted_error;
+                    previous + gain * (rssi_f - previous)
+                }
+                None => {
+                    self.error = measurement_noise;
+                    rssi_f
+                }
+            },
+        };
+        self.value = Some(value);
+        value
+    }
+}
+
+fn median(values: &VecDeque<i16>) -> f64 {
+    let mut sorted: Vec<i16> = values.iter().copied().collect();
+    sorted.sort_unstable();
+    let middle = sorted.len() / 2;
+    if sorted.len() % 2 == 1 {
+        f64::from(sorted[middle])
+    } else {
+        (f64::from(sorted[middle - 1]) + f64::from(sorted[middle])) / 2.0
+    }
+}
+
+/// Estimates the distance to a device from its RSSI using the log-distance path loss model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DistanceEstimator {
+    /// The calibrated RSSI at 1 m, in dBm, to use for devices which don't advertise their
+    /// transmission power.
+    pub measured_power: i16,
+    /// The path loss exponent of the environment. This is 2.0 in free space, and typically between
+    /// 2.0 and 4.0 indoors.
+    pub path_loss_exponent: f64,
+}
+
+impl Default for DistanceEstimator {
+    fn default() -> Self {
+        Self {
+            measured_power: -59,
+            path_loss_exponent: 2.0,
+        }
+    }
+}
+
+impl DistanceEstimator {
+    /// Estimate the distance in metres to a device with the given RSSI.
+    ///
+    /// If the device advertises its transmission power then this is used to estimate the RSSI at
+    /// 1 m, otherwise `measured_power` is used.
+    pub fn estimate(&self, rssi: f64, tx_power: Option<i16>) -> f64 {
+        let measured_power = tx_power.map_or(self.measured_power, |tx_power| {
+            tx_power.saturating_sub(PATH_LOSS_AT_1_M)
+        });
+        10f64.powf((f64::from(measured_power) - rssi) / (10.0 * self.path_loss_exponent))
+    }
+}
+
+/// Configuration for [`RssiStreamExt::smoothed_rssi`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RssiConfig {
+    /// The filter to apply to the RSSI values of each device.
+    pub filter: RssiFilter,
+    /// How to estimate the distance to each device.
+    pub distance: DistanceEstimator,
+    /// How long to keep the state for a device which hasn't sent any events. After this the
+    /// smoothing starts again from scratch if the device is seen again.
+    pub idle_timeout: Duration,
+}
+
+impl Default for RssiConfig {
+    fn default() -> Self {
+        Self {
+            filter: RssiFilter::default(),
+            distance: DistanceEstimator::default(),
+            idle_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// A smoothed RSSI reading for a device, emitted by [`RssiStreamExt::smoothed_rssi`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RssiReading {
+    /// The device which the reading is for.
+    pub id: DeviceId,
+    /// The raw RSSI value which was just received, in dBm.
+    pub raw_rssi: i16,
+    /// The smoothed RSSI value, in dBm.
+    pub rssi: f64,
+    /// The most recent transmission power level advertised by the device, if any.
+    pub tx_power: Option<i16>,
+    /// The estimated distance to the device in metres, based on the smoothed RSSI.
+    pub distance: f64,
+}
+
+/// The per-device state of a smoothed RSSI stream.
+#[derive(Debug)]
+struct DeviceRssi {
+    smoother: RssiSmoother,
+    /// When the device last sent an RSSI event.
+    last_seen: Instant,
+}
+
+/// The state of a smoothed RSSI stream, for all devices.
+#[derive(Debug)]
+struct RssiState {
+    config: RssiConfig,
+    devices: HashMap<DeviceId, DeviceRssi>,
+    /// The most recent TX power of each device. This is kept separately from the smoothing state
+    /// as BlueZ only reports it again if it changes, so it is only forgotten when the device is
+    /// removed.
+    tx_powers: HashMap<DeviceId, i16>,
+    /// When idle devices were last evicted.
+    last_evicted: Instant,
+}
+
+impl RssiState {
+    fn new(config: RssiConfig, now: Instant) -> Self {
+        Self {
+            config,
+            devices: HashMap::new(),
+            tx_powers: HashMap::new(),
+            last_evicted: now,
+        }
+    }
+
+    /// Get the state for the given device, starting afresh if it is new or has been idle for too
+    /// long.
+    fn device(&mut self, id: DeviceId, now: Instant) -> &mut DeviceRssi {
+        let filter = self.config.filter;
+        let idle_timeout = self.config.idle_timeout;
+        let device = self.devices.entry(id).or_insert_with(|| DeviceRssi {
+            smoother: RssiSmoother::new(filter),
+            last_seen: now,
+        });
+        if now > device.last_seen + idle_timeout {
+            device.smoother = RssiSmoother::new(filter);
+        }
+        device.last_seen = now;
+        device
+    }
+
+    /// Forget about devices which haven't sent any events for longer than the idle timeout. This
+    /// only checks at most once per idle timeout, so that it doesn't need to go through all devices
+    /// for every event.
+    fn evict_idle(&mut self, now: Instant) {
+        let idle_timeout = self.config.idle_timeout;
+        if now < self.last_evicted + idle_timeout {
+            return;
+        }
+        self.devices
+            .retain(|_, device| now <= device.last_seen + idle_timeout);
+        self.last_evicted = now;
+    }
+
+    fn handle_event(&mut self, event: BluetoothEvent, now: Instant) -> Option<RssiReading> {
+        self.evict_idle(now);
+        let (id, event) = match event {
+            BluetoothEvent::Device { id, event } => (id, event),
+            _ => return None,
+        };
+        match event {
+            DeviceEvent::Removed => {
+                self.devices.remove(&id);
+                self.tx_powers.remove(&id);
+                None
+            }
+            DeviceEvent::TxPower { tx_power } => {
+                self.tx_powers.insert(id, tx_power);
+                None
+            }
+            DeviceEvent::Rssi { rssi } => {
+                let distance = self.config.distance;
+                let tx_power = self.tx_powers.get(&id).copied();
+                let smoothed = self.device(id.clone(), now).smoother.update(rssi);
+                Some(RssiReading {
+                    id,
+                    raw_rssi: rssi,
+                    rssi: smoothed,
+                    tx_power,
+                    distance: distance.estimate(smoothed, tx_power),
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The stream returned by [`RssiStreamExt::smoothed_rssi`].
+pub type SmoothedRssi<S> = FilterMap<
+    S,
+    Ready<Option<RssiReading>>,
+    Box<dyn FnMut(BluetoothEvent) -> Ready<Option<RssiReading>> + Send>,
+>;
+
+/// An extension trait for streams of [`BluetoothEvent`]s, such as those returned by
+/// `BluetoothSession::event_stream` or `BluetoothSession::adapter_event_stream`.
+pub trait RssiStreamExt: Stream<Item = BluetoothEvent> + Sized {
+    /// Turn the stream of events into a stream of smoothed RSSI readings and estimated distances,
+    /// one for each `DeviceEvent::Rssi` event. Other events are used to keep track of the
+    /// transmission power of each device but otherwise dropped.
+    ///
+    /// The smoothing state for a device is dropped when it is removed, or once it has been idle for
+    /// longer than `RssiConfig::idle_timeout`. The transmission power is kept until the device is
+    /// removed.
+    ///
+    /// BlueZ only sends a `DeviceEvent::TxPower` event when the transmission power changes, so for
+    /// devices which were already known before the stream started callers should seed it from
+    /// `DeviceInfo::tx_power`, by putting a `TxPower` event for each device at the start of the
+    /// stream:
+    ///
+    /// ```no_run
+    /// # use bluez_async::{BluetoothEvent, BluetoothSession, DeviceEvent, RssiConfig, RssiStreamExt};
+    /// # use futures::stream::{self, StreamExt};
+    /// # async fn example(session: &BluetoothSession) -> Result<(), Box<dyn std::error::Error>> {
+    /// let events = session.event_stream().await?;
+    /// let tx_powers = session.get_devices().await?.into_iter().filter_map(|device| {
+    ///     Some(BluetoothEvent::Device {
+    ///         id: device.id,
+    ///         event: DeviceEvent::TxPower {
+    ///             tx_power: device.tx_power?,
+    ///         },
+    ///     })
+    /// });
+    /// let mut readings = stream::iter(tx_powers)
+    ///     .chain(events)
+    ///     .smoothed_rssi(RssiConfig::default());
+    /// while let Some(reading) = readings.next().await {
+    ///     println!("{:?}", reading);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn smoothed_rssi(self, config: RssiConfig) -> SmoothedRssi<Self> {
+        let mut state = RssiState::new(config, Instant::now());
+        self.filter_map(Box::new(move |event| {
+            future::ready(state.handle_event(event, Instant::now()))
+        }))
+    }
+}
+
+impl<S: Stream<Item = BluetoothEvent>> RssiStreamExt for S {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn smooth(filter: RssiFilter, values: &[i16]) -> Vec<f64> {
+        let mut smoother = RssiSmoother::new(filter);
+        values.iter().map(|&rssi| smoother.update(rssi)).collect()
+    }
+
+    #[test]
+    fn exponential_moving_average() {
+        assert_eq!(
+            smooth(
+                RssiFilter::ExponentialMovingAverage { alpha: 0.5 },
+                &[-60, -70, -70]
+            ),
+            vec![-60.0, -65.0, -67.5]
+        );
+    }
+
+    #[test]
+    fn median_window() {
+        assert_eq!(
+            smooth(RssiFilter::Median { window: 3 }, &[-60, -90, -62, -61, -80]),
+            vec![-60.0, -75.0, -62.0, -62.0, -62.0]
+        );
+    }
+
+    #[test]
+    fn kalman() {
+        let values = smooth(
+            RssiFilter::Kalman {
+                process_noise: 0.01,
+                measurement_noise: 4.0,
+            },
+            &[-60, -80, -60, -60],
+        );
+        assert_eq!(values[0], -60.0);
+        // An outlier moves the estimate only part of the way.
+        assert!(values[1] < -60.0 && values[1] > -80.0);
+        assert!(values[3] > values[1]);
+    }
+
+    #[test]
+    fn distance() {
+        let estimator = DistanceEstimator::default();
+        assert!((estimator.estimate(-59.0, None) - 1.0).abs() < 1e-9);
+        assert!((estimator.estimate(-79.0, None) - 10.0).abs() < 1e-9);
+        // Advertised TX power of 0 dBm gives -41 dBm at 1 m.
+        assert!((estimator.estimate(-41.0, Some(0)) - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn evict_idle_devices() {
+        let start = Instant::now();
+        let mut state = RssiState::new(RssiConfig::default(), start);
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let other = DeviceId::new("/org/bluez/hci0/dev_66_55_44_33_22_11");
+        let rssi = |id: &DeviceId, rssi| BluetoothEvent::Device {
+            id: id.clone(),
+            event: DeviceEvent::Rssi { rssi },
+        };
+
+        state.handle_event(rssi(&id, -60), start);
+        state.handle_event(rssi(&other, -60), start + Duration::from_secs(50));
+        assert_eq!(state.devices.len(), 2);
+
+        // The first device has been idle for too long, but the other hasn't.
+        state.handle_event(rssi(&other, -60), start + Duration::from_secs(70));
+        assert_eq!(state.devices.len(), 1);
+        assert!(state.devices.contains_key(&other));
+
+        // Smoothing starts again for a device which comes back.
+        let reading = state
+            .handle_event(rssi(&id, -80), start + Duration::from_secs(80))
+            .unwrap();
+        assert_eq!(reading.rssi, -80.0);
+
+        state.handle_event(
+            BluetoothEvent::Device {
+                id: other.clone(),
+                event: DeviceEvent::Removed,
+            },
+            start + Duration::from_secs(90),
+        );
+        assert!(!state.devices.contains_key(&other));
+    }
+
+    #[test]
+    fn reset_after_idle_timeout() {
+        let start = Instant::now();
+        let mut state = RssiState::new(RssiConfig::default(), start);
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let other = DeviceId::new("/org/bluez/hci0/dev_66_55_44_33_22_11");
+        let rssi = |id: &DeviceId, rssi| BluetoothEvent::Device {
+            id: id.clone(),
+            event: DeviceEvent::Rssi { rssi },
+        };
+
+        state.handle_event(rssi(&id, -60), start);
+        // Eviction runs here, but the first device isn't idle for too long yet.
+        state.handle_event(rssi(&other, -60), start + Duration::from_secs(60));
+        assert!(state.devices.contains_key(&id));
+        // Eviction doesn't run again until 120 seconds, but the device is still reset.
+        let reading = state
+            .handle_event(rssi(&id, -80), start + Duration::from_secs(61))
+            .unwrap();
+        assert_eq!(reading.rssi, -80.0);
+    }
+
+    #[test]
+    fn keep_tx_power_after_idle_timeout() {
+        let start = Instant::now();
+        let mut state = RssiState::new(RssiConfig::default(), start);
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let event = |event| BluetoothEvent::Device {
+            id: id.clone(),
+            event,
+        };
+
+        state.handle_event(event(DeviceEvent::TxPower { tx_power: 0 }), start);
+        state.handle_event(event(DeviceEvent::Rssi { rssi: -60 }), start);
+        // Both the in-place reset and eviction of the idle device keep the TX power.
+        let reading = state
+            .handle_event(
+                event(DeviceEvent::Rssi { rssi: -41 }),
+                start + Duration::from_secs(61),
+            )
+            .unwrap();
+        assert_eq!(reading.rssi, -41.0);
+        assert_eq!(reading.tx_power, Some(0));
+        assert_eq!(reading.distance, 1.0);
+        let reading = state
+            .handle_event(
+                event(DeviceEvent::Rssi { rssi: -41 }),
+                start + Duration::from_secs(200),
+            )
+            .unwrap();
+        assert_eq!(reading.tx_power, Some(0));
+
+        // It is forgotten when the device is removed.
+        state.handle_event(
+            event(DeviceEvent::Removed),
+            start + Duration::from_secs(201),
+        );
+        let reading = state
+            .handle_event(
+                event(DeviceEvent::Rssi { rssi: -41 }),
+                start + Duration::from_secs(202),
+            )
+            .unwrap();
+        assert_eq!(reading.tx_power, None);
+    }
+
+    #[tokio::test]
+    async fn stream() {
+        let id = DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66");
+        let events = futures::stream::iter(vec![
+            BluetoothEvent::Device {
+                id: id.clone(),
+                event: DeviceEvent::TxPower { tx_power: 0 },
+            },
+            BluetoothEvent::Device {
+                id: id.clone(),
+                event: DeviceEvent::Connected { connected: true },
+            },
+            BluetoothEvent::Device {
+                id: id.clone(),
+                event: DeviceEvent::Rssi { rssi: -41 },
+            },
+        ]);
+        let readings: Vec<_> = events.smoothed_rssi(RssiConfig::default()).collect().await;
+        assert_eq!(
+            readings,
+            vec![RssiReading {
+                id,
+                raw_rssi: -41,
+                rssi: -41.0,
+                tx_power: Some(0),
+                distance: 1.0,
+            }]
+        );
+    }
+}
@@ -0,0 +1,449 @@
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+use uuid::Uuid;
+
+use crate::{
+    AdapterId, AddressType, DeviceEvent, DeviceId, DeviceInfo, DiscoveryFilter, MacAddress,
+};
+
+/// A scan for nearby devices, combining a [`DiscoveryFilter`] which BlueZ applies with additional
+/// filters, deduplication and rate limiting applied on the client side.
+///
+/// Start the scan with [`BluetoothSession::scan`](struct.BluetoothSession.html#method.scan).
+///
+/// ```no_run
+/// # use bluez_async::{BluetoothSession, Scanner};
+/// # use futures::StreamExt;
+/// # use std::time::Duration;
+/// # async fn example(session: &BluetoothSession) -> Result<(), Box<dyn std::error::Error>> {
+/// let scanner = Scanner::new()
+///     .name_filter(|name| name.starts_with("Ruuvi "))
+///     .min_rssi(-80)
+///     .rate_limit(Duration::from_secs(10));
+/// let mut results = session.scan(&scanner).await?;
+/// while let Some(result) = results.next().await {
+///     println!("{:?}", result);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct Scanner {
+    discovery_filter: DiscoveryFilter,
+    adapter: Option<AdapterId>,
+    name_filter: Option<Arc<NameFilter>>,
+    manufacturer_ids: Vec<u16>,
+    service_data_uuids: Vec<Uuid>,
+    address_type: Option<AddressType>,
+    min_rssi: Option<i16>,
+    dedup_window: Option<Duration>,
+    rate_limit: Option<Duration>,
+}
+
+impl Scanner {
+    /// Create a new scanner which reports all devices on all adapters.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the discovery filter to pass to BlueZ. This defaults to `DiscoveryFilter::default()`.
+    pub fn discovery_filter(mut self, discovery_filter: DiscoveryFilter) -> Self {
+        self.discovery_filter = discovery_filter;
+        self
+    }
+
+    /// Only scan on the given adapter, rather than all adapters on the system.
+    pub fn adapter(mut self, adapter: AdapterId) -> Self {
+        self.adapter = Some(adapter);
+        self
+    }
+
+    /// Only report devices whose advertised name the given predicate returns `true` for. Devices
+    /// which don't advertise a name are not reported.
+    ///
+    /// This takes a predicate rather than a regular expression so that this crate doesn't need to
+    /// depend on `regex`. To match names against a regular expression, wrap it in a closure:
+    ///
+    /// ```
+    /// # use bluez_async::Scanner;
+    /// # use regex::Regex;
+    /// let pattern = Regex::new(r"^Ruuvi [0-9A-F]{4}$").unwrap();
+    /// let scanner = Scanner::new().name_filter(move |name| pattern.is_match(name));
+    /// ```
+    pub fn name_filter(
+        mut self,
+        name_filter: impl Fn(&str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.name_filter = Some(Arc::new(name_filter));
+        self
+    }
+
+    /// Only report devices with manufacturer-specific data for the given manufacturer ID. If this
+    /// is called several times then devices with data for any of the IDs are reported.
+    pub fn manufacturer_id(mut self, manufacturer_id: u16) -> Self {
+        self.manufacturer_ids.push(manufacturer_id);
+        self
+    }
+
+    /// Only report devices with service data for the given service UUID. If this is called several
+    /// times then devices with data for any of the UUIDs are reported.
+    pub fn service_data_uuid(mut self, uuid: Uuid) -> Self {
+        self.service_data_uuids.push(uuid);
+        self
+    }
+
+    /// Only report devices with the given address type.
+    pub fn address_type(mut self, address_type: AddressType) -> Self {
+        self.address_type = Some(address_type);
+        self
+    }
+
+    /// Only report devices whose RSSI is at least the given value. Devices with no RSSI are not
+    /// reported.
+    pub fn min_rssi(mut self, min_rssi: i16) -> Self {
+        self.min_rssi = Some(min_rssi);
+        self
+    }
+
+    /// Don't report a device again within the given time if its advertisement data is unchanged
+    /// since it was last reported. Changes in RSSI alone don't count.
+    pub fn dedup_window(mut self, dedup_window: Duration) -> Self {
+        self.dedup_window = Some(dedup_window);
+        self
+    }
+
+    /// Report each device at most once within the given time, even if its advertisement data has
+    /// changed.
+    pub fn rate_limit(mut self, rate_limit: Duration) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    pub(crate) fn get_discovery_filter(&self) -> &DiscoveryFilter {
+        &self.discovery_filter
+    }
+
+    pub(crate) fn get_adapter(&self) -> Option<&AdapterId> {
+        self.adapter.as_ref()
+    }
+
+    /// Returns whether the given result matches all the client-side filters of this scanner.
+    pub fn matches(&self, result: &ScanResult) -> bool {
+        if let Some(name_filter) = &self.name_filter {
+            if !result.name.as_deref().is_some_and(|name| name_filter(name)) {
+                return false;
+            }
+        }
+        if !self.manufacturer_ids.is_empty()
+            && !self
+                .manufacturer_ids
+                .iter()
+                .any(|id| result.manufacturer_data.contains_key(id))
+        {
+            return false;
+        }
+        if !self.service_data_uuids.is_empty()
+            && !self
+                .service_data_uuids
+                .iter()
+                .any(|uuid| result.service_data.contains_key(uuid))
+        {
+            return false;
+        }
+        if let Some(address_type) = self.address_type {
+            if result.address_type != address_type {
+                return false;
+            }
+        }
+        if let Some(min_rssi) = self.min_rssi {
+            if !matches!(result.rssi, Some(rssi) if rssi >= min_rssi) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+impl Debug for Scanner {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Scanner")
+            .field("discovery_filter", &self.discovery_filter)
+            .field("adapter", &self.adapter)
+            .field("name_filter", &self.name_filter.as_ref().map(|_| ".."))
+            .field("manufacturer_ids", &self.manufacturer_ids)
+            .field("service_data_uuids", &self.service_data_uuids)
+            .field("address_type", &self.address_type)
+            .field("min_rssi", &self.min_rssi)
+            .field("dedup_window", &self.dedup_window)
+            .field("rate_limit", &self.rate_limit)
+            .finish()
+    }
+}
+
+type NameFilter = dyn Fn(&str) -> bool + Send + Sync;
+
+/// The latest advertisement data for a device found by a [`Scanner`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScanResult {
+    /// The ID of the device, which can be used to connect to it.
+    pub id: DeviceId,
+    /// The MAC address of the device.
+    pub mac_address: MacAddress,
+    /// The type of MAC address the device uses.
+    pub address_type: AddressType,
+    /// The advertised name of the device, if any.
+    pub name: Option<String>,
+    /// The Received Signal Strength Indicator of the latest advertisement.
+    pub rssi: Option<i16>,
+    /// The transmission power level advertised by the device.
+    pub tx_power: Option<i16>,
+    /// Manufacturer-specific advertisement data. The keys are 'manufacturer IDs'.
+    pub manufacturer_data: HashMap<u16, Vec<u8>>,
+    /// GATT service advertisement data. This is a map from the service UUID to its data.
+    pub service_data: HashMap<Uuid, Vec<u8>>,
+    /// The GATT service UUIDs advertised by the device.
+    pub services: Vec<Uuid>,
+}
+
+impl ScanResult {
+    /// Returns whether the advertisement data of the two results is the same, ignoring RSSI.
+    fn same_advertisement(&self, other: &ScanResult) -> bool {
+        self.name == other.name
+            && self.tx_power == other.tx_power
+            && self.manufacturer_data == other.manufacturer_data
+            && self.service_data == other.service_data
+            && self.services == other.services
+    }
+}
+
+impl From<DeviceInfo> for ScanResult {
+    fn from(device: DeviceInfo) -> Self {
+        Self {
+            id: device.id,
+            mac_address: device.mac_address,
+            address_type: device.address_type,
+            name: device.name,
+            rssi: device.rssi,
+            tx_power: device.tx_power,
+            manufacturer_data: device.manufacturer_data,
+            service_data: device.service_data,
+            services: device.services,
+        }
+    }
+}
+
+/// Returns whether the given device event may indicate new advertisement data.
+pub(crate) fn is_advertisement_event(event: &DeviceEvent) -> bool {
+    matches!(
+        event,
+        DeviceEvent::Discovered
+            | DeviceEvent::Rssi { .. }
+            | DeviceEvent::TxPower { .. }
+            | DeviceEvent::ManufacturerData { .. }
+            | DeviceEvent::ServiceData { .. }
+            | DeviceEvent::Services { .. }
+    )
+}
+
+/// The result most recently reported for a device, and when.
+#[derive(Debug)]
+struct Reported {
+    result: ScanResult,
+    at: Instant,
+}
+
+/// Client-side state of a scan, for deduplication and rate limiting.
+#[derive(Clone, Debug)]
+pub(crate) struct ScanState {
+    scanner: Scanner,
+    /// The latest known advertisement data for each device seen during the scan, kept up to date
+    /// from events so that it doesn't need to be fetched from BlueZ each time.
+    devices: Arc<Mutex<HashMap<DeviceId, ScanResult>>>,
+    reported: Arc<Mutex<HashMap<DeviceId, Reported>>>,
+}
+
+impl ScanState {
+    pub(crate) fn new(scanner: Scanner) -> Self {
+        Self {
+            scanner,
+            devices: Default::default(),
+            reported: Default::default(),
+        }
+    }
+
+    /// Updates the cached advertisement data for the given device from the given event, and
+    /// returns the updated data.
+    ///
+    /// Returns `None` if the device's details need to be fetched from BlueZ instead, because it has
+    /// just been discovered or isn't cached yet.
+    pub(crate) fn update(&self, id: &DeviceId, event: &DeviceEvent) -> Option<ScanResult> {
+        let mut devices = self.devices.lock().unwrap();
+        let result = match event {
+            DeviceEvent::Discovered => return None,
+            _ => devices.get_mut(id)?,
+        };
+        match event {
+            DeviceEvent::Rssi { rssi } => result.rssi = Some(*rssi),
+            DeviceEvent::TxPower { tx_power } => result.tx_power = Some(*tx_power),
+            DeviceEvent::ManufacturerData { manufacturer_data } => {
+                result.manufacturer_data = manufacturer_data.clone()
+            }
+            DeviceEvent::ServiceData { service_data } => result.service_data = service_data.clone(),
+            DeviceEvent::Services { services } => result.services = services.clone(),
+            _ => {}
+        }
+        Some(result.clone())
+    }
+
+    /// Forgets about the given device, because BlueZ has removed it.
+    pub(crate) fn remove(&self, id: &DeviceId) {
+        self.devices.lock().unwrap().remove(id);
+        self.reported.lock().unwrap().remove(id);
+    }
+
+    /// Caches the given advertisement data fetched from BlueZ.
+    pub(crate) fn insert(&self, result: ScanResult) {
+        self.devices
+            .lock()
+            .unwrap()
+            .insert(result.id.clone(), result);
+    }
+
+    /// Returns whether the given device is currently rate limited, so there is no point fetching
+    /// its details.
+    pub(crate) fn rate_limited(&self, id: &DeviceId, now: Instant) -> bool {
+        if let Some(rate_limit) = self.scanner.rate_limit {
+            if let Some(reported) = self.reported.lock().unwrap().get(id) {
+                return now < reported.at + rate_limit;
+            }
+        }
+        false
+    }
+
+    /// Returns whether the given result should be reported, and if so records that it was.
+    pub(crate) fn should_report(&self, result: &ScanResult, now: Instant) -> bool {
+        if !self.scanner.matches(result) || self.rate_limited(&result.id, now) {
+            return false;
+        }
+        let mut reported = self.reported.lock().unwrap();
+        if let (Some(dedup_window), Some(previous)) =
+            (self.scanner.dedup_window, reported.get(&result.id))
+        {
+            if now < previous.at + dedup_window && previous.result.same_advertisement(result) {
+                return false;
+            }
+        }
+        reported.insert(
+            result.id.clone(),
+            Reported {
+                result: result.clone(),
+                at: now,
+            },
+        );
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, rssi: i16) -> ScanResult {
+        let mut manufacturer_data = HashMap::new();
+        manufacturer_data.insert(0x0499, vec![0x05, 0x01]);
+        ScanResult {
+            id: DeviceId::new("/org/bluez/hci0/dev_11_22_33_44_55_66"),
+            mac_address: "11:22:33:44:55:66".parse().unwrap(),
+            address_type: AddressType::Random,
+            name: Some(name.to_owned()),
+            rssi: Some(rssi),
+            tx_power: None,
+            manufacturer_data,
+            service_data: HashMap::new(),
+            services: vec![],
+        }
+    }
+
+    #[test]
+    fn filters() {
+        let result = result("Ruuvi 1234", -70);
+        assert!(Scanner::new().matches(&result));
+        assert!(Scanner::new()
+            .name_filter(|name| name.starts_with("Ruuvi"))
+            .manufacturer_id(0x004c)
+            .manufacturer_id(0x0499)
+            .address_type(AddressType::Random)
+            .min_rssi(-70)
+            .matches(&result));
+        assert!(!Scanner::new()
+            .name_filter(|name| name.starts_with("Mi"))
+            .matches(&result));
+        assert!(!Scanner::new().manufacturer_id(0x004c).matches(&result));
+        assert!(!Scanner::new()
+            .service_data_uuid(Uuid::nil())
+            .matches(&result));
+        assert!(!Scanner::new()
+            .address_type(AddressType::Public)
+            .matches(&result));
+        assert!(!Scanner::new().min_rssi(-60).matches(&result));
+    }
+
+    #[test]
+    fn update_from_events() {
+        let state = ScanState::new(Scanner::new());
+        let result = result("a", -70);
+        let id = result.id.clone();
+        assert_eq!(state.update(&id, &DeviceEvent::Rssi { rssi: -60 }), None);
+        state.insert(result.clone());
+        assert_eq!(state.update(&id, &DeviceEvent::Discovered), None);
+
+        let mut service_data = HashMap::new();
+        service_data.insert(Uuid::nil(), vec![0x42]);
+        state.update(&id, &DeviceEvent::Rssi { rssi: -60 }).unwrap();
+        state
+            .update(&id, &DeviceEvent::TxPower { tx_power: 4 })
+            .unwrap();
+        assert_eq!(
+            state.update(
+                &id,
+                &DeviceEvent::ServiceData {
+                    service_data: service_data.clone()
+                }
+            ),
+            Some(ScanResult {
+                rssi: Some(-60),
+                tx_power: Some(4),
+                service_data,
+                ..result
+            })
+        );
+    }
+
+    #[test]
+    fn dedup() {
+        let state = ScanState::new(Scanner::new().dedup_window(Duration::from_secs(10)));
+        let start = Instant::now();
+        assert!(state.should_report(&result("a", -70), start));
+        // RSSI changes alone are deduplicated.
+        assert!(!state.should_report(&result("a", -60), start + Duration::from_secs(1)));
+        // Other changes aren't.
+        assert!(state.should_report(&result("b", -60), start + Duration::from_secs(2)));
+        // Nor are unchanged results after the window.
+        assert!(state.should_report(&result("b", -60), start + Duration::from_secs(12)));
+    }
+
+    #[test]
+    fn rate_limit() {
+        let state = ScanState::new(Scanner::new().rate_limit(Duration::from_secs(10)));
+        let start = Instant::now();
+        let id = result("a", -70).id;
+        assert!(!state.rate_limited(&id, start));
+        assert!(state.should_report(&result("a", -70), start));
+        assert!(state.rate_limited(&id, start + Duration::from_secs(1)));
+        assert!(!state.should_report(&result("b", -70), start + Duration::from_secs(1)));
+        assert!(state.should_report(&result("b", -70), start + Duration::from_secs(10)));
+    }
+}
@@ -11,5 +11,5 @@ pub fn serialize<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::
 /// Deserialize a [`Path`] from a string.
 pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Path<'static>, D::Error> {
     let string = String::deserialize(deserializer)?;
-    Ok(Path::new(string).map_err(|e| D::Error::custom(format!("Invalid D-Bus path: {:?}", e)))?)
+    Path::new(string).map_err(|e| D::Error::custom(format!("Invalid D-Bus path: {:?}", e)))
 }
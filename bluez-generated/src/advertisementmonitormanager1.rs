@@ -0,0 +1,71 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.AdvertisementMonitorManager1.xml --interfaces=org.bluez.AdvertisementMonitorManager1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezAdvertisementMonitorManager1 {
+    fn register_monitor(&self, application: dbus::Path) -> nonblock::MethodReply<()>;
+    fn unregister_monitor(&self, application: dbus::Path) -> nonblock::MethodReply<()>;
+    fn supported_monitor_types(&self) -> nonblock::MethodReply<Vec<String>>;
+    fn supported_features(&self) -> nonblock::MethodReply<Vec<String>>;
+}
+
+pub const ORG_BLUEZ_ADVERTISEMENT_MONITOR_MANAGER1_NAME: &str =
+    "org.bluez.AdvertisementMonitorManager1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezAdvertisementMonitorManager1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezAdvertisementMonitorManager1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces
+            .get("org.bluez.AdvertisementMonitorManager1")
+            .map(Self)
+    }
+
+    pub fn supported_monitor_types(&self) -> Option<&Vec<String>> {
+        arg::prop_cast(self.0, "SupportedMonitorTypes")
+    }
+
+    pub fn supported_features(&self) -> Option<&Vec<String>> {
+        arg::prop_cast(self.0, "SupportedFeatures")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>>
+    OrgBluezAdvertisementMonitorManager1 for nonblock::Proxy<'a, C>
+{
+    fn register_monitor(&self, application: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.AdvertisementMonitorManager1",
+            "RegisterMonitor",
+            (application,),
+        )
+    }
+
+    fn unregister_monitor(&self, application: dbus::Path) -> nonblock::MethodReply<()> {
+        self.method_call(
+            "org.bluez.AdvertisementMonitorManager1",
+            "UnregisterMonitor",
+            (application,),
+        )
+    }
+
+    fn supported_monitor_types(&self) -> nonblock::MethodReply<Vec<String>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitorManager1",
+            "SupportedMonitorTypes",
+        )
+    }
+
+    fn supported_features(&self) -> nonblock::MethodReply<Vec<String>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.AdvertisementMonitorManager1",
+            "SupportedFeatures",
+        )
+    }
+}
@@ -2,6 +2,10 @@
 #![allow(clippy::upper_case_acronyms, clippy::needless_borrow)]
 pub mod adapter1;
 pub use adapter1::*;
+pub mod advertisementmonitor1;
+pub use advertisementmonitor1::*;
+pub mod advertisementmonitormanager1;
+pub use advertisementmonitormanager1::*;
 pub mod agentmanager1;
 pub use agentmanager1::*;
 pub mod battery1;
@@ -24,6 +28,8 @@ pub mod media1;
 pub use media1::*;
 pub mod mediacontrol1;
 pub use mediacontrol1::*;
+pub mod mediaplayer1;
+pub use mediaplayer1::*;
 pub mod network1;
 pub use network1::*;
 pub mod networkserver1;
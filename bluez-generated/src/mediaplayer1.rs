@@ -0,0 +1,296 @@
+// This code was autogenerated with `dbus-codegen-rust --file=specs/org.bluez.MediaPlayer1.xml --interfaces=org.bluez.MediaPlayer1 --client=nonblock --methodtype=none --prop-newtype`, see https://github.com/diwic/dbus-rs
+#[allow(unused_imports)]
+use dbus::arg;
+use dbus::nonblock;
+
+pub trait OrgBluezMediaPlayer1 {
+    fn play(&self) -> nonblock::MethodReply<()>;
+    fn pause(&self) -> nonblock::MethodReply<()>;
+    fn stop(&self) -> nonblock::MethodReply<()>;
+    fn next(&self) -> nonblock::MethodReply<()>;
+    fn previous(&self) -> nonblock::MethodReply<()>;
+    fn fast_forward(&self) -> nonblock::MethodReply<()>;
+    fn rewind(&self) -> nonblock::MethodReply<()>;
+    fn press(&self, avc_key: u8) -> nonblock::MethodReply<()>;
+    fn hold(&self, avc_key: u8) -> nonblock::MethodReply<()>;
+    fn release(&self) -> nonblock::MethodReply<()>;
+    fn name(&self) -> nonblock::MethodReply<String>;
+    fn type_(&self) -> nonblock::MethodReply<String>;
+    fn subtype(&self) -> nonblock::MethodReply<String>;
+    fn position(&self) -> nonblock::MethodReply<u32>;
+    fn status(&self) -> nonblock::MethodReply<String>;
+    fn equalizer(&self) -> nonblock::MethodReply<String>;
+    fn set_equalizer(&self, value: String) -> nonblock::MethodReply<()>;
+    fn repeat(&self) -> nonblock::MethodReply<String>;
+    fn set_repeat(&self, value: String) -> nonblock::MethodReply<()>;
+    fn shuffle(&self) -> nonblock::MethodReply<String>;
+    fn set_shuffle(&self, value: String) -> nonblock::MethodReply<()>;
+    fn scan(&self) -> nonblock::MethodReply<String>;
+    fn set_scan(&self, value: String) -> nonblock::MethodReply<()>;
+    fn track(&self) -> nonblock::MethodReply<arg::PropMap>;
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+    fn browsable(&self) -> nonblock::MethodReply<bool>;
+    fn searchable(&self) -> nonblock::MethodReply<bool>;
+    fn playlist(&self) -> nonblock::MethodReply<dbus::Path<'static>>;
+}
+
+pub const ORG_BLUEZ_MEDIA_PLAYER1_NAME: &str = "org.bluez.MediaPlayer1";
+
+#[derive(Copy, Clone, Debug)]
+pub struct OrgBluezMediaPlayer1Properties<'a>(pub &'a arg::PropMap);
+
+impl<'a> OrgBluezMediaPlayer1Properties<'a> {
+    pub fn from_interfaces(
+        interfaces: &'a ::std::collections::HashMap<String, arg::PropMap>,
+    ) -> Option<Self> {
+        interfaces.get("org.bluez.MediaPlayer1").map(Self)
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Name")
+    }
+
+    pub fn type_(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Type")
+    }
+
+    pub fn subtype(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Subtype")
+    }
+
+    pub fn position(&self) -> Option<u32> {
+        arg::prop_cast(self.0, "Position").copied()
+    }
+
+    pub fn status(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Status")
+    }
+
+    pub fn equalizer(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Equalizer")
+    }
+
+    pub fn repeat(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Repeat")
+    }
+
+    pub fn shuffle(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Shuffle")
+    }
+
+    pub fn scan(&self) -> Option<&String> {
+        arg::prop_cast(self.0, "Scan")
+    }
+
+    pub fn track(&self) -> Option<&arg::PropMap> {
+        arg::prop_cast(self.0, "Track")
+    }
+
+    pub fn device(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Device")
+    }
+
+    pub fn browsable(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "Browsable").copied()
+    }
+
+    pub fn searchable(&self) -> Option<bool> {
+        arg::prop_cast(self.0, "Searchable").copied()
+    }
+
+    pub fn playlist(&self) -> Option<&dbus::Path<'static>> {
+        arg::prop_cast(self.0, "Playlist")
+    }
+}
+
+impl<'a, T: nonblock::NonblockReply, C: ::std::ops::Deref<Target = T>> OrgBluezMediaPlayer1
+    for nonblock::Proxy<'a, C>
+{
+    fn play(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Play", ())
+    }
+
+    fn pause(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Pause", ())
+    }
+
+    fn stop(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Stop", ())
+    }
+
+    fn next(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Next", ())
+    }
+
+    fn previous(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Previous", ())
+    }
+
+    fn fast_forward(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "FastForward", ())
+    }
+
+    fn rewind(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Rewind", ())
+    }
+
+    fn press(&self, avc_key: u8) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Press", (avc_key,))
+    }
+
+    fn hold(&self, avc_key: u8) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Hold", (avc_key,))
+    }
+
+    fn release(&self) -> nonblock::MethodReply<()> {
+        self.method_call("org.bluez.MediaPlayer1", "Release", ())
+    }
+
+    fn name(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Name",
+        )
+    }
+
+    fn type_(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Type",
+        )
+    }
+
+    fn subtype(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Subtype",
+        )
+    }
+
+    fn position(&self) -> nonblock::MethodReply<u32> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Position",
+        )
+    }
+
+    fn status(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Status",
+        )
+    }
+
+    fn equalizer(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Equalizer",
+        )
+    }
+
+    fn repeat(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Repeat",
+        )
+    }
+
+    fn shuffle(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Shuffle",
+        )
+    }
+
+    fn scan(&self) -> nonblock::MethodReply<String> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Scan",
+        )
+    }
+
+    fn track(&self) -> nonblock::MethodReply<arg::PropMap> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Track",
+        )
+    }
+
+    fn device(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Device",
+        )
+    }
+
+    fn browsable(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Browsable",
+        )
+    }
+
+    fn searchable(&self) -> nonblock::MethodReply<bool> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Searchable",
+        )
+    }
+
+    fn playlist(&self) -> nonblock::MethodReply<dbus::Path<'static>> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::get(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Playlist",
+        )
+    }
+
+    fn set_equalizer(&self, value: String) -> nonblock::MethodReply<()> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::set(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Equalizer",
+            value,
+        )
+    }
+
+    fn set_repeat(&self, value: String) -> nonblock::MethodReply<()> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::set(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Repeat",
+            value,
+        )
+    }
+
+    fn set_shuffle(&self, value: String) -> nonblock::MethodReply<()> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::set(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Shuffle",
+            value,
+        )
+    }
+
+    fn set_scan(&self, value: String) -> nonblock::MethodReply<()> {
+        <Self as nonblock::stdintf::org_freedesktop_dbus::Properties>::set(
+            &self,
+            "org.bluez.MediaPlayer1",
+            "Scan",
+            value,
+        )
+    }
+}